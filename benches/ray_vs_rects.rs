@@ -0,0 +1,80 @@
+/*
+ * Copyright (c) Peter Bjorklund. All rights reserved. https://github.com/piot/impact-rs
+ * Licensed under the MIT License. See LICENSE in the project root for license information.
+ */
+
+//! Compares casting one ray against many rectangles with [`ray_vs_rects`] and with a loop
+//! over [`ray_vs_rect`]. Run with `cargo bench`.
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use fixed32::Fp;
+use fixed32_math::{Rect, Vector};
+use impact_rs::{ray_vs_rect, ray_vs_rects, RayIntersectionResult};
+
+const TARGET_COUNT: i16 = 1000;
+const ROUNDS: u32 = 2000;
+
+fn per_target(
+    ray_origin: Vector,
+    ray_direction: Vector,
+    targets: &[Rect],
+) -> Option<(usize, RayIntersectionResult)> {
+    let mut closest: Option<(usize, RayIntersectionResult)> = None;
+    for (index, target) in targets.iter().enumerate() {
+        if let Some(result) = ray_vs_rect(ray_origin, ray_direction, *target) {
+            if closest
+                .as_ref()
+                .is_none_or(|(_, best)| result.closest_time < best.closest_time)
+            {
+                closest = Some((index, result));
+            }
+        }
+    }
+    closest
+}
+
+fn measure(name: &str, mut run: impl FnMut()) -> Duration {
+    let start = Instant::now();
+    for _ in 0..ROUNDS {
+        run();
+    }
+    let elapsed = start.elapsed();
+    println!("{name:>12}: {:?} per cast", elapsed / ROUNDS);
+    elapsed
+}
+
+fn main() {
+    // A field of small boxes, most of which are crossed by the diagonal ray
+    let targets: Vec<Rect> = (0..TARGET_COUNT)
+        .map(|index| {
+            Rect::new(
+                Vector::new(Fp::from(index % 40), Fp::from(index / 40)),
+                Vector::new(Fp::from(0.5), Fp::from(0.5)),
+            )
+        })
+        .collect();
+    let ray_origin = Vector::new(Fp::from(-1), Fp::from(-0.75));
+    let ray_direction = Vector::new(Fp::from(1), Fp::from(0.6));
+
+    let per_target_time = measure("per target", || {
+        black_box(per_target(
+            black_box(ray_origin),
+            black_box(ray_direction),
+            black_box(&targets),
+        ));
+    });
+    let batch_time = measure("batch", || {
+        black_box(ray_vs_rects(
+            black_box(ray_origin),
+            black_box(ray_direction),
+            black_box(&targets),
+        ));
+    });
+
+    println!(
+        "batch speedup: {:.2}x",
+        per_target_time.as_secs_f64() / batch_time.as_secs_f64()
+    );
+}
@@ -0,0 +1,107 @@
+/*
+ * Copyright (c) Peter Bjorklund. All rights reserved. https://github.com/piot/impact-rs
+ * Licensed under the MIT License. See LICENSE in the project root for license information.
+ */
+
+//! Broadphase queries for finding candidate pairs among many rectangles.
+
+use fixed32_math::Rect;
+
+use crate::overlap::rect_vs_rect;
+use crate::rect_util::normalize_rect;
+
+/// Finds all pairs of overlapping rectangles using sort and sweep (SAP).
+///
+/// The rectangles are sorted by their left edge and swept along the x-axis, so only
+/// rectangles that overlap on x are compared. Overlap uses the same rules as
+/// [`rect_vs_rect`]: rectangles that only share an edge are not paired.
+///
+/// # Returns
+///
+/// The index pairs `(i, j)` with `i < j`, sorted in ascending order.
+///
+/// # Example
+///
+/// ```rust
+/// use fixed32_math::Rect;
+/// use impact_rs::prelude::full::*;
+///
+/// let rects = [
+///     Rect::from((0, 0, 4, 4)),
+///     Rect::from((10, 0, 4, 4)),
+///     Rect::from((2, 2, 4, 4)),
+/// ];
+///
+/// assert_eq!(overlapping_pairs(&rects), vec![(0, 2)]);
+/// ```
+#[must_use]
+pub fn overlapping_pairs(rects: &[Rect]) -> Vec<(usize, usize)> {
+    sweep_pairs(rects, |_, _| true)
+}
+
+/// Finds all pairs of overlapping rectangles that are not in the same collision group.
+///
+/// This is [`overlapping_pairs`] with a group id per rectangle. Rectangles with the same
+/// non-zero group id, for example the parts of one multi-part entity, are never paired with
+/// each other. Group `0` is no group: its rectangles are paired with everything they overlap,
+/// including other rectangles of group `0`.
+///
+/// # Returns
+///
+/// The index pairs `(i, j)` with `i < j`, sorted in ascending order.
+///
+/// # Panics
+///
+/// Panics if `groups` and `rects` do not have the same length.
+///
+/// # Example
+///
+/// ```rust
+/// use fixed32_math::Rect;
+/// use impact_rs::prelude::full::*;
+///
+/// let rects = [
+///     Rect::from((0, 0, 4, 4)),
+///     Rect::from((2, 0, 4, 4)),
+///     Rect::from((4, 2, 4, 4)),
+/// ];
+///
+/// assert_eq!(overlapping_pairs_grouped(&rects, &[1, 1, 2]), vec![(1, 2)]);
+/// assert_eq!(overlapping_pairs_grouped(&rects, &[0, 0, 2]), vec![(0, 1), (1, 2)]);
+/// ```
+#[must_use]
+pub fn overlapping_pairs_grouped(rects: &[Rect], groups: &[u32]) -> Vec<(usize, usize)> {
+    assert_eq!(rects.len(), groups.len(), "every rect must have a group id");
+
+    sweep_pairs(rects, |a, b| groups[a] == 0 || groups[a] != groups[b])
+}
+
+/// Runs the sort and sweep, keeping the overlapping pairs that `accept` returns `true` for.
+fn sweep_pairs<F>(rects: &[Rect], accept: F) -> Vec<(usize, usize)>
+where
+    F: Fn(usize, usize) -> bool,
+{
+    let rects: Vec<Rect> = rects.iter().map(|rect| normalize_rect(*rect)).collect();
+
+    let mut order: Vec<usize> = (0..rects.len()).collect();
+    order.sort_by_key(|index| rects[*index].left());
+
+    let mut active: Vec<usize> = Vec::new();
+    let mut pairs = Vec::new();
+
+    for index in order {
+        let rect = rects[index];
+        active.retain(|other| rects[*other].right() > rect.left());
+
+        for other in &active {
+            if accept(index, *other) && rect_vs_rect(rect, rects[*other]) {
+                pairs.push((index.min(*other), index.max(*other)));
+            }
+        }
+
+        active.push(index);
+    }
+
+    pairs.sort_unstable();
+    pairs
+}
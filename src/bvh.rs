@@ -0,0 +1,179 @@
+/*
+ * Copyright (c) Peter Bjorklund. All rights reserved. https://github.com/piot/impact-rs
+ * Licensed under the MIT License. See LICENSE in the project root for license information.
+ */
+
+//! Bounding volume hierarchy for raycasts against large sets of static rectangles.
+
+use fixed32_math::{Rect, Vector};
+
+use crate::rect_util::normalize_rect;
+use crate::{ray_vs_rect, RayIntersectionResult};
+
+#[derive(Debug, Clone)]
+enum Node {
+    Leaf {
+        bounds: Rect,
+        index: usize,
+    },
+    Branch {
+        bounds: Rect,
+        left: usize,
+        right: usize,
+    },
+}
+
+impl Node {
+    const fn bounds(&self) -> Rect {
+        match self {
+            Self::Leaf { bounds, .. } | Self::Branch { bounds, .. } => *bounds,
+        }
+    }
+}
+
+/// An axis-aligned bounding box tree over static rectangles.
+///
+/// The tree is built once from a slice of rectangles and can not be changed afterwards;
+/// build a new tree when the geometry changes. Raycasts only test the rectangles whose
+/// enclosing nodes are hit by the ray, which makes them logarithmic in the number of
+/// rectangles for typical level layouts.
+#[derive(Debug, Clone)]
+pub struct AabbTree {
+    nodes: Vec<Node>,
+    root: Option<usize>,
+}
+
+impl AabbTree {
+    /// Builds a tree over `rects`.
+    ///
+    /// Each node is split at the median of its rectangles along the longest axis of its
+    /// bounds. The indices reported by [`AabbTree::raycast`] are positions in `rects`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fixed32_math::{Rect, Vector};
+    /// use impact_rs::bvh::AabbTree;
+    ///
+    /// let rects = [
+    ///     Rect::from((20, -5, 10, 10)),
+    ///     Rect::from((10, -5, 5, 10)),
+    ///     Rect::from((10, 40, 5, 10)),
+    /// ];
+    /// let tree = AabbTree::build(&rects);
+    ///
+    /// let (index, _) = tree
+    ///     .raycast(Vector::from((0, 0)), Vector::from((1, 0)))
+    ///     .expect("should hit");
+    /// assert_eq!(index, 1);
+    /// ```
+    #[must_use]
+    pub fn build(rects: &[Rect]) -> Self {
+        let mut leaves: Vec<(usize, Rect)> = rects
+            .iter()
+            .enumerate()
+            .map(|(index, rect)| (index, normalize_rect(*rect)))
+            .collect();
+
+        let mut nodes = Vec::with_capacity(leaves.len() * 2);
+        let root = if leaves.is_empty() {
+            None
+        } else {
+            Some(Self::build_node(&mut nodes, &mut leaves))
+        };
+
+        Self { nodes, root }
+    }
+
+    fn build_node(nodes: &mut Vec<Node>, leaves: &mut [(usize, Rect)]) -> usize {
+        if let [(index, bounds)] = leaves {
+            nodes.push(Node::Leaf {
+                bounds: *bounds,
+                index: *index,
+            });
+            return nodes.len() - 1;
+        }
+
+        let bounds = leaves
+            .iter()
+            .skip(1)
+            .fold(leaves[0].1, |bounds, (_, rect)| bounds.union(rect));
+
+        if bounds.size.x > bounds.size.y {
+            leaves.sort_by_key(|(index, rect)| (rect.left() + rect.right(), *index));
+        } else {
+            leaves.sort_by_key(|(index, rect)| (rect.bottom() + rect.top(), *index));
+        }
+
+        let (left_leaves, right_leaves) = leaves.split_at_mut(leaves.len() / 2);
+        let left = Self::build_node(nodes, left_leaves);
+        let right = Self::build_node(nodes, right_leaves);
+
+        nodes.push(Node::Branch {
+            bounds,
+            left,
+            right,
+        });
+        nodes.len() - 1
+    }
+
+    /// Casts a ray against the rectangles in the tree and returns the nearest hit.
+    ///
+    /// The result is the same as calling [`ray_vs_rects`](crate::ray_vs_rects) with the
+    /// rectangles the tree was built from, including the lowest index winning when two
+    /// rectangles are hit at the same time. Nodes are tested with [`ray_vs_rect`] before
+    /// their children, and nodes that are entered later than the nearest hit found so far
+    /// are skipped.
+    ///
+    /// # Parameters
+    ///
+    /// - `origin`: The origin point of the ray as a [`Vector`].
+    /// - `direction`: The ray as a [`Vector`]. The direction vector must not be zero.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Some((index, RayIntersectionResult))` for the nearest hit, where `index` is the
+    /// position of the hit rectangle in the slice passed to [`AabbTree::build`]. Returns
+    /// `None` if no rectangle is hit.
+    #[must_use]
+    pub fn raycast(
+        &self,
+        origin: Vector,
+        direction: Vector,
+    ) -> Option<(usize, RayIntersectionResult)> {
+        let mut closest: Option<(usize, RayIntersectionResult)> = None;
+        let mut stack: Vec<usize> = self.root.into_iter().collect();
+
+        while let Some(node_index) = stack.pop() {
+            let node = &self.nodes[node_index];
+            let Some(entry) = ray_vs_rect(origin, direction, node.bounds()) else {
+                continue;
+            };
+
+            if closest
+                .as_ref()
+                .is_some_and(|(_, best)| entry.closest_time > best.closest_time)
+            {
+                continue;
+            }
+
+            match node {
+                Node::Leaf { index, .. } => {
+                    let is_closer = closest.as_ref().is_none_or(|(best_index, best)| {
+                        entry.closest_time < best.closest_time
+                            || (entry.closest_time == best.closest_time && *index < *best_index)
+                    });
+                    if is_closer {
+                        closest = Some((*index, entry));
+                    }
+                }
+                Node::Branch { left, right, .. } => {
+                    stack.push(*right);
+                    stack.push(*left);
+                }
+            }
+        }
+
+        closest
+    }
+}
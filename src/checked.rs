@@ -0,0 +1,108 @@
+/*
+ * Copyright (c) Peter Bjorklund. All rights reserved. https://github.com/piot/impact-rs
+ * Licensed under the MIT License. See LICENSE in the project root for license information.
+ */
+
+//! Query variants that report fixed-point overflow instead of returning a wrong result.
+
+use std::error::Error;
+use std::fmt;
+
+use fixed32::Fp;
+use fixed32_math::{Rect, Vector};
+
+use crate::{ray_vs_rect, RayIntersectionResult};
+
+/// An intermediate value of a query did not fit in the range of [`Fp`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OverflowError;
+
+impl fmt::Display for OverflowError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "fixed-point overflow in collision query")
+    }
+}
+
+impl Error for OverflowError {}
+
+/// Performs a ray-rectangle intersection test, checking every intermediate value for overflow.
+///
+/// [`ray_vs_rect`] computes the rectangle edges, the distances from the ray origin to the
+/// edges, the slab times and the contact point in [`Fp`]. With large coordinates or very
+/// short directions these can exceed the range of [`Fp`], where additions wrap and the slab
+/// times saturate, which can report a hit that does not exist or miss one that does. This
+/// function computes the same values in 64-bit integers first, and only runs [`ray_vs_rect`]
+/// once they are all known to fit. It is slower, so [`ray_vs_rect`] stays the default.
+///
+/// # Parameters
+///
+/// - `ray_origin`: The origin point of the ray as a [`Vector`].
+/// - `ray_direction`: The ray as a [`Vector`]. The direction vector must not be zero.
+/// - `target`: The [`Rect`] representing the target rectangle to test for intersection.
+///
+/// # Errors
+///
+/// Returns [`OverflowError`] if an edge of `target`, a distance from `ray_origin` to an edge,
+/// a slab time or the contact point does not fit in an [`Fp`].
+///
+/// # Example
+///
+/// ```rust
+/// use fixed32::Fp;
+/// use fixed32_math::{Rect, Vector};
+/// use impact_rs::prelude::full::*;
+///
+/// let ray_origin = Vector::from((-30000, 0));
+/// let far_target = Rect::from((30000, -1, 10, 2));
+///
+/// assert_eq!(
+///     try_ray_vs_rect_checked(ray_origin, Vector::from((1, 0)), far_target).unwrap_err(),
+///     OverflowError
+/// );
+/// ```
+pub fn try_ray_vs_rect_checked(
+    ray_origin: Vector,
+    ray_direction: Vector,
+    target: Rect,
+) -> Result<Option<RayIntersectionResult>, OverflowError> {
+    check_slab(ray_origin.x, ray_direction.x, target.pos.x, target.size.x)?;
+    check_slab(ray_origin.y, ray_direction.y, target.pos.y, target.size.y)?;
+
+    let result = ray_vs_rect(ray_origin, ray_direction, target);
+
+    if let Some(result) = &result {
+        check_contact(ray_origin.x, ray_direction.x, result.closest_time)?;
+        check_contact(ray_origin.y, ray_direction.y, result.closest_time)?;
+    }
+
+    Ok(result)
+}
+
+/// Checks the edges, edge distances and slab times of one axis.
+fn check_slab(origin: Fp, direction: Fp, pos: Fp, size: Fp) -> Result<(), OverflowError> {
+    let start = i64::from(pos.inner());
+    let end = start + i64::from(size.inner());
+    check_raw(end)?;
+
+    for edge in [start, end] {
+        let distance = edge - i64::from(origin.inner());
+        check_raw(distance)?;
+
+        if !direction.is_zero() {
+            check_raw(distance * Fp::SCALE_I64 / i64::from(direction.inner()))?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Checks `origin + time * direction` for one axis.
+fn check_contact(origin: Fp, direction: Fp, time: Fp) -> Result<(), OverflowError> {
+    let offset = i64::from(time.inner()) * i64::from(direction.inner()) / Fp::SCALE_I64;
+    check_raw(offset)?;
+    check_raw(i64::from(origin.inner()) + offset)
+}
+
+fn check_raw(raw: i64) -> Result<(), OverflowError> {
+    i32::try_from(raw).map(|_| ()).map_err(|_| OverflowError)
+}
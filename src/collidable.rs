@@ -0,0 +1,124 @@
+/*
+ * Copyright (c) Peter Bjorklund. All rights reserved. https://github.com/piot/impact-rs
+ * Licensed under the MIT License. See LICENSE in the project root for license information.
+ */
+
+//! A shape-agnostic interface for plugging custom shapes into the queries.
+
+use fixed32_math::{Rect, Vector};
+
+use crate::circle::{ray_vs_circle, Circle};
+use crate::obb::{ray_vs_obb, Obb};
+use crate::{ray_vs_rect, RayIntersectionResult};
+
+/// A shape that can be ray cast and bounded by an axis-aligned rectangle.
+///
+/// Implement this for custom shapes to use them with [`ray_vs_collidables`]. The bounding
+/// rectangle returned by [`Collidable::aabb`] lets broadphase structures stay shape-agnostic;
+/// it must contain the whole shape, but does not need to be tight.
+pub trait Collidable {
+    /// Casts a ray against the shape, with the same conventions as [`ray_vs_rect`].
+    fn ray_cast(&self, origin: Vector, direction: Vector) -> Option<RayIntersectionResult>;
+
+    /// Returns an axis-aligned [`Rect`] containing the whole shape.
+    fn aabb(&self) -> Rect;
+}
+
+impl Collidable for Rect {
+    fn ray_cast(&self, origin: Vector, direction: Vector) -> Option<RayIntersectionResult> {
+        ray_vs_rect(origin, direction, *self)
+    }
+
+    fn aabb(&self) -> Rect {
+        *self
+    }
+}
+
+impl Collidable for Circle {
+    fn ray_cast(&self, origin: Vector, direction: Vector) -> Option<RayIntersectionResult> {
+        ray_vs_circle(origin, direction, *self)
+    }
+
+    fn aabb(&self) -> Rect {
+        Self::aabb(self)
+    }
+}
+
+impl Collidable for Obb {
+    fn ray_cast(&self, origin: Vector, direction: Vector) -> Option<RayIntersectionResult> {
+        ray_vs_obb(origin, direction, *self)
+    }
+
+    fn aabb(&self) -> Rect {
+        Self::aabb(self)
+    }
+}
+
+impl<T: Collidable + ?Sized> Collidable for Box<T> {
+    fn ray_cast(&self, origin: Vector, direction: Vector) -> Option<RayIntersectionResult> {
+        (**self).ray_cast(origin, direction)
+    }
+
+    fn aabb(&self) -> Rect {
+        (**self).aabb()
+    }
+}
+
+impl<T: Collidable + ?Sized> Collidable for &T {
+    fn ray_cast(&self, origin: Vector, direction: Vector) -> Option<RayIntersectionResult> {
+        (**self).ray_cast(origin, direction)
+    }
+
+    fn aabb(&self) -> Rect {
+        (**self).aabb()
+    }
+}
+
+/// Casts a ray against a slice of [`Collidable`] shapes and returns the nearest hit.
+///
+/// This is the shape-agnostic version of [`crate::ray_vs_rects`] and works both with
+/// homogeneous slices (`&[Circle]`) and heterogeneous ones (`&[Box<dyn Collidable>]`).
+/// If two shapes are hit at the same time, the one with the lowest index wins.
+///
+/// # Returns
+///
+/// Returns `Some((index, RayIntersectionResult))` for the nearest hit, or `None` if no
+/// shape is hit.
+///
+/// # Example
+///
+/// ```rust
+/// use fixed32::Fp;
+/// use fixed32_math::{Rect, Vector};
+/// use impact_rs::prelude::full::*;
+///
+/// let shapes: Vec<Box<dyn Collidable>> = vec![
+///     Box::new(Rect::from((20, -1, 2, 2))),
+///     Box::new(Circle::new(Vector::from((10, 0)), Fp::one())),
+/// ];
+///
+/// let (index, _) = ray_vs_collidables(Vector::from((0, 0)), Vector::from((1, 0)), &shapes)
+///     .expect("should hit");
+/// assert_eq!(index, 1);
+/// ```
+#[must_use]
+pub fn ray_vs_collidables<T: Collidable>(
+    ray_origin: Vector,
+    ray_direction: Vector,
+    targets: &[T],
+) -> Option<(usize, RayIntersectionResult)> {
+    let mut closest: Option<(usize, RayIntersectionResult)> = None;
+
+    for (index, target) in targets.iter().enumerate() {
+        if let Some(result) = target.ray_cast(ray_origin, ray_direction) {
+            let is_closer = closest
+                .as_ref()
+                .is_none_or(|(_, best)| result.closest_time < best.closest_time);
+            if is_closer {
+                closest = Some((index, result));
+            }
+        }
+    }
+
+    closest
+}
@@ -0,0 +1,86 @@
+/*
+ * Copyright (c) Peter Bjorklund. All rights reserved. https://github.com/piot/impact-rs
+ * Licensed under the MIT License. See LICENSE in the project root for license information.
+ */
+
+//! Fan-of-rays queries, typically used for AI vision cones.
+
+use fixed32::Fp;
+use fixed32_math::{Rect, Vector};
+
+use crate::trig::rotate;
+use crate::{ray_vs_rects, RayIntersectionResult};
+
+/// Casts a fan of rays spread evenly across a cone and returns the nearest hit per ray.
+///
+/// The rays are spread from `-half_angle` to `+half_angle` around `forward`, so the
+/// first and last rays lie exactly on the edges of the cone. A single ray is cast
+/// straight along `forward`. Each ray is tested against all `targets` with
+/// [`ray_vs_rects`].
+///
+/// The angle of each ray is computed from the raw value of `half_angle` in 64-bit integers
+/// and only rounded once, so the spacing error does not add up across the fan. The rays are
+/// rotated with [`rotate`], so ray directions are accurate to the precision of [`Fp`].
+///
+/// # Parameters
+///
+/// - `origin`: The apex of the cone, where every ray starts.
+/// - `forward`: The center direction of the cone. It must not be zero. Its length is kept
+///   for every ray, so `closest_time` is parametric to the length of `forward`.
+/// - `half_angle`: Half of the cone opening angle, in radians.
+/// - `ray_count`: The number of rays to cast.
+/// - `targets`: The [`Rect`]s to test for intersection.
+///
+/// # Returns
+///
+/// A vector with exactly `ray_count` entries, ordered from the `-half_angle` edge to the
+/// `+half_angle` edge. Each entry is `Some((index, RayIntersectionResult))` for the nearest
+/// target hit by that ray, or `None` if the ray hit nothing.
+///
+/// # Example
+///
+/// ```rust
+/// use fixed32::Fp;
+/// use fixed32_math::{Rect, Vector};
+/// use impact_rs::prelude::full::*;
+///
+/// let targets = [Rect::from((10.0, -1.0, 2.0, 2.0))];
+/// let hits = cone_cast(
+///     Vector::from((0.0, 0.0)),
+///     Vector::from((1.0, 0.0)),
+///     Fp::from(0.5),
+///     5,
+///     &targets,
+/// );
+///
+/// assert_eq!(hits.len(), 5);
+/// assert!(hits[2].is_some());
+/// ```
+#[must_use]
+pub fn cone_cast(
+    origin: Vector,
+    forward: Vector,
+    half_angle: Fp,
+    ray_count: usize,
+    targets: &[Rect],
+) -> Vec<Option<(usize, RayIntersectionResult)>> {
+    if ray_count == 0 {
+        return Vec::new();
+    }
+
+    if ray_count == 1 {
+        return vec![ray_vs_rects(origin, forward, targets)];
+    }
+
+    let last = ray_count as i64 - 1;
+    let half = i64::from(half_angle.inner());
+
+    (0..=last)
+        .map(|index| {
+            // The offset from the first ray is at most `2 * half`, so the angle fits in an `Fp`
+            let angle = Fp::from_raw((-half + 2 * half * index / last) as i32);
+            let direction = rotate(forward, angle);
+            ray_vs_rects(origin, direction, targets)
+        })
+        .collect()
+}
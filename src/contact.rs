@@ -0,0 +1,188 @@
+/*
+ * Copyright (c) Peter Bjorklund. All rights reserved. https://github.com/piot/impact-rs
+ * Licensed under the MIT License. See LICENSE in the project root for license information.
+ */
+
+//! Queries about resting contacts, such as standing on the ground or riding a platform.
+
+use fixed32::Fp;
+use fixed32_math::{Rect, Vector};
+
+use crate::math::DEFAULT_EPSILON;
+use crate::rect_util::normalize_rect;
+use crate::ContactSide;
+
+/// Checks if `rider` is resting on top of `ground`.
+///
+/// The rider is resting if its bottom edge is within `tolerance` of the top edge of
+/// `ground` (either slightly above or slightly sunk into it) and the two rectangles
+/// overlap horizontally. Rectangles that only touch at a corner are not resting.
+/// [`DEFAULT_EPSILON`] is the `tolerance` that [`platform_carry`] uses.
+///
+/// # Example
+///
+/// ```rust
+/// use fixed32::Fp;
+/// use fixed32_math::Rect;
+/// use impact_rs::prelude::full::*;
+///
+/// let ground = Rect::from((0, 0, 10, 2));
+/// assert!(is_grounded(Rect::from((2, 2, 1, 1)), ground, Fp::from(0.1)));
+/// assert!(!is_grounded(Rect::from((2, 3, 1, 1)), ground, Fp::from(0.1)));
+/// ```
+#[must_use]
+pub fn is_grounded(rider: Rect, ground: Rect, tolerance: Fp) -> bool {
+    let rider = normalize_rect(rider);
+    let ground = normalize_rect(ground);
+
+    let horizontal_overlap = rider.left() < ground.right() && rider.right() > ground.left();
+    let gap = rider.bottom() - ground.top();
+
+    horizontal_overlap && gap.abs() <= tolerance
+}
+
+/// Computes how far a rider on a moving platform should be carried.
+///
+/// If `rider` is resting on top of `platform` (see [`is_grounded`]), it should move
+/// along with the platform, so the full `platform_delta` is returned. The rider's bottom
+/// may be up to [`DEFAULT_EPSILON`] away from the platform top and still be carried.
+///
+/// # Parameters
+///
+/// - `rider`: The rectangle that may be standing on the platform.
+/// - `platform`: The platform rectangle at the start of its move.
+/// - `platform_delta`: How far the platform moves this frame.
+///
+/// # Returns
+///
+/// Returns `Some(Vector)` with the delta to apply to `rider`, or `None` if the rider is
+/// not resting on the platform.
+///
+/// # Example
+///
+/// ```rust
+/// use fixed32_math::{Rect, Vector};
+/// use impact_rs::prelude::full::*;
+///
+/// let platform = Rect::from((0, 0, 10, 2));
+/// let rider = Rect::from((2, 2, 1, 1));
+///
+/// assert_eq!(
+///     platform_carry(rider, platform, Vector::from((3, 0))),
+///     Some(Vector::from((3, 0)))
+/// );
+/// ```
+#[must_use]
+pub fn platform_carry(rider: Rect, platform: Rect, platform_delta: Vector) -> Option<Vector> {
+    if is_grounded(rider, platform, DEFAULT_EPSILON) {
+        Some(platform_delta)
+    } else {
+        None
+    }
+}
+
+/// The contacts a moving rectangle made during one move, ordered by time.
+///
+/// Each contact is a `(time, index, side)` entry, where `time` is the fraction of the move
+/// that had passed at the contact, `index` is the index of the contacted target and `side` is
+/// the [`ContactSide`] of the mover that made contact. This is what
+/// [`move_and_slide_events`](crate::response::move_and_slide_events) returns.
+///
+/// # Example
+///
+/// ```rust
+/// use fixed32::Fp;
+/// use impact_rs::prelude::full::*;
+///
+/// let contacts: ContactSet = [
+///     (Fp::from(0.75), 2, ContactSide::Right),
+///     (Fp::from(0.5), 0, ContactSide::Bottom),
+/// ]
+/// .into_iter()
+/// .collect();
+///
+/// assert!(contacts.grounded());
+/// assert_eq!(contacts.strongest(), Some(&(Fp::from(0.5), 0, ContactSide::Bottom)));
+/// assert_eq!(contacts.walls().count(), 1);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ContactSet {
+    contacts: Vec<(Fp, usize, ContactSide)>,
+}
+
+impl ContactSet {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            contacts: Vec::new(),
+        }
+    }
+
+    /// Adds a contact, keeping the contacts ordered by time. A contact at the same time as
+    /// earlier ones is placed after them.
+    pub fn push(&mut self, time: Fp, index: usize, side: ContactSide) {
+        let position = self
+            .contacts
+            .partition_point(|(other, _, _)| *other <= time);
+        self.contacts.insert(position, (time, index, side));
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.contacts.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.contacts.is_empty()
+    }
+
+    /// Returns the contacts, ordered by time.
+    #[must_use]
+    pub fn as_slice(&self) -> &[(Fp, usize, ContactSide)] {
+        &self.contacts
+    }
+
+    /// Iterates over the contacts, ordered by time.
+    pub fn iter(&self) -> std::slice::Iter<'_, (Fp, usize, ContactSide)> {
+        self.contacts.iter()
+    }
+
+    /// Checks if the mover landed on something, that is made contact with its bottom side.
+    #[must_use]
+    pub fn grounded(&self) -> bool {
+        self.iter().any(|(_, _, side)| *side == ContactSide::Bottom)
+    }
+
+    /// Iterates over the contacts made with the left or right side of the mover.
+    pub fn walls(&self) -> impl Iterator<Item = &(Fp, usize, ContactSide)> {
+        self.iter()
+            .filter(|(_, _, side)| matches!(side, ContactSide::Left | ContactSide::Right))
+    }
+
+    /// Returns the earliest contact, the one that stopped the original motion. Among contacts
+    /// at the same time, the one added first is returned.
+    #[must_use]
+    pub fn strongest(&self) -> Option<&(Fp, usize, ContactSide)> {
+        self.contacts.first()
+    }
+}
+
+impl FromIterator<(Fp, usize, ContactSide)> for ContactSet {
+    fn from_iter<T: IntoIterator<Item = (Fp, usize, ContactSide)>>(iter: T) -> Self {
+        let mut set = Self::new();
+        for (time, index, side) in iter {
+            set.push(time, index, side);
+        }
+        set
+    }
+}
+
+impl<'a> IntoIterator for &'a ContactSet {
+    type Item = &'a (Fp, usize, ContactSide);
+    type IntoIter = std::slice::Iter<'a, (Fp, usize, ContactSide)>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
@@ -0,0 +1,137 @@
+/*
+ * Copyright (c) Peter Bjorklund. All rights reserved. https://github.com/piot/impact-rs
+ * Licensed under the MIT License. See LICENSE in the project root for license information.
+ */
+
+//! Geometry for drawing query results while debugging.
+
+use fixed32::Fp;
+use fixed32_math::{Rect, Vector};
+
+use crate::math::saturating_div;
+use crate::rect_util::normalize_rect;
+use crate::{ray_vs_rect, RayIntersectionResult, SlabAxis};
+
+/// Half the length of each arm of the cross marking a contact point.
+const MARKER_HALF_SIZE: Fp = Fp::from_raw(Fp::SCALE / 4);
+
+/// Returns line segments that visualize a ray query and its result.
+///
+/// The segments are, in order:
+///
+/// 1. The ray, from `ray_origin` to `ray_origin + ray_direction`.
+/// 2. The contact normal, from the contact point to `contact_point + contact_normal`. As
+///    for all queries in this crate, the normal points along the ray, into the target.
+/// 3. The horizontal arm of a cross marking the contact point.
+/// 4. The vertical arm of that cross.
+///
+/// Everything is computed in fixed-point, so the output is deterministic and can be
+/// compared between runs.
+///
+/// # Returns
+///
+/// A list of `(start, end)` segments for a renderer to draw.
+///
+/// # Example
+///
+/// ```rust
+/// use fixed32_math::{Rect, Vector};
+/// use impact_rs::prelude::full::*;
+///
+/// let ray_origin = Vector::from((0, 0));
+/// let ray_direction = Vector::from((8, 0));
+/// let result = ray_vs_rect(ray_origin, ray_direction, Rect::from((4, -1, 2, 2))).unwrap();
+///
+/// let lines = result_debug_lines(&result, ray_origin, ray_direction);
+/// assert_eq!(lines[0], (ray_origin, Vector::from((8, 0))));
+/// assert_eq!(lines[1], (Vector::from((4, 0)), Vector::from((5, 0))));
+/// ```
+#[must_use]
+pub fn result_debug_lines(
+    result: &RayIntersectionResult,
+    ray_origin: Vector,
+    ray_direction: Vector,
+) -> Vec<(Vector, Vector)> {
+    let contact_point = result.contact_point;
+    let horizontal = Vector::new(MARKER_HALF_SIZE, Fp::zero());
+    let vertical = Vector::new(Fp::zero(), MARKER_HALF_SIZE);
+
+    vec![
+        (ray_origin, ray_origin + ray_direction),
+        (contact_point, contact_point + result.contact_normal),
+        (contact_point - horizontal, contact_point + horizontal),
+        (contact_point - vertical, contact_point + vertical),
+    ]
+}
+
+/// The intermediate values of a [`ray_vs_rect`] query, returned by [`ray_vs_rect_debug`].
+#[derive(Debug, Clone)]
+pub struct RayDebug {
+    /// The time at which the ray enters the slab of the target on each axis.
+    pub time_near: Vector,
+    /// The time at which the ray leaves the slab of the target on each axis.
+    pub time_far: Vector,
+    /// `1 / ray_direction` for each axis, or [`Fp::MAX`] for a zero component.
+    pub inverted_direction: Vector,
+    /// The result of [`ray_vs_rect`] for the same query.
+    pub result: Option<RayIntersectionResult>,
+}
+
+/// Performs [`ray_vs_rect`] and returns the slab times it was decided from.
+///
+/// This is a diagnostics API for finding out why a cast hits or misses, it is slower than
+/// [`ray_vs_rect`] and not meant for hot paths. A hit has `closest_time` equal to the larger
+/// of the two `time_near` components. For an axis where the ray direction is zero the times
+/// are [`Fp::MIN`] and [`Fp::MAX`] if the origin lies within the slab, or [`Fp::MAX`] and
+/// [`Fp::MIN`] if it does not, so that the ray never enters the slab.
+///
+/// # Example
+///
+/// ```rust
+/// use fixed32::Fp;
+/// use fixed32_math::{Rect, Vector};
+/// use impact_rs::prelude::full::*;
+///
+/// let debug = ray_vs_rect_debug(Vector::from((0, 0)), Vector::from((2, 1)), Rect::from((4, 0, 4, 4)));
+///
+/// assert_eq!(debug.time_near, Vector::from((2, 0)));
+/// assert_eq!(debug.time_far, Vector::from((4, 4)));
+/// assert_eq!(debug.inverted_direction, Vector::from((0.5, 1.0)));
+/// assert_eq!(debug.result.unwrap().closest_time, Fp::from(2));
+/// ```
+#[must_use]
+pub fn ray_vs_rect_debug(ray_origin: Vector, ray_direction: Vector, target: Rect) -> RayDebug {
+    let normalized = normalize_rect(target);
+
+    let (near_x, far_x) = SlabAxis::new(ray_direction.x)
+        .times(
+            ray_origin.x,
+            ray_direction.x,
+            normalized.left(),
+            normalized.right(),
+        )
+        .unwrap_or((Fp::MAX, Fp::MIN));
+    let (near_y, far_y) = SlabAxis::new(ray_direction.y)
+        .times(
+            ray_origin.y,
+            ray_direction.y,
+            normalized.bottom(),
+            normalized.top(),
+        )
+        .unwrap_or((Fp::MAX, Fp::MIN));
+
+    RayDebug {
+        time_near: Vector::new(near_x, near_y),
+        time_far: Vector::new(far_x, far_y),
+        inverted_direction: Vector::new(inverted(ray_direction.x), inverted(ray_direction.y)),
+        result: ray_vs_rect(ray_origin, ray_direction, target),
+    }
+}
+
+fn inverted(direction: Fp) -> Fp {
+    if direction.is_zero() {
+        Fp::MAX
+    } else {
+        saturating_div(Fp::one(), direction)
+    }
+}
@@ -0,0 +1,61 @@
+/*
+ * Copyright (c) Peter Bjorklund. All rights reserved. https://github.com/piot/impact-rs
+ * Licensed under the MIT License. See LICENSE in the project root for license information.
+ */
+
+//! Conversions between the fixed-point types and [`glam`], enabled with the `glam` feature.
+//!
+//! Both [`Vector`] and [`glam::Vec2`] are defined in other crates, so the conversions are
+//! free functions rather than `From` implementations.
+//!
+//! <div class="warning">
+//!
+//! **Precision:** [`Fp`] is a 16.16 fixed-point number. Converting from `f32` truncates
+//! to the nearest `1/65536` towards zero and values outside roughly `±32767` overflow.
+//! Converting back to `f32` is exact for the fixed-point value, so a round trip is only
+//! accurate to about `1/65536`. Keep the simulation in fixed point and only convert at the
+//! rendering boundary.
+//!
+//! </div>
+
+use fixed32::Fp;
+use fixed32_math::{Rect, Vector};
+use glam::Vec2;
+
+/// Converts a fixed-point [`Vector`] to a [`glam::Vec2`].
+#[must_use]
+pub fn vector_to_vec2(v: Vector) -> Vec2 {
+    Vec2::new(f32::from(v.x), f32::from(v.y))
+}
+
+/// Converts a [`glam::Vec2`] to a fixed-point [`Vector`], truncating to the [`Fp`] resolution.
+#[must_use]
+pub fn vec2_to_vector(v: Vec2) -> Vector {
+    Vector::new(Fp::from(v.x), Fp::from(v.y))
+}
+
+/// Builds a [`Rect`] from `glam` min and max corners.
+///
+/// The corners may be given in any order; the resulting rectangle always has a
+/// non-negative size.
+///
+/// # Example
+///
+/// ```rust
+/// use fixed32_math::Rect;
+/// use glam::Vec2;
+/// use impact_rs::glam_interop::rect_from_vec2_min_max;
+///
+/// let rect = rect_from_vec2_min_max(Vec2::new(1.0, 2.0), Vec2::new(4.0, 6.0));
+/// assert_eq!(rect, Rect::from((1.0, 2.0, 3.0, 4.0)));
+/// ```
+#[must_use]
+pub fn rect_from_vec2_min_max(min: Vec2, max: Vec2) -> Rect {
+    let lower = vec2_to_vector(min.min(max));
+    let upper = vec2_to_vector(min.max(max));
+
+    Rect {
+        pos: lower,
+        size: upper - lower,
+    }
+}
@@ -0,0 +1,182 @@
+/*
+ * Copyright (c) Peter Bjorklund. All rights reserved. https://github.com/piot/impact-rs
+ * Licensed under the MIT License. See LICENSE in the project root for license information.
+ */
+
+//! A uniform grid that buckets collider ids by the cells their rectangles cover.
+
+use std::collections::HashMap;
+
+use fixed32::Fp;
+use fixed32_math::{Rect, Vector};
+
+use crate::rect_util::{normalize_rect, swept_aabb};
+use crate::tiles::world_to_tile;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct CellRange {
+    min: (i32, i32),
+    max: (i32, i32),
+}
+
+impl CellRange {
+    const fn contains(&self, cell: (i32, i32)) -> bool {
+        cell.0 >= self.min.0 && cell.0 <= self.max.0 && cell.1 >= self.min.1 && cell.1 <= self.max.1
+    }
+
+    fn cells(self) -> impl Iterator<Item = (i32, i32)> {
+        (self.min.1..=self.max.1).flat_map(move |y| (self.min.0..=self.max.0).map(move |x| (x, y)))
+    }
+}
+
+/// A broadphase grid mapping cells to the ids of the colliders that cover them.
+///
+/// A collider is stored in every cell that its rectangle touches, using the same cell
+/// coordinates as [`world_to_tile`]. A rectangle that ends exactly on a cell boundary is
+/// also stored in the cell after the boundary. The grid does not store the rectangles
+/// themselves, so the caller passes the rectangle a collider was inserted with when removing
+/// or moving it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SpatialGrid {
+    cell_size: Fp,
+    cells: HashMap<(i32, i32), Vec<u32>>,
+}
+
+impl SpatialGrid {
+    /// Creates an empty grid.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `cell_size` is not positive.
+    #[must_use]
+    pub fn new(cell_size: Fp) -> Self {
+        assert!(cell_size > Fp::zero(), "cell size must be positive");
+        Self {
+            cell_size,
+            cells: HashMap::new(),
+        }
+    }
+
+    #[must_use]
+    pub const fn cell_size(&self) -> Fp {
+        self.cell_size
+    }
+
+    /// Adds the collider `id` to every cell covered by `rect`.
+    pub fn insert(&mut self, id: u32, rect: Rect) {
+        for cell in self.cell_range(rect).cells() {
+            self.add_to_cell(cell, id);
+        }
+    }
+
+    /// Removes the collider `id` from every cell covered by `rect`.
+    ///
+    /// `rect` must be the rectangle that the collider was inserted or last moved with.
+    pub fn remove(&mut self, id: u32, rect: Rect) {
+        for cell in self.cell_range(rect).cells() {
+            self.remove_from_cell(cell, id);
+        }
+    }
+
+    /// Moves the collider `id` from `old` to `new`.
+    ///
+    /// Only the cells that are covered by one of the rectangles but not the other are
+    /// touched, so a collider that moves within its cells costs no updates at all. The
+    /// resulting grid is the same as after calling [`SpatialGrid::remove`] with `old` and
+    /// [`SpatialGrid::insert`] with `new`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fixed32::Fp;
+    /// use fixed32_math::Rect;
+    /// use impact_rs::grid::SpatialGrid;
+    ///
+    /// let mut grid = SpatialGrid::new(Fp::from(16));
+    /// let old = Rect::from((2, 2, 4, 4));
+    /// let new = Rect::from((18, 2, 4, 4));
+    ///
+    /// grid.insert(7, old);
+    /// grid.move_collider(7, old, new);
+    ///
+    /// assert!(grid.cell(0, 0).is_empty());
+    /// assert_eq!(grid.cell(1, 0), &[7]);
+    /// ```
+    pub fn move_collider(&mut self, id: u32, old: Rect, new: Rect) {
+        let old_range = self.cell_range(old);
+        let new_range = self.cell_range(new);
+
+        if old_range == new_range {
+            return;
+        }
+
+        for cell in old_range.cells().filter(|cell| !new_range.contains(*cell)) {
+            self.remove_from_cell(cell, id);
+        }
+
+        for cell in new_range.cells().filter(|cell| !old_range.contains(*cell)) {
+            self.add_to_cell(cell, id);
+        }
+    }
+
+    /// Returns the ids stored in the cell at `(x, y)`, in ascending order.
+    #[must_use]
+    pub fn cell(&self, x: i32, y: i32) -> &[u32] {
+        self.cells.get(&(x, y)).map_or(&[], Vec::as_slice)
+    }
+
+    /// Returns the ids stored in any of the cells covered by `area`.
+    ///
+    /// The ids are candidates only: a collider is included if it shares a cell with
+    /// `area`, even if the rectangles do not overlap.
+    ///
+    /// # Returns
+    ///
+    /// The candidate ids in ascending order, without duplicates.
+    #[must_use]
+    pub fn query(&self, area: Rect) -> Vec<u32> {
+        let mut ids: Vec<u32> = self
+            .cell_range(area)
+            .cells()
+            .flat_map(|(x, y)| self.cell(x, y).iter().copied())
+            .collect();
+        ids.sort_unstable();
+        ids.dedup();
+        ids
+    }
+
+    /// Returns the ids stored in any of the cells covered by `origin` moving along `delta`.
+    ///
+    /// This is [`SpatialGrid::query`] with the [`swept_aabb`] of the motion, so the result
+    /// contains every collider that a swept query of `origin` could hit.
+    #[must_use]
+    pub fn query_swept(&self, origin: Rect, delta: Vector) -> Vec<u32> {
+        self.query(swept_aabb(origin, delta))
+    }
+
+    fn cell_range(&self, rect: Rect) -> CellRange {
+        let rect = normalize_rect(rect);
+        CellRange {
+            min: world_to_tile(rect.pos, self.cell_size),
+            max: world_to_tile(rect.pos + rect.size, self.cell_size),
+        }
+    }
+
+    fn add_to_cell(&mut self, cell: (i32, i32), id: u32) {
+        let ids = self.cells.entry(cell).or_default();
+        if let Err(position) = ids.binary_search(&id) {
+            ids.insert(position, id);
+        }
+    }
+
+    fn remove_from_cell(&mut self, cell: (i32, i32), id: u32) {
+        if let Some(ids) = self.cells.get_mut(&cell) {
+            if let Ok(position) = ids.binary_search(&id) {
+                ids.remove(position);
+            }
+            if ids.is_empty() {
+                self.cells.remove(&cell);
+            }
+        }
+    }
+}
@@ -14,13 +14,386 @@ use std::cmp::{max, min, Ordering};
 use fixed32::Fp;
 use fixed32_math::{Rect, Vector};
 
+use crate::limits::delta_in_range;
+use crate::math::{
+    dot, length, normalize, raw_cross, raw_offset, raw_quotient, saturating_div, Rounding,
+};
+use crate::overlap::{contains_inclusive, corners, rect_penetration, rect_vs_rect};
+use crate::rect_util::normalize_rect;
+
+pub mod broadphase;
+pub mod bvh;
+pub mod checked;
+pub mod circle;
+pub mod collidable;
+pub mod cone;
+pub mod contact;
+pub mod debug;
+#[cfg(feature = "glam")]
+pub mod glam_interop;
+pub mod grid;
+pub mod limits;
+pub mod math;
+pub mod obb;
+pub mod overlap;
+pub mod polygon;
 pub mod prelude;
+pub mod ray;
+pub mod rect_util;
+pub mod response;
+pub mod segment;
+pub mod tiles;
+pub mod triangle;
+pub mod trig;
+pub mod world;
 
+/// The result of a successful intersection query.
+///
+/// The meaning of `closest_time` depends on the function that produced the result. For
+/// [`ray_vs_rect`] and the swept functions it is the parametric `t` along the supplied
+/// direction (or delta), so that `contact_point == origin + closest_time * direction`.
+/// It is only a world-space distance when the direction has unit length.
+/// [`ray_vs_rect_distance`] always reports a world-space distance.
+///
+/// Because `closest_time` can mean either, the swept functions also set `frame_fraction`
+/// to the fraction of the frame, in `[0, 1]`, that had passed at the contact. Ray casts leave
+/// it `None`, so a result can not be mistaken for a point in time during a frame.
 #[derive(Debug, Clone)]
 pub struct RayIntersectionResult {
     pub contact_point: Vector,
     pub contact_normal: Vector,
     pub closest_time: Fp,
+    pub face: ContactSide,
+    /// The fraction of the frame that had passed at the contact, or `None` if the result
+    /// comes from a ray cast that is not tied to a frame.
+    pub frame_fraction: Option<Fp>,
+}
+
+impl RayIntersectionResult {
+    /// Returns the part of a motion that is left after reaching the contact.
+    ///
+    /// This is `(1 - closest_time) * delta`, where `delta` is the motion that was swept to
+    /// produce this result. Adding it to the consumed part, `closest_time * delta`, gives back
+    /// `delta`. The leftover motion is what a response such as sliding along or reflecting off
+    /// the surface is applied to.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fixed32_math::{Rect, Vector};
+    /// use impact_rs::prelude::full::*;
+    ///
+    /// let origin = Rect::from((0, 0, 2, 2));
+    /// let delta = Vector::from((16, 0));
+    /// let result = swept_rect_vs_rect(origin, Rect::from((10, 0, 2, 2)), delta).unwrap();
+    ///
+    /// assert_eq!(result.remaining(delta), Vector::from((8, 0)));
+    /// ```
+    #[must_use]
+    pub fn remaining(&self, delta: Vector) -> Vector {
+        delta * (Fp::one() - self.closest_time)
+    }
+
+    /// Returns the contact normal, or `None` if it is zero.
+    ///
+    /// The `contact_normal` field is zero for exact corner hits, see [`CornerPolicy::None`].
+    /// Code that divides by the normal or uses it as a direction should go through this
+    /// accessor, so that the corner case has to be handled.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fixed32_math::{Rect, Vector};
+    /// use impact_rs::prelude::full::*;
+    ///
+    /// let target = Rect::from((5, 5, 10, 10));
+    ///
+    /// let face = ray_vs_rect(Vector::from((0, 6)), Vector::from((1, 0)), target).unwrap();
+    /// assert_eq!(face.normal().map(ContactNormal::vector), Some(Vector::right()));
+    ///
+    /// let corner = ray_vs_rect(Vector::from((0, 0)), Vector::from((1, 1)), target).unwrap();
+    /// assert_eq!(corner.normal(), None);
+    /// ```
+    #[must_use]
+    pub fn normal(&self) -> Option<ContactNormal> {
+        ContactNormal::new(self.contact_normal)
+    }
+
+    /// The number of bytes written by [`RayIntersectionResult::to_bytes`].
+    pub const PACKED_SIZE: usize = 26;
+
+    /// Packs the result into a fixed-size byte layout, for example to send it over the network.
+    ///
+    /// Every [`Fp`] is stored as its raw 32-bit value in little-endian byte order, so the
+    /// layout does not depend on the platform or on the `serde` feature:
+    ///
+    /// | Bytes    | Field              |
+    /// |----------|--------------------|
+    /// | `0..4`   | `contact_point.x`  |
+    /// | `4..8`   | `contact_point.y`  |
+    /// | `8..12`  | `contact_normal.x` |
+    /// | `12..16` | `contact_normal.y` |
+    /// | `16..20` | `closest_time`     |
+    /// | `20`     | `face`: 0 = `Left`, 1 = `Right`, 2 = `Top`, 3 = `Bottom`, 4 = `Corner` |
+    /// | `21`     | 1 if `frame_fraction` is `Some`, otherwise 0 |
+    /// | `22..26` | `frame_fraction`, or zero if it is `None` |
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fixed32_math::{Rect, Vector};
+    /// use impact_rs::prelude::full::*;
+    ///
+    /// let result = ray_vs_rect(Vector::from((0, 0)), Vector::from((1, 0)), Rect::from((4, -1, 2, 2))).unwrap();
+    ///
+    /// let bytes = result.to_bytes();
+    /// assert_eq!(bytes.len(), RayIntersectionResult::PACKED_SIZE);
+    ///
+    /// let unpacked = RayIntersectionResult::from_bytes(&bytes).unwrap();
+    /// assert_eq!(unpacked.contact_point, result.contact_point);
+    /// assert_eq!(unpacked.face, result.face);
+    /// ```
+    #[must_use]
+    pub fn to_bytes(&self) -> [u8; Self::PACKED_SIZE] {
+        let mut bytes = [0; Self::PACKED_SIZE];
+        let values = [
+            self.contact_point.x,
+            self.contact_point.y,
+            self.contact_normal.x,
+            self.contact_normal.y,
+            self.closest_time,
+        ];
+        for (chunk, value) in bytes.chunks_exact_mut(4).zip(values) {
+            chunk.copy_from_slice(&value.inner().to_le_bytes());
+        }
+        bytes[20] = match self.face {
+            ContactSide::Left => 0,
+            ContactSide::Right => 1,
+            ContactSide::Top => 2,
+            ContactSide::Bottom => 3,
+            ContactSide::Corner => 4,
+        };
+        if let Some(frame_fraction) = self.frame_fraction {
+            bytes[21] = 1;
+            bytes[22..26].copy_from_slice(&frame_fraction.inner().to_le_bytes());
+        }
+        bytes
+    }
+
+    /// Unpacks a result written by [`RayIntersectionResult::to_bytes`].
+    ///
+    /// Only the first [`RayIntersectionResult::PACKED_SIZE`] bytes are read, so a result can be
+    /// decoded from the start of a larger buffer.
+    ///
+    /// # Returns
+    ///
+    /// Returns `None` if `bytes` is shorter than [`RayIntersectionResult::PACKED_SIZE`] or if
+    /// the face or frame fraction flag byte is not one of the values listed in
+    /// [`RayIntersectionResult::to_bytes`].
+    #[must_use]
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        let bytes = bytes.get(..Self::PACKED_SIZE)?;
+        let raw = |start: usize| {
+            let mut value = [0; 4];
+            value.copy_from_slice(&bytes[start..start + 4]);
+            Fp::from_raw(i32::from_le_bytes(value))
+        };
+
+        let face = match bytes[20] {
+            0 => ContactSide::Left,
+            1 => ContactSide::Right,
+            2 => ContactSide::Top,
+            3 => ContactSide::Bottom,
+            4 => ContactSide::Corner,
+            _ => return None,
+        };
+
+        let frame_fraction = match bytes[21] {
+            0 => None,
+            1 => Some(raw(22)),
+            _ => return None,
+        };
+
+        Some(Self {
+            contact_point: Vector::new(raw(0), raw(4)),
+            contact_normal: Vector::new(raw(8), raw(12)),
+            closest_time: raw(16),
+            face,
+            frame_fraction,
+        })
+    }
+}
+
+/// A contact normal that is never zero.
+///
+/// The vector is normalized on construction, so it has unit length up to the precision of
+/// [`Fp`]. Query results keep `contact_normal` as a plain [`Vector`], which is zero for corner
+/// hits. Use [`RayIntersectionResult::normal`] to get it as a `ContactNormal`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ContactNormal(Vector);
+
+impl ContactNormal {
+    /// Creates a normal pointing along `v`, or returns `None` if `v` is zero. Vectors of any
+    /// length are accepted.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fixed32_math::Vector;
+    /// use impact_rs::ContactNormal;
+    ///
+    /// assert_eq!(ContactNormal::new(Vector::from((0, -3))).unwrap().vector(), Vector::down());
+    /// assert_eq!(ContactNormal::new(Vector::default()), None);
+    /// ```
+    #[must_use]
+    pub fn new(v: Vector) -> Option<Self> {
+        normalize(v).map(Self)
+    }
+
+    /// Returns the normal as a [`Vector`].
+    #[must_use]
+    pub const fn vector(self) -> Vector {
+        self.0
+    }
+}
+
+impl From<ContactNormal> for Vector {
+    fn from(normal: ContactNormal) -> Self {
+        normal.0
+    }
+}
+
+/// The side of the ray or moving shape that made contact.
+///
+/// The side matches the `contact_normal` of a [`RayIntersectionResult`]: a ray moving to
+/// the right that hits the left face of a target reports a normal of [`Vector::right`] and
+/// the side [`ContactSide::Right`]. Hits exactly on a corner of a rectangle report
+/// [`ContactSide::Corner`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ContactSide {
+    Left,
+    Right,
+    Top,
+    Bottom,
+    Corner,
+}
+
+impl ContactSide {
+    /// Classifies a contact normal by its dominant axis.
+    ///
+    /// Normals with equal magnitude on both axes, including the zero vector, are
+    /// classified as [`ContactSide::Corner`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fixed32_math::Vector;
+    /// use impact_rs::ContactSide;
+    ///
+    /// assert_eq!(ContactSide::from_normal(Vector::up()), ContactSide::Top);
+    /// assert_eq!(ContactSide::from_normal(Vector::from((1, 1))), ContactSide::Corner);
+    /// ```
+    #[must_use]
+    pub fn from_normal(normal: Vector) -> Self {
+        match normal.x.abs().cmp(&normal.y.abs()) {
+            Ordering::Greater => {
+                if normal.x > Fp::zero() {
+                    Self::Right
+                } else {
+                    Self::Left
+                }
+            }
+            Ordering::Less => {
+                if normal.y > Fp::zero() {
+                    Self::Top
+                } else {
+                    Self::Bottom
+                }
+            }
+            Ordering::Equal => Self::Corner,
+        }
+    }
+}
+
+/// Returns the face of the target that was hit.
+///
+/// The `face` and `contact_normal` of a [`RayIntersectionResult`] describe the side of the
+/// ray or moving shape that made contact, which is always opposite to the face of the target:
+/// a mover reporting [`ContactSide::Right`] hit the left face of the target. A corner hit
+/// stays [`ContactSide::Corner`].
+///
+/// # Example
+///
+/// ```rust
+/// use fixed32_math::{Rect, Vector};
+/// use impact_rs::prelude::full::*;
+///
+/// let floor = Rect::from((0, 0, 10, 2));
+/// let result = swept_rect_vs_rect(Rect::from((2, 4, 2, 2)), floor, Vector::from((0, -4))).unwrap();
+///
+/// assert_eq!(result.face, ContactSide::Bottom);
+/// assert_eq!(target_face(&result), ContactSide::Top);
+/// ```
+#[must_use]
+pub fn target_face(result: &RayIntersectionResult) -> ContactSide {
+    match result.face {
+        ContactSide::Left => ContactSide::Right,
+        ContactSide::Right => ContactSide::Left,
+        ContactSide::Top => ContactSide::Bottom,
+        ContactSide::Bottom => ContactSide::Top,
+        ContactSide::Corner => ContactSide::Corner,
+    }
+}
+
+/// How [`ray_vs_rect_with`] picks the contact normal when a ray hits a corner exactly.
+///
+/// A corner hit happens when the ray enters the rectangle through both slabs at the same
+/// time, so both the horizontal and the vertical face are equally valid.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum CornerPolicy {
+    /// Use the normal of the vertical face, as if the ray hit the side of the rectangle.
+    PreferX,
+    /// Use the normal of the horizontal face, as if the ray hit the top or bottom.
+    PreferY,
+    /// Use the normalized sum of both face normals.
+    Diagonal,
+    /// Report a zero normal. This is the behavior of [`ray_vs_rect`].
+    #[default]
+    None,
+}
+
+/// Whether a ray that only touches the boundary of a rectangle counts as a hit.
+///
+/// Tiles that share an edge also share its end points, so a ray passing exactly through
+/// such a point touches a tile it never enters. [`BoundaryMode::HalfOpen`] ignores those
+/// touches, so each tile is hit at most once along a row, while [`BoundaryMode::Closed`]
+/// reports them, so no tile the ray touches is missed.
+///
+/// Both modes report a ray running exactly along an edge, since it is parallel to the
+/// slab and never enters and leaves it at the same time.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum BoundaryMode {
+    /// A ray that enters and leaves the rectangle at the same time is a hit.
+    Closed,
+    /// A ray that enters and leaves the rectangle at the same time is not a hit. This is the
+    /// behavior of [`ray_vs_rect`].
+    #[default]
+    HalfOpen,
+}
+
+/// Which way the y-axis points in the coordinates passed to a query.
+///
+/// The crate works in [`CoordinateSpace::YUp`] coordinates, where a [`Rect`] is given by its
+/// lower-left corner and [`ContactSide::Top`] is the side with the larger y. Screen
+/// coordinates usually have the y-axis pointing down, where the same [`Rect`] fields describe
+/// the top-left corner and the top is the side with the smaller y.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum CoordinateSpace {
+    /// The y-axis points up. This is what all queries assume.
+    #[default]
+    YUp,
+    /// The y-axis points down, as in screen coordinates.
+    YDown,
 }
 
 /// Checks for intersection between a swept rectangle and a target rectangle.
@@ -30,231 +403,1693 @@ pub struct RayIntersectionResult {
 /// during its motion. The swept rectangle is calculated based on the initial
 /// lower-left position and size of the origin rectangle, expanded to account for its
 /// movement. The function performs a ray-rectangle intersection test to
-/// check if there is an intersection within the valid time range.
+/// check if there is an intersection within the valid time range. Targets that the
+/// bounding box of the motion cannot reach are rejected before the intersection test.
+///
+/// The target is grown by the size of the origin rectangle and the ray is cast from the
+/// upper-right corner of the origin, the same as in [`swept_rect_vs_rect_horizontal_time`]
+/// and [`swept_rect_vs_rect_vertical_time`], so a motion along one axis reports the same
+/// time as the matching axis function. That includes the half-open vertical range of
+/// [`swept_rect_vs_rect_horizontal_time`]: a rectangle moving horizontally with its bottom
+/// exactly on the top of `target` slides over it without a contact.
+///
+/// # Parameters
+///
+/// - `origin`: A [`Rect`] representing the starting rectangle
+///   that is swept along a direction and length defined by the `delta` vector.
+/// - `target`: A [`Rect`] representing the target rectangle
+///   to check for intersection with the swept rectangle.
+/// - `delta`: The vector representing the movement direction and magnitude
+///   of the `origin` rectangle. This vector determines how far and in which
+///   direction the origin rectangle is moved.
+///
+/// # Returns
+///
+/// Returns `Some(RayIntersectionResult)` if there is an intersection between
+/// the swept rectangle and the target rectangle within the valid time range.
+///
+/// The [`RayIntersectionResult`] contains information about the intersection
+/// point and other related details. If there is no intersection or the
+/// intersection does not occur within the valid time range, `None` is returned.
+///
+/// Returns `None` without testing if a component of `delta` is larger than
+/// [`MAX_SWEEP_DELTA`](crate::limits::MAX_SWEEP_DELTA), where the time could no longer place
+/// the contact precisely.
+///
+/// A zero `delta` can not be cast as a ray, so the rectangles are tested for a static overlap
+/// with [`rect_vs_rect`](crate::overlap::rect_vs_rect) instead. If they overlap, the result
+/// has a `closest_time` of zero and the `contact_normal` of the axis along which `origin`
+/// would leave `target` the soonest, pointing into `target`.
+///
+/// A rectangle that starts flush against `target` and moves into it makes contact at a
+/// `closest_time` of zero, with the `contact_normal` of the face it touches. This is also the
+/// result when the gap is too small for the time to resolve, so a mover resting against a
+/// wall always learns which way the wall is. The only exception is a rectangle touching
+/// `target` at a corner and moving into that corner, which is a corner hit with a zero
+/// normal, see [`CornerPolicy::None`].
+///
+/// # Example
+///
+/// ```rust
+/// use fixed32_math::{Rect, Vector};
+/// use impact_rs::prelude::*;
+///
+/// let origin = Rect::from((0.0, 0.0, 10.0, 10.0));
+/// let target = Rect::from((5.0, 5.0, 10.0, 10.0));
+/// let delta = Vector::from((15.0, 15.0));
+///
+/// match swept_rect_vs_rect(origin, target, delta) {
+///     Some(result) => {
+///         println!("Intersection found: {:?}", result);
+///     }
+///     None => {
+///         println!("No intersection.");
+///     }
+/// }
+/// ```
+#[must_use]
+pub fn swept_rect_vs_rect(
+    origin: Rect,
+    target: Rect,
+    delta: Vector,
+) -> Option<RayIntersectionResult> {
+    swept_rect_vs_rect_in_range(origin, target, delta, false)
+}
+
+/// Checks for intersection between a swept rectangle and a target rectangle, including
+/// contacts exactly at the end of the motion.
+///
+/// This function is identical to [`swept_rect_vs_rect`], except that the valid time
+/// range is the closed interval `[0, 1]` instead of the half-open `[0, 1)`.
+///
+/// The default stays half-open because a contact at exactly `t = 1` is also a contact at
+/// `t = 0` of the next frame, so a simulation that sweeps every frame would otherwise see the
+/// same contact twice. The inclusive version is useful when the motion is known to end flush
+/// against a surface, for example when moving into a corridor that is exactly as wide as
+/// the mover, where dropping the contact lets the mover tunnel one frame into the wall.
+///
+/// # Parameters
+///
+/// - `origin`: A [`Rect`] representing the starting rectangle.
+/// - `target`: A [`Rect`] representing the target rectangle.
+/// - `delta`: The movement of the `origin` rectangle.
+///
+/// # Returns
+///
+/// Returns `Some(RayIntersectionResult)` if there is an intersection within the closed
+/// time range `[0, 1]`, otherwise `None`.
+///
+/// # Example
+///
+/// ```rust
+/// use fixed32::Fp;
+/// use fixed32_math::{Rect, Vector};
+/// use impact_rs::prelude::full::*;
+///
+/// let origin = Rect::from((0, 0, 2, 2));
+/// let target = Rect::from((11, 0, 2, 2));
+/// let delta = Vector::from((18, 0));
+///
+/// let result = swept_rect_vs_rect_inclusive(origin, target, delta).unwrap();
+/// assert_eq!(result.closest_time, Fp::from(0.5));
+/// ```
+#[must_use]
+pub fn swept_rect_vs_rect_inclusive(
+    origin: Rect,
+    target: Rect,
+    delta: Vector,
+) -> Option<RayIntersectionResult> {
+    swept_rect_vs_rect_in_range(origin, target, delta, true)
+}
+
+/// Sweeps a rectangle from `origin` to `end` against a target rectangle.
+///
+/// This is [`swept_rect_vs_rect`] with `delta = end.pos - origin.pos`, for callers that know
+/// where the rectangle should end up rather than how far it moves. Both rectangles are
+/// normalized first, so the delta is between their lower-left corners even if one of them
+/// has a negative size.
+///
+/// # Panics
+///
+/// Panics if `origin` and `end` do not have the same size. A sweep moves the rectangle
+/// without resizing it.
+///
+/// # Example
+///
+/// ```rust
+/// use fixed32::Fp;
+/// use fixed32_math::{Rect, Vector};
+/// use impact_rs::prelude::full::*;
+///
+/// let origin = Rect::from((0, 0, 2, 2));
+/// let end = Rect::from((16, 0, 2, 2));
+/// let target = Rect::from((10, 0, 2, 2));
+///
+/// let result = swept_rect_to(origin, end, target).unwrap();
+/// assert_eq!(result.closest_time, Fp::from(0.5));
+/// ```
+#[must_use]
+pub fn swept_rect_to(origin: Rect, end: Rect, target: Rect) -> Option<RayIntersectionResult> {
+    let origin = normalize_rect(origin);
+    let end = normalize_rect(end);
+    assert_eq!(
+        origin.size, end.size,
+        "origin and end must have the same size"
+    );
+
+    swept_rect_vs_rect(origin, target, end.pos - origin.pos)
+}
+
+/// Sweeps a rectangle against a slice of rectangles and returns the earliest contact.
+///
+/// Every target is tested with [`swept_rect_vs_rect`] and the contact with the smallest
+/// `closest_time` is kept. If two targets are contacted at the same time, the one with the
+/// lowest index wins. Targets that the mover is not moving toward, as decided by
+/// [`is_moving_toward`], are skipped without running the sweep, since they can not be hit.
+///
+/// # Parameters
+///
+/// - `origin`: The rectangle at the start of the motion.
+/// - `targets`: The static rectangles to test against.
+/// - `delta`: The movement of `origin`.
+///
+/// # Returns
+///
+/// Returns `Some((index, RayIntersectionResult))` for the earliest contact, where `index` is
+/// the position of the contacted rectangle in `targets`. Returns `None` if no target is hit.
+///
+/// # Example
+///
+/// ```rust
+/// use fixed32::Fp;
+/// use fixed32_math::{Rect, Vector};
+/// use impact_rs::prelude::full::*;
+///
+/// let targets = [Rect::from((-10, 0, 2, 2)), Rect::from((12, 0, 2, 2)), Rect::from((6, 0, 2, 2))];
+///
+/// let (index, result) = swept_rect_vs_rects(Rect::from((0, 0, 2, 2)), &targets, Vector::from((16, 0))).unwrap();
+/// assert_eq!(index, 2);
+/// assert_eq!(result.closest_time, Fp::from(0.25));
+/// ```
+#[must_use]
+pub fn swept_rect_vs_rects(
+    origin: Rect,
+    targets: &[Rect],
+    delta: Vector,
+) -> Option<(usize, RayIntersectionResult)> {
+    let mut closest: Option<(usize, RayIntersectionResult)> = None;
+
+    for (index, target) in targets.iter().enumerate() {
+        if !is_moving_toward(origin, *target, delta) {
+            continue;
+        }
+
+        if let Some(result) = swept_rect_vs_rect(origin, *target, delta) {
+            let is_closer = closest
+                .as_ref()
+                .is_none_or(|(_, best)| result.closest_time < best.closest_time);
+            if is_closer {
+                closest = Some((index, result));
+            }
+        }
+    }
+
+    closest
+}
+
+/// Sweeps a rectangle against a slice of rectangles and returns every contact, earliest first.
+///
+/// This is the swept version of [`ray_vs_rects_all`]: every target is tested with
+/// [`swept_rect_vs_rect`], so each target that the motion reaches during `[0, 1)` is
+/// reported once, at the time it is first contacted. The motion is not stopped by a contact,
+/// which suits a projectile that passes through everything in its path. Contacts at the same
+/// time are sorted by ascending index.
+///
+/// # Parameters
+///
+/// - `origin`: The rectangle at the start of the motion.
+/// - `targets`: The static rectangles to test against.
+/// - `delta`: The movement of `origin`.
+///
+/// # Returns
+///
+/// The `(index, RayIntersectionResult)` of every contacted target, sorted by
+/// `closest_time`.
+///
+/// # Example
+///
+/// ```rust
+/// use fixed32_math::{Rect, Vector};
+/// use impact_rs::prelude::full::*;
+///
+/// let targets = [Rect::from((12, 0, 2, 2)), Rect::from((-10, 0, 2, 2)), Rect::from((6, 0, 2, 2))];
+///
+/// let contacts = swept_rect_vs_rects_all(Rect::from((0, 0, 2, 2)), &targets, Vector::from((16, 0)));
+/// let order: Vec<usize> = contacts.iter().map(|(index, _)| *index).collect();
+/// assert_eq!(order, [2, 0]);
+/// ```
+#[must_use]
+pub fn swept_rect_vs_rects_all(
+    origin: Rect,
+    targets: &[Rect],
+    delta: Vector,
+) -> Vec<(usize, RayIntersectionResult)> {
+    let mut contacts: Vec<(usize, RayIntersectionResult)> = targets
+        .iter()
+        .enumerate()
+        .filter(|(_, target)| is_moving_toward(origin, **target, delta))
+        .filter_map(|(index, target)| {
+            swept_rect_vs_rect(origin, *target, delta).map(|result| (index, result))
+        })
+        .collect();
+    contacts.sort_unstable_by_key(|(index, result)| (result.closest_time, *index));
+
+    contacts
+}
+
+/// Checks if a motion of `origin` along `delta` could reach `target`.
+///
+/// On each axis where the rectangles are apart, `delta` must reduce the gap between them.
+/// A mover that only touches the target along an edge can still slide along it, so a zero
+/// component of `delta` counts as moving toward a target that touches `origin` on that
+/// axis. Rectangles whose projections overlap on an axis place no requirement on that axis.
+///
+/// This is a cheap test for skipping receding targets before a sweep: when it returns
+/// `false`, [`swept_rect_vs_rect`] would not report a contact either.
+///
+/// # Example
+///
+/// ```rust
+/// use fixed32_math::{Rect, Vector};
+/// use impact_rs::prelude::full::*;
+///
+/// let origin = Rect::from((0, 0, 2, 2));
+/// let wall = Rect::from((6, -4, 2, 10));
+///
+/// assert!(is_moving_toward(origin, wall, Vector::from((1, 5))));
+/// assert!(!is_moving_toward(origin, wall, Vector::from((-1, 5))));
+/// ```
+#[must_use]
+pub fn is_moving_toward(origin: Rect, target: Rect, delta: Vector) -> bool {
+    let origin = normalize_rect(origin);
+    let target = normalize_rect(target);
+
+    axis_moving_toward(
+        origin.left(),
+        origin.right(),
+        target.left(),
+        target.right(),
+        delta.x,
+    ) && axis_moving_toward(
+        origin.bottom(),
+        origin.top(),
+        target.bottom(),
+        target.top(),
+        delta.y,
+    )
+}
+
+fn axis_moving_toward(min: Fp, max: Fp, target_min: Fp, target_max: Fp, delta: Fp) -> bool {
+    if target_min >= max {
+        delta > Fp::zero() || (delta.is_zero() && target_min == max)
+    } else if target_max <= min {
+        delta < Fp::zero() || (delta.is_zero() && target_max == min)
+    } else {
+        true
+    }
+}
+
+fn swept_rect_vs_rect_in_range(
+    origin: Rect,
+    target: Rect,
+    delta: Vector,
+    include_end: bool,
+) -> Option<RayIntersectionResult> {
+    if !delta_in_range(delta) {
+        return None;
+    }
+
+    let origin = normalize_rect(origin);
+    let target = normalize_rect(target);
+
+    let expanded_target = Rect {
+        pos: target.pos,
+        size: target.size + origin.size,
+    };
+
+    let origin_point = origin.pos + origin.size;
+
+    if delta.x.is_zero() && delta.y.is_zero() {
+        return static_overlap_result(origin, target, origin_point);
+    }
+
+    // A horizontal motion uses the half-open vertical range of
+    // `swept_rect_vs_rect_horizontal_time`, so a rectangle sliding along the top of a floor
+    // does not hit the next floor tile
+    if delta.y.is_zero() && origin_point.y >= expanded_target.top() {
+        return None;
+    }
+
+    if sweep_misses_rect(origin_point, delta, expanded_target) {
+        return None;
+    }
+
+    let maybe_intersected = ray_vs_rect(origin_point, delta, expanded_target);
+    if let Some(result) = maybe_intersected {
+        let time = result.closest_time;
+        let before_end = if include_end {
+            time <= Fp::one()
+        } else {
+            time < Fp::one()
+        };
+        if time >= Fp::zero() && before_end {
+            return Some(RayIntersectionResult {
+                frame_fraction: Some(time),
+                ..result
+            });
+        }
+    }
+
+    None
+}
+
+/// Reports an overlap of `origin` and `target` as a contact at time zero.
+///
+/// The contact normal is the opposite of the direction of the minimum translation vector
+/// from [`rect_penetration`](crate::overlap::rect_penetration), so it points from `origin`
+/// into `target`, as if `origin` had moved into it along that axis.
+fn static_overlap_result(
+    origin: Rect,
+    target: Rect,
+    origin_point: Vector,
+) -> Option<RayIntersectionResult> {
+    let translation = rect_penetration(origin, target)?;
+
+    let contact_normal = Vector::new(
+        Fp::from_raw(-translation.x.inner().signum() * Fp::SCALE),
+        Fp::from_raw(-translation.y.inner().signum() * Fp::SCALE),
+    );
+
+    Some(RayIntersectionResult {
+        contact_point: origin_point,
+        contact_normal,
+        closest_time: Fp::zero(),
+        face: ContactSide::from_normal(contact_normal),
+        frame_fraction: Some(Fp::zero()),
+    })
+}
+
+/// Checks if a motion from `start` along `delta` can not possibly touch `target`.
+///
+/// This compares the bounding box of the motion with the target. The box is padded to cover the
+/// rounding of the slab times in [`ray_vs_rect`] with a margin that grows with the square of
+/// the delta, so a `false` result never hides a contact that the full slab test would report.
+fn sweep_misses_rect(start: Vector, delta: Vector, target: Rect) -> bool {
+    axis_misses_range(start.x, delta.x, target.left(), target.right())
+        || axis_misses_range(start.y, delta.y, target.bottom(), target.top())
+}
+
+fn axis_misses_range(start: Fp, delta: Fp, min: Fp, max: Fp) -> bool {
+    // In 64 bits, so that the end of a long motion near the edge of the range does not overflow
+    let start = i64::from(start.inner());
+    let delta = i64::from(delta.inner());
+    let margin = ((delta * delta) >> 32) + 2;
+
+    let (low, high) = if delta < 0 {
+        (start + delta, start)
+    } else {
+        (start, start + delta)
+    };
+
+    high + margin < i64::from(min.inner()) || low - margin > i64::from(max.inner())
+}
+
+/// Computes the time interval during which a swept rectangle overlaps a target rectangle.
+///
+/// For each axis, the slab entry and exit times are the times at which the projections of
+/// the moving `origin` and the `target` start and stop overlapping. The rectangles overlap
+/// while both axes do, from the latest entry to the earliest exit. Unlike
+/// [`swept_rect_vs_rect`], which only reports the first contact, this also tells how long the
+/// rectangles stay overlapped, for example how long a mover spends inside a trigger volume.
+///
+/// # Parameters
+///
+/// - `origin`: The rectangle at the start of the motion.
+/// - `target`: The static rectangle to test against.
+/// - `delta`: The movement of `origin`.
+///
+/// # Returns
+///
+/// Returns `Some((entry_time, exit_time))` clamped to `[0, 1]`, where `entry_time` is zero if
+/// the rectangles already overlap at the start of the motion. Returns `None` if the
+/// rectangles never overlap during the motion. Rectangles that only touch, as defined by
+/// [`rect_vs_rect`](crate::overlap::rect_vs_rect), do not overlap.
+/// Also returns `None` if a component of `delta` is larger than
+/// [`MAX_SWEEP_DELTA`](crate::limits::MAX_SWEEP_DELTA).
+///
+/// # Example
+///
+/// ```rust
+/// use fixed32::Fp;
+/// use fixed32_math::{Rect, Vector};
+/// use impact_rs::prelude::full::*;
+///
+/// let origin = Rect::from((0, 0, 2, 2));
+/// let target = Rect::from((4, 0, 2, 2));
+///
+/// let (entry, exit) = swept_rect_overlap_interval(origin, target, Vector::from((8, 0))).unwrap();
+/// assert_eq!(entry, Fp::from(0.25));
+/// assert_eq!(exit, Fp::from(0.75));
+/// ```
+#[must_use]
+pub fn swept_rect_overlap_interval(origin: Rect, target: Rect, delta: Vector) -> Option<(Fp, Fp)> {
+    if !delta_in_range(delta) {
+        return None;
+    }
+
+    let origin = normalize_rect(origin);
+    let target = normalize_rect(target);
+
+    let (entry_x, exit_x) = axis_overlap_interval(
+        origin.left(),
+        origin.right(),
+        delta.x,
+        target.left(),
+        target.right(),
+    )?;
+    let (entry_y, exit_y) = axis_overlap_interval(
+        origin.bottom(),
+        origin.top(),
+        delta.y,
+        target.bottom(),
+        target.top(),
+    )?;
+
+    let entry_time = max(entry_x, entry_y);
+    let exit_time = min(exit_x, exit_y);
+
+    if entry_time >= exit_time || exit_time <= Fp::zero() || entry_time >= Fp::one() {
+        return None;
+    }
+
+    Some((max(entry_time, Fp::zero()), min(exit_time, Fp::one())))
+}
+
+/// Computes when a moving rectangle enters and leaves a trigger volume during a frame.
+///
+/// A trigger does not block the motion, so the mover can pass through it. This is
+/// [`swept_rect_overlap_interval`] under the name that trigger code looks for: the mover is
+/// inside the trigger from `enter` to `exit`. Only touching the edge of the trigger does not
+/// count as being inside.
+///
+/// # Parameters
+///
+/// - `origin`: The moving rectangle at the start of the frame.
+/// - `trigger`: The trigger volume.
+/// - `delta`: The movement of `origin` during the frame.
+///
+/// # Returns
+///
+/// Returns `Some((enter, exit))` as fractions of the frame in `[0, 1]`. `enter` is zero if the
+/// mover starts inside the trigger, and `exit` is one if it is still inside at the end of the
+/// frame. Returns `None` if the mover is never inside the trigger during the frame.
+///
+/// # Example
+///
+/// ```rust
+/// use fixed32::Fp;
+/// use fixed32_math::{Rect, Vector};
+/// use impact_rs::prelude::full::*;
+///
+/// let trigger = Rect::from((4, -10, 4, 20));
+/// let origin = Rect::from((0, 0, 2, 2));
+///
+/// let (enter, exit) = swept_rect_vs_trigger(origin, trigger, Vector::from((8, 0))).unwrap();
+/// assert_eq!((enter, exit), (Fp::from(0.25), Fp::one()));
+/// ```
+#[must_use]
+pub fn swept_rect_vs_trigger(origin: Rect, trigger: Rect, delta: Vector) -> Option<(Fp, Fp)> {
+    swept_rect_overlap_interval(origin, trigger, delta)
+}
+
+/// Finds when two moving rectangles first overlap by testing them at evenly spaced times.
+///
+/// Both rectangles move during the frame, `origin` by `origin_delta` and `target` by
+/// `target_delta`. The positions are tested with [`rect_vs_rect`](crate::overlap::rect_vs_rect)
+/// at the times `i / samples` for `i` in `0..=samples`, so the start and the end of the
+/// frame are always included. Testing at several times catches two thin, fast objects that
+/// cross paths during the frame even though neither their start nor their end positions
+/// overlap.
+///
+/// More samples cost more time and give a finer answer: the cost grows linearly with
+/// `samples`, the reported time is at most `1 / samples` later than the real first overlap,
+/// and an overlap that lasts less than `1 / samples` of the frame can fall between two
+/// samples and be missed. [`swept_rect_overlap_interval`] with the relative motion
+/// `origin_delta - target_delta` finds the exact interval, this function trades that
+/// precision for a test that only needs rectangle overlaps.
+///
+/// # Parameters
+///
+/// - `origin`: The first rectangle at the start of the frame.
+/// - `origin_delta`: The movement of `origin` during the frame.
+/// - `target`: The second rectangle at the start of the frame.
+/// - `target_delta`: The movement of `target` during the frame.
+/// - `samples`: The number of intervals the frame is split into. Zero only tests the start.
+///
+/// # Returns
+///
+/// Returns `Some(time)` with the earliest sampled time in `[0, 1]` at which the rectangles
+/// overlap, or `None` if they do not overlap at any sample.
+///
+/// # Example
+///
+/// ```rust
+/// use fixed32::Fp;
+/// use fixed32_math::{Rect, Vector};
+/// use impact_rs::prelude::full::*;
+///
+/// let origin = Rect::from((0, 0, 2, 2));
+/// let target = Rect::from((9, 0, 2, 2));
+///
+/// let time = anti_tunnel_sweep(origin, Vector::from((8, 0)), target, Vector::from((-8, 0)), 4);
+/// assert_eq!(time, Some(Fp::from(0.5)));
+/// ```
+#[must_use]
+pub fn anti_tunnel_sweep(
+    origin: Rect,
+    origin_delta: Vector,
+    target: Rect,
+    target_delta: Vector,
+    samples: u32,
+) -> Option<Fp> {
+    let relative_delta = origin_delta - target_delta;
+
+    (0..=samples).find_map(|sample| {
+        let time = if samples == 0 {
+            Fp::zero()
+        } else {
+            Fp::from_raw((i64::from(sample) * Fp::SCALE_I64 / i64::from(samples)) as i32)
+        };
+        rect_vs_rect(origin.move_by(relative_delta * time), target).then_some(time)
+    })
+}
+
+/// Returns the slab entry and exit times of a moving range against a static range.
+fn axis_overlap_interval(
+    start_min: Fp,
+    start_max: Fp,
+    delta: Fp,
+    target_min: Fp,
+    target_max: Fp,
+) -> Option<(Fp, Fp)> {
+    match delta.cmp(&Fp::zero()) {
+        Ordering::Greater => Some((
+            saturating_div(target_min - start_max, delta),
+            saturating_div(target_max - start_min, delta),
+        )),
+        Ordering::Less => Some((
+            saturating_div(target_max - start_min, delta),
+            saturating_div(target_min - start_max, delta),
+        )),
+        Ordering::Equal => {
+            if start_min < target_max && start_max > target_min {
+                Some((Fp::MIN, Fp::MAX))
+            } else {
+                None
+            }
+        }
+    }
+}
+
+/// Checks when a moving rectangle first touches a static ray.
+///
+/// This is the dual of [`swept_rect_vs_rect`]: the ray stays in place, for example a
+/// sensor, and `target` moves by `target_delta` during the frame. The ray covers the segment
+/// from `ray_origin` to `ray_origin + ray_direction`. The first contact is the earliest of a
+/// ray end point entering the moving rectangle and a corner of the rectangle crossing the ray.
+///
+/// # Parameters
+///
+/// - `ray_origin`: The origin point of the ray.
+/// - `ray_direction`: The ray, its length is the reach of the ray.
+/// - `target`: The rectangle at the start of its motion.
+/// - `target_delta`: The movement of `target` during the frame.
+///
+/// # Returns
+///
+/// Returns `Some((time, result))` if the rectangle touches the ray within the time range
+/// `[0, 1)`, where `time` is zero if they already touch at the start of the motion. The
+/// `result` is [`ray_vs_rect`] against the rectangle moved to `time`, with two exceptions:
+/// if the ray starts inside the rectangle, the contact is at `ray_origin` with a
+/// `closest_time` of zero, and if the rectangle only grazes the ray, the `face` is
+/// [`ContactSide::Corner`] with a zero `contact_normal`.
+///
+/// Returns `None` if the rectangle does not touch the ray during the motion.
+///
+/// # Example
+///
+/// ```rust
+/// use fixed32::Fp;
+/// use fixed32_math::{Rect, Vector};
+/// use impact_rs::prelude::full::*;
+///
+/// let ray_origin = Vector::from((0, 0));
+/// let ray_direction = Vector::from((8, 0));
+/// let falling = Rect::from((4, 4, 2, 2));
+///
+/// let (time, result) =
+///     ray_vs_swept_rect(ray_origin, ray_direction, falling, Vector::from((0, -8))).unwrap();
+/// assert_eq!(time, Fp::from(0.5));
+/// assert_eq!(result.contact_point, Vector::from((4, 0)));
+/// ```
+#[must_use]
+pub fn ray_vs_swept_rect(
+    ray_origin: Vector,
+    ray_direction: Vector,
+    target: Rect,
+    target_delta: Vector,
+) -> Option<(Fp, RayIntersectionResult)> {
+    let target = normalize_rect(target);
+    let in_frame = |time: &Fp| *time >= Fp::zero() && *time < Fp::one();
+
+    // Seen from the target, the ray end points move by `-target_delta`
+    let end_point_contacts = [
+        (ray_origin, Fp::zero()),
+        (ray_origin + ray_direction, Fp::one()),
+    ]
+    .into_iter()
+    .filter_map(|(point, ray_time)| {
+        if contains_inclusive(target, point) {
+            return Some((Fp::zero(), ray_time));
+        }
+        ray_vs_rect(point, -target_delta, target)
+            .map(|result| result.closest_time)
+            .filter(in_frame)
+            .map(|time| (time, ray_time))
+    });
+
+    // Solves `corner + time * target_delta == ray_origin + ray_time * ray_direction` on the raw
+    // values, as the cross products overflow `Fp` for long rays and motions
+    let raw_delta = raw_offset(Vector::default(), target_delta);
+    let raw_direction = raw_offset(Vector::default(), ray_direction);
+    let denominator = raw_cross(raw_delta, raw_direction);
+    let scale = i128::from(Fp::SCALE_I64);
+    let corner_contacts = corners(target)
+        .into_iter()
+        .filter(|_| denominator != 0)
+        .filter_map(|corner| {
+            let offset = raw_offset(corner, ray_origin);
+            // Rounding up places the moved target on the ray rather than just short of it
+            let time = raw_quotient(
+                raw_cross(offset, raw_direction),
+                denominator,
+                Rounding::Ceil,
+            );
+            let ray_time = raw_quotient(raw_cross(offset, raw_delta), denominator, Rounding::Floor);
+            ((0..scale).contains(&time) && (0..=scale).contains(&ray_time))
+                .then(|| (Fp::from_raw(time as i32), Fp::from_raw(ray_time as i32)))
+        });
+
+    // The ray can already cross the target without an end point inside it
+    let crossing = ray_vs_rect(ray_origin, ray_direction, target)
+        .filter(|result| result.closest_time >= Fp::zero() && result.closest_time <= Fp::one())
+        .map(|result| (Fp::zero(), result.closest_time));
+
+    let (time, ray_time) = end_point_contacts
+        .chain(corner_contacts)
+        .chain(crossing)
+        .min_by_key(|(time, _)| *time)?;
+
+    let moved = target.move_by(target_delta * time);
+    let result = match ray_vs_rect(ray_origin, ray_direction, moved) {
+        Some(result) if result.closest_time >= Fp::zero() => RayIntersectionResult {
+            frame_fraction: Some(time),
+            ..result
+        },
+        // The ray starts inside the target
+        Some(result) => RayIntersectionResult {
+            contact_point: ray_origin,
+            closest_time: Fp::zero(),
+            frame_fraction: Some(time),
+            ..result
+        },
+        // The target only grazes the ray
+        None => RayIntersectionResult {
+            contact_point: ray_origin + ray_direction * ray_time,
+            contact_normal: Vector::default(),
+            closest_time: ray_time,
+            face: ContactSide::Corner,
+            frame_fraction: Some(time),
+        },
+    };
+
+    Some((time, result))
+}
+
+/// Performs a ray-rectangle intersection test.
+///
+/// This function determines if a ray intersects with a given rectangle. The ray
+/// is defined by its origin and direction, and the rectangle is defined by its
+/// lower-left position and size. The function computes the intersection time and contact
+/// point if an intersection occurs.
+///
+/// # Parameters
+///
+/// - `ray_origin`: The origin point of the ray as a [`Vector`]. This is where
+///   the ray starts.
+/// - `ray_direction`: The ray as a [`Vector`]. This indicates
+///   the direction and length in which the ray is cast. The direction vector must not be zero.
+/// - `target`: The [`Rect`] representing the target rectangle to test for intersection.
+///   It is defined by its lower-left position and size.
+///
+/// # Returns
+///
+/// Returns `Some(RayIntersectionResult)` if there is an intersection between
+/// the ray and the rectangle. The [`RayIntersectionResult`] includes:
+/// - `contact_point`: The point of intersection between the ray and the rectangle.
+/// - `contact_normal`: The normal vector of the rectangle at the point of intersection.
+/// - `face`: The [`ContactSide`] matching `contact_normal`, or [`ContactSide::Corner`] if the
+///   ray hits a corner exactly. Corner hits report a zero `contact_normal`, use
+///   [`ray_vs_rect_with`] to pick a normal instead.
+/// - `closest_time`: The parametric time `t` along the ray at which the intersection occurs, so
+///   that `contact_point == ray_origin + t * ray_direction`. The time is measured in multiples
+///   of `ray_direction`, not in world units, so it is only a distance if `ray_direction` has
+///   unit length. Use [`ray_vs_rect_distance`] to get a world-space distance instead.
+///
+/// Returns `None` if there is no intersection or if the ray direction is zero.
+///
+/// A ray that starts exactly on a face of the rectangle and moves into it hits at time zero,
+/// with the normal of that face. A ray that starts on a face and moves out of the rectangle
+/// is not reported.
+///
+/// # Example
+///
+/// ```rust
+/// use fixed32_math::{Rect, Vector};
+/// use impact_rs::prelude::*;
+///
+/// let ray_origin = Vector::from((0.0, 0.0));
+/// let ray_direction = Vector::from((1.0, 1.0));
+/// let target = Rect::from((5.0, 5.0, 10.0, 10.0));
+///
+/// match ray_vs_rect(ray_origin, ray_direction, target) {
+///     Some(result) => {
+///         println!("Intersection found at point: {:?}", result.contact_point);
+///         println!("Contact normal: {:?}", result.contact_normal);
+///         println!("Intersection time: {:?}", result.closest_time);
+///     }
+///     None => {
+///         println!("No intersection.");
+///     }
+/// }
+/// ```
+#[must_use]
+pub fn ray_vs_rect(
+    ray_origin: Vector,
+    ray_direction: Vector,
+    target: Rect,
+) -> Option<RayIntersectionResult> {
+    ray_vs_rect_with(ray_origin, ray_direction, target, CornerPolicy::default())
+}
+
+/// Performs a ray-rectangle intersection test, resolving exact corner hits with `policy`.
+///
+/// This function behaves exactly as [`ray_vs_rect`], except that the `contact_normal` of a
+/// ray that hits a corner exactly is chosen by the supplied [`CornerPolicy`]. The `face` of
+/// a corner hit is always [`ContactSide::Corner`], regardless of the policy.
+///
+/// # Parameters
+///
+/// - `ray_origin`: The origin point of the ray as a [`Vector`].
+/// - `ray_direction`: The ray as a [`Vector`]. The direction vector must not be zero.
+/// - `target`: The [`Rect`] representing the target rectangle to test for intersection.
+/// - `policy`: How to pick the `contact_normal` when the ray hits a corner exactly.
+///
+/// # Returns
+///
+/// Returns `Some(RayIntersectionResult)` if there is an intersection, or `None` if there is
+/// no intersection or if the ray direction is zero.
+///
+/// # Example
+///
+/// ```rust
+/// use fixed32_math::{Rect, Vector};
+/// use impact_rs::prelude::full::*;
+///
+/// let ray_origin = Vector::from((0, 0));
+/// let ray_direction = Vector::from((1, 1));
+/// let target = Rect::from((5, 5, 10, 10));
+///
+/// let result = ray_vs_rect_with(ray_origin, ray_direction, target, CornerPolicy::PreferY).unwrap();
+/// assert_eq!(result.contact_normal, Vector::up());
+/// assert_eq!(result.face, ContactSide::Corner);
+/// ```
+#[must_use]
+pub fn ray_vs_rect_with(
+    ray_origin: Vector,
+    ray_direction: Vector,
+    target: Rect,
+    policy: CornerPolicy,
+) -> Option<RayIntersectionResult> {
+    RayPrecomputed::new(ray_origin, ray_direction).vs_rect_with(target, policy)
+}
+
+/// Performs a ray-rectangle intersection test with a chosen [`BoundaryMode`].
+///
+/// This function behaves exactly as [`ray_vs_rect`] with [`BoundaryMode::HalfOpen`]. With
+/// [`BoundaryMode::Closed`], a ray that only touches a corner of the rectangle is also
+/// reported, with the time at which it touches it.
+///
+/// # Parameters
+///
+/// - `ray_origin`: The origin point of the ray as a [`Vector`].
+/// - `ray_direction`: The ray as a [`Vector`]. The direction vector must not be zero.
+/// - `target`: The [`Rect`] representing the target rectangle to test for intersection.
+/// - `mode`: Whether touching the boundary of `target` counts as a hit.
+///
+/// # Returns
+///
+/// Returns `Some(RayIntersectionResult)` if there is an intersection, or `None` if there is
+/// no intersection or if the ray direction is zero.
+///
+/// # Example
+///
+/// ```rust
+/// use fixed32::Fp;
+/// use fixed32_math::{Rect, Vector};
+/// use impact_rs::prelude::full::*;
+///
+/// // The ray passes through the top-right corner of the tile without entering it
+/// let tile = Rect::from((0, 0, 1, 1));
+/// let ray_origin = Vector::from((0, 2));
+/// let ray_direction = Vector::from((2, -2));
+///
+/// assert!(ray_vs_rect_with_boundary(ray_origin, ray_direction, tile, BoundaryMode::HalfOpen).is_none());
+///
+/// let touch = ray_vs_rect_with_boundary(ray_origin, ray_direction, tile, BoundaryMode::Closed).unwrap();
+/// assert_eq!(touch.closest_time, Fp::from(0.5));
+/// assert_eq!(touch.contact_point, Vector::from((1, 1)));
+/// ```
+#[must_use]
+pub fn ray_vs_rect_with_boundary(
+    ray_origin: Vector,
+    ray_direction: Vector,
+    target: Rect,
+    mode: BoundaryMode,
+) -> Option<RayIntersectionResult> {
+    RayPrecomputed::new(ray_origin, ray_direction).vs_rect_with_boundary(target, mode)
+}
+
+/// Performs a ray-rectangle intersection test in the given [`CoordinateSpace`].
+///
+/// The times, the contact point and the `contact_normal` vector do not depend on which way
+/// the y-axis points, since they are computed from the same numbers. Only the meaning of the
+/// vertical faces changes: in [`CoordinateSpace::YDown`] a ray moving towards larger y is
+/// moving down, so it hits with its [`ContactSide::Bottom`] instead of its
+/// [`ContactSide::Top`]. This function reports `face` in the given space, and is the same as
+/// [`ray_vs_rect`] for [`CoordinateSpace::YUp`].
+///
+/// # Parameters
+///
+/// - `ray_origin`: The origin point of the ray as a [`Vector`].
+/// - `ray_direction`: The ray as a [`Vector`]. The direction vector must not be zero.
+/// - `target`: The [`Rect`] representing the target rectangle, given by its top-left corner
+///   in [`CoordinateSpace::YDown`].
+/// - `space`: The coordinate space of `ray_origin`, `ray_direction` and `target`.
+///
+/// # Returns
+///
+/// Returns `Some(RayIntersectionResult)` if there is an intersection, or `None` if there is
+/// no intersection or if the ray direction is zero.
+///
+/// # Example
+///
+/// ```rust
+/// use fixed32_math::{Rect, Vector};
+/// use impact_rs::prelude::full::*;
+///
+/// // A ray falling down the screen onto a platform
+/// let platform = Rect::from((0, 10, 20, 2));
+/// let result = ray_vs_rect_in_space(Vector::from((5, 0)), Vector::from((0, 1)), platform, CoordinateSpace::YDown).unwrap();
+///
+/// assert_eq!(result.face, ContactSide::Bottom);
+/// assert_eq!(result.contact_normal, Vector::from((0, 1)));
+/// ```
+#[must_use]
+pub fn ray_vs_rect_in_space(
+    ray_origin: Vector,
+    ray_direction: Vector,
+    target: Rect,
+    space: CoordinateSpace,
+) -> Option<RayIntersectionResult> {
+    let mut result = ray_vs_rect(ray_origin, ray_direction, target)?;
+
+    if space == CoordinateSpace::YDown {
+        result.face = match result.face {
+            ContactSide::Top => ContactSide::Bottom,
+            ContactSide::Bottom => ContactSide::Top,
+            side => side,
+        };
+    }
+
+    Some(result)
+}
+
+/// How a ray moves along one axis, decided once per ray by [`RayPrecomputed`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum SlabAxis {
+    Increasing,
+    Decreasing,
+    Parallel,
+}
+
+impl SlabAxis {
+    pub(crate) fn new(direction: Fp) -> Self {
+        match direction.cmp(&Fp::zero()) {
+            Ordering::Greater => Self::Increasing,
+            Ordering::Less => Self::Decreasing,
+            Ordering::Equal => Self::Parallel,
+        }
+    }
+
+    /// Returns the entry and exit times of the ray for the range `[min, max]` on this axis.
+    pub(crate) fn times(self, origin: Fp, direction: Fp, min: Fp, max: Fp) -> Option<(Fp, Fp)> {
+        let (near, far) = match self {
+            Self::Increasing => (
+                saturating_div(min - origin, direction),
+                saturating_div(max - origin, direction),
+            ),
+            Self::Decreasing => (
+                saturating_div(max - origin, direction),
+                saturating_div(min - origin, direction),
+            ),
+            Self::Parallel => {
+                if origin < min || origin > max {
+                    return None;
+                }
+                (Fp::MIN, Fp::MAX)
+            }
+        };
+
+        if near > far {
+            Some((far, near))
+        } else {
+            Some((near, far))
+        }
+    }
+}
+
+/// A ray prepared for testing against many rectangles.
+///
+/// [`ray_vs_rect`] decides for every target which edge of each axis the ray enters through,
+/// based on the signs of the direction. This type makes those decisions once, so casting
+/// one ray against many rectangles only runs the slab divisions per target.
+/// [`RayPrecomputed::vs_rects`] also skips the rest of the slab test for a target once its
+/// horizontal slab shows that it can not be nearer than the best hit so far, and only builds
+/// the full [`RayIntersectionResult`] for the nearest target. The results are identical to
+/// [`ray_vs_rect`] and [`ray_vs_rects`], which are implemented with this type.
+///
+/// # Example
+///
+/// ```rust
+/// use fixed32::Fp;
+/// use fixed32_math::{Rect, Vector};
+/// use impact_rs::prelude::full::*;
+///
+/// let ray = RayPrecomputed::new(Vector::from((0, 0)), Vector::from((1, 0)));
+/// let targets = [Rect::from((20, -5, 10, 10)), Rect::from((10, -5, 5, 10))];
+///
+/// let (index, result) = ray.vs_rects(&targets).unwrap();
+/// assert_eq!(index, 1);
+/// assert_eq!(result.closest_time, Fp::from(10));
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct RayPrecomputed {
+    origin: Vector,
+    direction: Vector,
+    x_axis: SlabAxis,
+    y_axis: SlabAxis,
+}
+
+impl RayPrecomputed {
+    #[must_use]
+    pub fn new(ray_origin: Vector, ray_direction: Vector) -> Self {
+        Self {
+            origin: ray_origin,
+            direction: ray_direction,
+            x_axis: SlabAxis::new(ray_direction.x),
+            y_axis: SlabAxis::new(ray_direction.y),
+        }
+    }
+
+    /// Tests the ray against a rectangle, the same as [`ray_vs_rect`].
+    #[must_use]
+    pub fn vs_rect(&self, target: Rect) -> Option<RayIntersectionResult> {
+        self.vs_rect_with(target, CornerPolicy::default())
+    }
+
+    /// Tests the ray against a rectangle, the same as [`ray_vs_rect_with`].
+    #[must_use]
+    pub fn vs_rect_with(
+        &self,
+        target: Rect,
+        policy: CornerPolicy,
+    ) -> Option<RayIntersectionResult> {
+        self.entry_times(target, None, BoundaryMode::default())
+            .map(|time_near| self.result(time_near, policy))
+    }
+
+    /// Tests the ray against a rectangle, the same as [`ray_vs_rect_with_boundary`].
+    #[must_use]
+    pub fn vs_rect_with_boundary(
+        &self,
+        target: Rect,
+        mode: BoundaryMode,
+    ) -> Option<RayIntersectionResult> {
+        self.entry_times(target, None, mode)
+            .map(|time_near| self.result(time_near, CornerPolicy::default()))
+    }
+
+    /// Finds the nearest rectangle hit by the ray, the same as [`ray_vs_rects`].
+    #[must_use]
+    pub fn vs_rects(&self, targets: &[Rect]) -> Option<(usize, RayIntersectionResult)> {
+        let mut closest: Option<(usize, Vector, Fp)> = None;
+
+        for (index, target) in targets.iter().enumerate() {
+            let best_time = closest.map(|(_, _, best_time)| best_time);
+            if let Some(time_near) = self.entry_times(*target, best_time, BoundaryMode::default()) {
+                let time = max(time_near.x, time_near.y);
+                if best_time.is_none_or(|best_time| time < best_time) {
+                    closest = Some((index, time_near, time));
+                }
+            }
+        }
+
+        closest
+            .map(|(index, time_near, _)| (index, self.result(time_near, CornerPolicy::default())))
+    }
+
+    /// Runs the slab test and returns the entry time of each axis if the ray hits `target`.
+    ///
+    /// Hits that can not be earlier than `limit` are rejected as soon as the horizontal
+    /// slab shows it, which skips the vertical divisions for most targets in a batch.
+    ///
+    /// A ray along one axis only runs the slab test of that axis, and both components of the
+    /// returned vector are its entry time.
+    fn entry_times(&self, target: Rect, limit: Option<Fp>, mode: BoundaryMode) -> Option<Vector> {
+        let target = normalize_rect(target);
+
+        match (self.x_axis, self.y_axis) {
+            (SlabAxis::Parallel, SlabAxis::Parallel) => return None,
+            (axis, SlabAxis::Parallel) => {
+                let near = aligned_entry_time(
+                    axis,
+                    self.origin.x,
+                    self.direction.x,
+                    (target.left(), target.right()),
+                    (self.origin.y, target.bottom(), target.top()),
+                    limit,
+                )?;
+                return Some(Vector::new(near, near));
+            }
+            (SlabAxis::Parallel, axis) => {
+                let near = aligned_entry_time(
+                    axis,
+                    self.origin.y,
+                    self.direction.y,
+                    (target.bottom(), target.top()),
+                    (self.origin.x, target.left(), target.right()),
+                    limit,
+                )?;
+                return Some(Vector::new(near, near));
+            }
+            _ => {}
+        }
+
+        let (near_x, far_x) = self.x_axis.times(
+            self.origin.x,
+            self.direction.x,
+            target.pos.x,
+            target.pos.x + target.size.x,
+        )?;
+        if far_x <= 0 || limit.is_some_and(|limit| near_x >= limit) {
+            return None;
+        }
+
+        let (near_y, far_y) = self.y_axis.times(
+            self.origin.y,
+            self.direction.y,
+            target.pos.y,
+            target.pos.y + target.size.y,
+        )?;
+
+        let misses = match mode {
+            BoundaryMode::Closed => near_x > far_y || near_y > far_x,
+            BoundaryMode::HalfOpen => near_x >= far_y || near_y >= far_x,
+        };
+        if misses {
+            return None;
+        }
+
+        // A ray that leaves a slab at time zero starts on the boundary and moves out of it
+        if min(far_x, far_y) <= 0 {
+            return None;
+        }
+
+        Some(Vector::new(near_x, near_y))
+    }
+
+    /// Builds the result of a hit from the entry time of each axis.
+    fn result(&self, time_near: Vector, policy: CornerPolicy) -> RayIntersectionResult {
+        let closest_time = max(time_near.x, time_near.y);
+
+        let contact_point = self.origin + closest_time * self.direction;
+
+        let (x_normal, x_face) = if self.direction.x > 0 {
+            (Vector::right(), ContactSide::Right)
+        } else {
+            (Vector::left(), ContactSide::Left)
+        };
+        let (y_normal, y_face) = if self.direction.y > 0 {
+            (Vector::up(), ContactSide::Top)
+        } else {
+            (Vector::down(), ContactSide::Bottom)
+        };
+
+        let order = match (self.x_axis, self.y_axis) {
+            (_, SlabAxis::Parallel) => Ordering::Greater,
+            (SlabAxis::Parallel, _) => Ordering::Less,
+            _ => time_near.x.cmp(&time_near.y),
+        };
+
+        let (contact_normal, face) = match order {
+            Ordering::Greater => (x_normal, x_face),
+            Ordering::Less => (y_normal, y_face),
+            Ordering::Equal => {
+                let corner_normal = match policy {
+                    CornerPolicy::PreferX => x_normal,
+                    CornerPolicy::PreferY => y_normal,
+                    CornerPolicy::Diagonal => {
+                        let diagonal = x_normal + y_normal;
+                        diagonal.normalize().unwrap_or(diagonal)
+                    }
+                    CornerPolicy::None => Vector::default(),
+                };
+                (corner_normal, ContactSide::Corner)
+            }
+        };
+
+        RayIntersectionResult {
+            contact_point,
+            contact_normal,
+            closest_time,
+            face,
+            frame_fraction: None,
+        }
+    }
+}
+
+/// Returns the entry time of a ray that is parallel to the other axis, see
+/// [`RayPrecomputed::entry_times`].
+///
+/// The ray can only hit the target if its origin is within the target across the ray,
+/// edges included, so only the slab of `axis` has to be tested. `across` is the origin
+/// coordinate and the target range on the other axis.
+fn aligned_entry_time(
+    axis: SlabAxis,
+    origin: Fp,
+    direction: Fp,
+    (min, max): (Fp, Fp),
+    (across, across_min, across_max): (Fp, Fp, Fp),
+    limit: Option<Fp>,
+) -> Option<Fp> {
+    if across < across_min || across > across_max {
+        return None;
+    }
+
+    let (near, far) = axis.times(origin, direction, min, max)?;
+    if far <= 0 || limit.is_some_and(|limit| near >= limit) {
+        return None;
+    }
+
+    Some(near)
+}
+
+/// Performs a ray-rectangle intersection test, only reporting hits in front of the ray origin.
+///
+/// This function behaves exactly as [`ray_vs_rect`], except that results with a negative
+/// `closest_time` are discarded. [`ray_vs_rect`] never reports a target that lies entirely
+/// behind the origin, but a ray that starts inside the target (straddling it) is reported with
+/// the negative time at which it would have entered. There is no separate inside flag, so
+/// this function is the way to ignore those hits; use [`ray_vs_rect`] and check for a negative
+/// `closest_time` to detect them instead. A ray that starts exactly on the near edge has a
+/// `closest_time` of zero and is still reported.
+///
+/// # Parameters
+///
+/// - `ray_origin`: The origin point of the ray as a [`Vector`].
+/// - `ray_direction`: The ray as a [`Vector`]. The direction vector must not be zero.
+/// - `target`: The [`Rect`] representing the target rectangle to test for intersection.
+///
+/// # Returns
+///
+/// Returns `Some(RayIntersectionResult)` with a non-negative `closest_time`, or `None` if there
+/// is no such intersection or if the ray direction is zero.
+///
+/// # Example
+///
+/// ```rust
+/// use fixed32_math::{Rect, Vector};
+/// use impact_rs::prelude::full::*;
+///
+/// let target = Rect::from((-5, -5, 10, 10));
+///
+/// assert!(ray_vs_rect(Vector::from((0, 0)), Vector::from((1, 0)), target).is_some());
+/// assert!(ray_vs_rect_forward(Vector::from((0, 0)), Vector::from((1, 0)), target).is_none());
+/// ```
+#[must_use]
+pub fn ray_vs_rect_forward(
+    ray_origin: Vector,
+    ray_direction: Vector,
+    target: Rect,
+) -> Option<RayIntersectionResult> {
+    ray_vs_rect(ray_origin, ray_direction, target)
+        .filter(|result| result.closest_time >= Fp::zero())
+}
+
+/// Performs an intersection test between an infinite line and a rectangle.
+///
+/// The line passes through `point` along `direction` and extends in both directions, so a
+/// target behind `point` is hit as well as one in front of it. Of the places where the line
+/// crosses the edge of `target`, the one nearest to `point` is reported. It is found with
+/// [`ray_vs_rect`] cast along `direction` and along `-direction`, and the `contact_normal` and
+/// `face` are those of the cast that reached it, so the normal points from the crossing into
+/// `target`.
 ///
 /// # Parameters
 ///
-/// - `origin`: A [`Rect`] representing the starting rectangle
-///   that is swept along a direction and length defined by the `delta` vector.
-/// - `target`: A [`Rect`] representing the target rectangle
-///   to check for intersection with the swept rectangle.
-/// - `delta`: The vector representing the movement direction and magnitude
-///   of the `origin` rectangle. This vector determines how far and in which
-///   direction the origin rectangle is moved.
+/// - `point`: A point on the line as a [`Vector`].
+/// - `direction`: The direction of the line as a [`Vector`]. It must not be zero.
+/// - `target`: The [`Rect`] representing the target rectangle to test for intersection.
 ///
 /// # Returns
 ///
-/// Returns `Some(RayIntersectionResult)` if there is an intersection between
-/// the swept rectangle and the target rectangle within the valid time range.
+/// Returns `Some(RayIntersectionResult)` for the crossing nearest to `point`, where
+/// `contact_point == point + closest_time * direction`. The `closest_time` is negative when
+/// the crossing is behind `point`, which is always the case for a target entirely behind it.
+/// When `point` is inside `target`, the nearer of the two crossings is reported; at equal
+/// distances the one in front wins.
 ///
-/// The [`RayIntersectionResult`] contains information about the intersection
-/// point and other related details. If there is no intersection or the
-/// intersection does not occur within the valid time range, `None` is returned.
+/// Returns `None` if the line misses the rectangle or if `direction` is zero.
 ///
 /// # Example
 ///
 /// ```rust
+/// use fixed32::Fp;
 /// use fixed32_math::{Rect, Vector};
-/// use impact_rs::prelude::*;
+/// use impact_rs::prelude::full::*;
 ///
-/// let origin = Rect::from((0.0, 0.0, 10.0, 10.0));
-/// let target = Rect::from((5.0, 5.0, 10.0, 10.0));
-/// let delta = Vector::from((15.0, 15.0));
+/// let target = Rect::from((-10, -1, 4, 2));
 ///
-/// match swept_rect_vs_rect(origin, target, delta) {
-///     Some(result) => {
-///         println!("Intersection found: {:?}", result);
-///     }
-///     None => {
-///         println!("No intersection.");
-///     }
-/// }
+/// let result = line_vs_rect(Vector::from((0, 0)), Vector::from((1, 0)), target).unwrap();
+/// assert_eq!(result.closest_time, Fp::from(-6));
+/// assert_eq!(result.contact_point, Vector::from((-6, 0)));
+/// assert_eq!(result.contact_normal, Vector::left());
 /// ```
 #[must_use]
-pub fn swept_rect_vs_rect(
-    origin: Rect,
+pub fn line_vs_rect(
+    point: Vector,
+    direction: Vector,
     target: Rect,
-    delta: Vector,
 ) -> Option<RayIntersectionResult> {
-    let expanded_target = Rect {
-        pos: target.pos - origin.size / 2,
-        size: target.size + origin.size,
-    };
-
-    let origin_point = origin.pos + origin.size;
-
-    let maybe_intersected = ray_vs_rect(origin_point, delta, expanded_target);
-    if let Some(result) = maybe_intersected {
-        let time = result.closest_time;
-        if time >= Fp::zero() && time < Fp::one() {
-            return Some(result);
-        }
-    }
+    let forward = ray_vs_rect(point, direction, target);
+    let backward = ray_vs_rect(point, -direction, target).map(|result| RayIntersectionResult {
+        closest_time: -result.closest_time,
+        ..result
+    });
 
-    None
+    forward
+        .into_iter()
+        .chain(backward)
+        .min_by_key(|result| (result.closest_time.abs(), result.closest_time < Fp::zero()))
 }
 
-/// Performs a ray-rectangle intersection test.
+/// Performs a ray-rectangle intersection test against a one-way rectangle.
 ///
-/// This function determines if a ray intersects with a given rectangle. The ray
-/// is defined by its origin and direction, and the rectangle is defined by its
-/// lower-left position and size. The function computes the intersection time and contact
-/// point if an intersection occurs.
+/// Only the face of `target` whose outward normal is `solid_normal` blocks the ray, which is
+/// how one-way platforms work: a ray falling onto the top of the platform hits it, while a
+/// ray coming from below, from the side, or starting inside it passes through. A hit is
+/// reported only if the ray moves against the solid face, `dot(ray_direction, solid_normal) < 0`,
+/// and enters `target` through that face, or through a corner of it.
 ///
 /// # Parameters
 ///
-/// - `ray_origin`: The origin point of the ray as a [`Vector`]. This is where
-///   the ray starts.
-/// - `ray_direction`: The ray as a [`Vector`]. This indicates
-///   the direction and length in which the ray is cast. The direction vector must not be zero.
+/// - `ray_origin`: The origin point of the ray as a [`Vector`].
+/// - `ray_direction`: The ray as a [`Vector`]. The direction vector must not be zero.
 /// - `target`: The [`Rect`] representing the target rectangle to test for intersection.
-///   It is defined by its lower-left position and size.
+/// - `solid_normal`: The outward normal of the solid face, for example [`Vector::up`] for a
+///   platform that can be stood on.
 ///
 /// # Returns
 ///
-/// Returns `Some(RayIntersectionResult)` if there is an intersection between
-/// the ray and the rectangle. The [`RayIntersectionResult`] includes:
-/// - `contact_point`: The point of intersection between the ray and the rectangle.
-/// - `contact_normal`: The normal vector of the rectangle at the point of intersection.
-/// - `closest_time`: The normalized time along the ray at which the intersection occurs.
-///
-/// Returns `None` if there is no intersection or if the ray direction is zero.
+/// Returns `Some(RayIntersectionResult)` with a non-negative `closest_time` if the ray hits
+/// the solid face, or `None` otherwise.
 ///
 /// # Example
 ///
 /// ```rust
+/// use fixed32::Fp;
 /// use fixed32_math::{Rect, Vector};
-/// use impact_rs::prelude::*;
+/// use impact_rs::prelude::full::*;
 ///
-/// let ray_origin = Vector::from((0.0, 0.0));
-/// let ray_direction = Vector::from((1.0, 1.0));
-/// let target = Rect::from((5.0, 5.0, 10.0, 10.0));
+/// let platform = Rect::from((0, 0, 10, 1));
 ///
-/// match ray_vs_rect(ray_origin, ray_direction, target) {
-///     Some(result) => {
-///         println!("Intersection found at point: {:?}", result.contact_point);
-///         println!("Contact normal: {:?}", result.contact_normal);
-///         println!("Intersection time: {:?}", result.closest_time);
-///     }
-///     None => {
-///         println!("No intersection.");
-///     }
-/// }
+/// let landing = ray_vs_rect_oneway(Vector::from((5, 4)), Vector::from((0, -1)), platform, Vector::up());
+/// assert_eq!(landing.unwrap().closest_time, Fp::from(3));
+///
+/// let jumping = ray_vs_rect_oneway(Vector::from((5, -4)), Vector::from((0, 1)), platform, Vector::up());
+/// assert!(jumping.is_none());
 /// ```
 #[must_use]
-pub fn ray_vs_rect(
+pub fn ray_vs_rect_oneway(
     ray_origin: Vector,
     ray_direction: Vector,
     target: Rect,
+    solid_normal: Vector,
 ) -> Option<RayIntersectionResult> {
-    if ray_direction.x.is_zero() && ray_direction.y.is_zero() {
+    if dot(ray_direction, solid_normal) >= Fp::zero() {
         return None;
     }
 
-    let mut time_near = Vector::default();
-    let mut time_far = Vector::default();
-
-    let inverted_direction = Vector::new(
-        if ray_direction.x != 0 {
-            Fp::one() / ray_direction.x
-        } else {
-            Fp::zero()
-        },
-        if ray_direction.y != 0 {
-            Fp::one() / ray_direction.y
-        } else {
-            Fp::zero()
-        },
-    );
+    ray_vs_rect_forward(ray_origin, ray_direction, target).filter(|result| {
+        result.face == ContactSide::Corner
+            || target_face(result) == ContactSide::from_normal(solid_normal)
+    })
+}
 
-    match ray_direction.x.cmp(&Fp::zero()) {
-        Ordering::Greater => {
-            time_near.x = (target.pos.x - ray_origin.x) * inverted_direction.x;
-            time_far.x = (target.pos.x + target.size.x - ray_origin.x) * inverted_direction.x;
-        }
-        Ordering::Less => {
-            time_near.x = (target.pos.x + target.size.x - ray_origin.x) * inverted_direction.x;
-            time_far.x = (target.pos.x - ray_origin.x) * inverted_direction.x;
-        }
-        Ordering::Equal => {
-            // Ray direction is purely vertical
-            if ray_origin.x < target.pos.x || ray_origin.x > target.pos.x + target.size.x {
-                return None;
-            }
-            time_near.x = Fp::MIN;
-            time_far.x = Fp::MAX;
-        }
-    }
+/// Performs a ray-rectangle intersection test, reporting the world-space distance to the contact.
+///
+/// This function behaves exactly as [`ray_vs_rect`], except that `closest_time` in the
+/// returned [`RayIntersectionResult`] is the distance from `ray_origin` to the contact point,
+/// measured in world units, instead of the parametric `t` along `ray_direction`.
+/// The two are only equal when `ray_direction` has unit length. The length of
+/// `ray_direction` is computed with [`math::length`](crate::math::length), so long directions
+/// do not overflow.
+///
+/// # Parameters
+///
+/// - `ray_origin`: The origin point of the ray as a [`Vector`].
+/// - `ray_direction`: The direction of the ray as a [`Vector`]. It does not need to be normalized,
+///   but it must not be zero.
+/// - `target`: The [`Rect`] representing the target rectangle to test for intersection.
+///
+/// # Returns
+///
+/// Returns `Some(RayIntersectionResult)` with `closest_time` set to the world-space distance
+/// to the contact point, or `None` if there is no intersection or if the ray direction is zero.
+///
+/// # Example
+///
+/// ```rust
+/// use fixed32::Fp;
+/// use fixed32_math::{Rect, Vector};
+/// use impact_rs::prelude::full::*;
+///
+/// let ray_origin = Vector::from((0, 0));
+/// let ray_direction = Vector::from((4, 3));
+/// let target = Rect::from((8, 0, 10, 20));
+///
+/// let parametric = ray_vs_rect(ray_origin, ray_direction, target).unwrap();
+/// let distance = ray_vs_rect_distance(ray_origin, ray_direction, target).unwrap();
+///
+/// assert_eq!(parametric.closest_time, Fp::from(2));
+/// assert_eq!(distance.closest_time, Fp::from(10));
+/// ```
+#[must_use]
+pub fn ray_vs_rect_distance(
+    ray_origin: Vector,
+    ray_direction: Vector,
+    target: Rect,
+) -> Option<RayIntersectionResult> {
+    let mut result = ray_vs_rect(ray_origin, ray_direction, target)?;
+    result.closest_time = result.closest_time * length(ray_direction, Rounding::Floor);
+    Some(result)
+}
 
-    match ray_direction.y.cmp(&Fp::zero()) {
-        Ordering::Greater => {
-            time_near.y = (target.pos.y - ray_origin.y) * inverted_direction.y;
-            time_far.y = (target.pos.y + target.size.y - ray_origin.y) * inverted_direction.y;
-        }
-        Ordering::Less => {
-            time_near.y = (target.pos.y + target.size.y - ray_origin.y) * inverted_direction.y;
-            time_far.y = (target.pos.y - ray_origin.y) * inverted_direction.y;
-        }
-        Ordering::Equal => {
-            // Ray direction is purely horizontal
-            if ray_origin.y < target.pos.y || ray_origin.y > target.pos.y + target.size.y {
-                return None;
-            }
-            time_near.y = Fp::MIN;
-            time_far.y = Fp::MAX;
-        }
-    }
+/// Casts a ray of a fixed world-space length against a target rectangle.
+///
+/// `direction` only sets where the ray is aimed: it is normalized before the cast, so its
+/// length does not matter. The normalization is computed in 64-bit integers, so a long
+/// `direction` does not overflow. How far the ray reaches is set by `length` alone. As with
+/// [`ray_vs_rect_distance`], `closest_time` in the returned [`RayIntersectionResult`] is the
+/// world-space distance from `ray_origin` to the contact point.
+///
+/// # Parameters
+///
+/// - `ray_origin`: The origin point of the ray as a [`Vector`].
+/// - `direction`: The direction to cast in. It does not need to be normalized.
+/// - `length`: The maximum distance, in world units, that the ray reaches.
+/// - `target`: The [`Rect`] representing the target rectangle to test for intersection.
+///
+/// # Returns
+///
+/// Returns `Some(RayIntersectionResult)` if the ray hits the target at most `length` away
+/// from `ray_origin`. Returns `None` if the target is further away, the ray misses, or
+/// `direction` is zero.
+///
+/// # Example
+///
+/// ```rust
+/// use fixed32::Fp;
+/// use fixed32_math::{Rect, Vector};
+/// use impact_rs::prelude::full::*;
+///
+/// let target = Rect::from((8, -1, 2, 2));
+///
+/// let result =
+///     ray_vs_rect_normalized(Vector::from((0, 0)), Vector::from((5, 0)), Fp::from(10), target)
+///         .unwrap();
+/// assert_eq!(result.closest_time, Fp::from(8));
+///
+/// assert!(ray_vs_rect_normalized(Vector::from((0, 0)), Vector::from((5, 0)), Fp::from(6), target)
+///     .is_none());
+/// ```
+#[must_use]
+pub fn ray_vs_rect_normalized(
+    ray_origin: Vector,
+    direction: Vector,
+    length: Fp,
+    target: Rect,
+) -> Option<RayIntersectionResult> {
+    let unit_direction = normalize(direction)?;
 
-    // Sort distances
-    if time_near.x > time_far.x {
-        std::mem::swap(&mut time_near.x, &mut time_far.x);
-    }
+    ray_vs_rect(ray_origin, unit_direction, target).filter(|result| result.closest_time <= length)
+}
 
-    if time_near.y > time_far.y {
-        std::mem::swap(&mut time_near.y, &mut time_far.y);
-    }
+/// Casts a ray with a width (a "thick ray") against a target rectangle.
+///
+/// A thick ray behaves like a line segment of length `thickness`, centered on the ray and
+/// held perpendicular to `direction`, that is swept along `direction`. This is useful for
+/// beams and other wide projectiles. The perpendicular segment is converted to its
+/// axis-aligned bounding box before the sweep, so axis-aligned directions are exact while
+/// diagonal directions are slightly conservative (the beam is treated as up to `√2` times wider
+/// at 45 degrees).
+///
+/// The box is swept the same way as in [`swept_rect_vs_rect`]: the target is grown by the
+/// size of the box and a ray is cast against it. Unlike [`swept_rect_vs_rect`], the sweep is
+/// not limited to `[0, 1)`, so the beam reaches as far as a ray does. The direction is
+/// normalized in 64-bit integers, so long directions do not overflow.
+///
+/// # Parameters
+///
+/// - `origin`: The center of the beam at its start.
+/// - `direction`: The direction of the beam. The direction vector must not be zero.
+/// - `thickness`: The full width of the beam, measured perpendicular to `direction`.
+/// - `target`: The [`Rect`] representing the target rectangle to test for intersection.
+///
+/// # Returns
+///
+/// Returns `Some(RayIntersectionResult)` if the beam touches the target. The `contact_point` is
+/// the center of the beam at the time of contact, and `closest_time` is the parametric time along
+/// `direction`, as for [`ray_vs_rect`]. Returns `None` if there is no intersection or if the
+/// direction is zero.
+///
+/// # Example
+///
+/// ```rust
+/// use fixed32::Fp;
+/// use fixed32_math::{Rect, Vector};
+/// use impact_rs::prelude::full::*;
+///
+/// let origin = Vector::from((0, 0));
+/// let direction = Vector::from((1, 0));
+/// let target = Rect::from((10, 1, 2, 2));
+///
+/// assert!(ray_vs_rect(origin, direction, target).is_none());
+/// assert!(thick_ray_vs_rect(origin, direction, Fp::from(4), target).is_some());
+/// ```
+#[must_use]
+pub fn thick_ray_vs_rect(
+    origin: Vector,
+    direction: Vector,
+    thickness: Fp,
+    target: Rect,
+) -> Option<RayIntersectionResult> {
+    let unit_direction = normalize(direction)?;
+    let target = normalize_rect(target);
 
-    if time_near.x >= time_far.y || time_near.y >= time_far.x {
-        return None;
-    }
+    // The bounding box of a `thickness` long segment perpendicular to the direction
+    let beam_size = Vector::new(
+        thickness * unit_direction.y.abs(),
+        thickness * unit_direction.x.abs(),
+    );
 
-    let time_far_magnitude = min(time_far.x, time_far.y);
+    let expanded_target = Rect {
+        pos: target.pos - beam_size / 2,
+        size: target.size + beam_size,
+    };
 
-    if time_far_magnitude < 0 {
-        return None;
-    }
+    ray_vs_rect(origin, direction, expanded_target)
+}
 
-    let closest_time = max(time_near.x, time_near.y);
+/// Casts a ray against a slice of rectangles and returns the nearest hit.
+///
+/// Every target is tested with [`ray_vs_rect`] and the hit with the smallest
+/// `closest_time` is kept. If two targets are hit at the same time, the one with the
+/// lowest index wins. The ray is prepared once with [`RayPrecomputed`], which is faster than
+/// calling [`ray_vs_rect`] for every target.
+///
+/// # Parameters
+///
+/// - `ray_origin`: The origin point of the ray as a [`Vector`].
+/// - `ray_direction`: The ray as a [`Vector`]. The direction vector must not be zero.
+/// - `targets`: The [`Rect`]s to test for intersection.
+///
+/// # Returns
+///
+/// Returns `Some((index, RayIntersectionResult))` for the nearest hit, where `index` is the
+/// position of the hit rectangle in `targets`. Returns `None` if no target is hit.
+///
+/// # Example
+///
+/// ```rust
+/// use fixed32_math::{Rect, Vector};
+/// use impact_rs::prelude::full::*;
+///
+/// let targets = [
+///     Rect::from((20.0, -5.0, 10.0, 10.0)),
+///     Rect::from((10.0, -5.0, 5.0, 10.0)),
+/// ];
+///
+/// let (index, _) = ray_vs_rects(Vector::from((0.0, 0.0)), Vector::from((1.0, 0.0)), &targets)
+///     .expect("should hit");
+/// assert_eq!(index, 1);
+/// ```
+#[must_use]
+pub fn ray_vs_rects(
+    ray_origin: Vector,
+    ray_direction: Vector,
+    targets: &[Rect],
+) -> Option<(usize, RayIntersectionResult)> {
+    RayPrecomputed::new(ray_origin, ray_direction).vs_rects(targets)
+}
 
-    let contact_point = ray_origin + closest_time * ray_direction;
+/// Casts a ray against a slice of rectangles and returns every hit, nearest first.
+///
+/// Every target is tested with [`ray_vs_rect`], so a target that the ray starts inside is
+/// included with its negative `closest_time`. Hits at the same time are sorted by ascending
+/// index, so the order is reproducible. Use [`ray_vs_rects_all_into`] to reuse the returned
+/// buffer between calls.
+///
+/// # Parameters
+///
+/// - `ray_origin`: The origin point of the ray as a [`Vector`].
+/// - `ray_direction`: The ray as a [`Vector`]. The direction vector must not be zero.
+/// - `targets`: The [`Rect`]s to test for intersection.
+///
+/// # Returns
+///
+/// The `(index, RayIntersectionResult)` of every hit target, sorted by `closest_time`.
+///
+/// # Example
+///
+/// ```rust
+/// use fixed32_math::{Rect, Vector};
+/// use impact_rs::prelude::full::*;
+///
+/// let targets = [
+///     Rect::from((20, -5, 10, 10)),
+///     Rect::from((0, 10, 5, 5)),
+///     Rect::from((10, -5, 5, 10)),
+/// ];
+///
+/// let hits = ray_vs_rects_all(Vector::from((0, 0)), Vector::from((1, 0)), &targets);
+/// let order: Vec<usize> = hits.iter().map(|(index, _)| *index).collect();
+/// assert_eq!(order, [2, 0]);
+/// ```
+#[must_use]
+pub fn ray_vs_rects_all(
+    ray_origin: Vector,
+    ray_direction: Vector,
+    targets: &[Rect],
+) -> Vec<(usize, RayIntersectionResult)> {
+    let mut hits = Vec::new();
+    ray_vs_rects_all_into(ray_origin, ray_direction, targets, &mut hits);
+    hits
+}
 
-    let mut contact_normal: Vector = Vector::default();
+/// Casts a ray against a slice of rectangles, writing every hit into a reusable buffer.
+///
+/// This is [`ray_vs_rects_all`] without the allocation: `out` is cleared and filled with the
+/// same hits in the same order, so a buffer kept between calls only grows when a ray hits
+/// more targets than any ray before it.
+///
+/// # Parameters
+///
+/// - `ray_origin`: The origin point of the ray as a [`Vector`].
+/// - `ray_direction`: The ray as a [`Vector`]. The direction vector must not be zero.
+/// - `targets`: The [`Rect`]s to test for intersection.
+/// - `out`: The buffer that receives the `(index, RayIntersectionResult)` of every hit,
+///   sorted by `closest_time`.
+///
+/// # Returns
+///
+/// The number of hits written to `out`.
+///
+/// # Example
+///
+/// ```rust
+/// use fixed32_math::{Rect, Vector};
+/// use impact_rs::prelude::full::*;
+///
+/// let targets = [Rect::from((20, -5, 10, 10)), Rect::from((10, -5, 5, 10))];
+/// let mut hits = Vec::new();
+///
+/// for _ in 0..3 {
+///     let count = ray_vs_rects_all_into(Vector::from((0, 0)), Vector::from((1, 0)), &targets, &mut hits);
+///     assert_eq!(count, 2);
+///     assert_eq!(hits[0].0, 1);
+/// }
+/// ```
+pub fn ray_vs_rects_all_into(
+    ray_origin: Vector,
+    ray_direction: Vector,
+    targets: &[Rect],
+    out: &mut Vec<(usize, RayIntersectionResult)>,
+) -> usize {
+    let ray = RayPrecomputed::new(ray_origin, ray_direction);
 
-    match time_near.x.cmp(&time_near.y) {
-        Ordering::Greater => {
-            contact_normal = if ray_direction.x > 0 {
-                Vector::right()
-            } else {
-                Vector::left()
-            };
-        }
-        Ordering::Less => {
-            contact_normal = if ray_direction.y > 0 {
-                Vector::up()
-            } else {
-                Vector::down()
-            };
-        }
-        Ordering::Equal => {
-            // Handle the case where time_near.x == time_near.y, if needed
-        }
-    }
+    out.clear();
+    out.extend(
+        targets
+            .iter()
+            .enumerate()
+            .filter_map(|(index, target)| ray.vs_rect(*target).map(|result| (index, result))),
+    );
+    // The index breaks ties, so the order never depends on the sort algorithm
+    out.sort_unstable_by_key(|(index, result)| (result.closest_time, *index));
 
-    Some(RayIntersectionResult {
-        contact_point,
-        contact_normal,
-        closest_time,
-    })
+    out.len()
 }
 
 /// Checks for intersection between a vertically swept rectangle and a target rectangle.
@@ -280,6 +2115,8 @@ pub fn ray_vs_rect(
 /// and the target rectangle within the valid time range `[0, 1)`. The [`Fp`] value
 /// represents the normalized time at which the intersection occurs. If there is no intersection
 /// or if the intersection does not occur within the valid time range, `None` is returned.
+/// `None` is also returned if the delta is larger than
+/// [`MAX_SWEEP_DELTA`](crate::limits::MAX_SWEEP_DELTA).
 ///
 /// # Example
 ///
@@ -304,6 +2141,13 @@ pub fn ray_vs_rect(
 ///
 #[must_use]
 pub fn swept_rect_vs_rect_vertical_time(origin: Rect, target: Rect, y_delta: Fp) -> Option<Fp> {
+    if !delta_in_range(Vector::new(Fp::zero(), y_delta)) {
+        return None;
+    }
+
+    let origin = normalize_rect(origin);
+    let target = normalize_rect(target);
+
     let combined_target_rect = Rect {
         pos: target.pos,
         size: target.size + origin.size,
@@ -375,6 +2219,8 @@ pub fn ray_vs_rect_vertical_time(
         return None;
     }
 
+    let target_rect = normalize_rect(target_rect);
+
     if ray_origin.x < target_rect.pos.x || ray_origin.x > target_rect.pos.x + target_rect.size.x {
         return None;
     }
@@ -414,6 +2260,8 @@ pub fn ray_vs_rect_vertical_time(
 /// The `Fp` value represents the time at which the intersection occurs. If there
 /// is no intersection or if the intersection does not occur within the valid normalized time
 /// range `[0, 1)`, `None` is returned.
+/// `None` is also returned if the delta is larger than
+/// [`MAX_SWEEP_DELTA`](crate::limits::MAX_SWEEP_DELTA).
 ///
 /// # Example
 ///
@@ -437,6 +2285,13 @@ pub fn ray_vs_rect_vertical_time(
 /// ```
 #[must_use]
 pub fn swept_rect_vs_rect_horizontal_time(origin: Rect, target: Rect, x_delta: Fp) -> Option<Fp> {
+    if !delta_in_range(Vector::new(x_delta, Fp::zero())) {
+        return None;
+    }
+
+    let origin = normalize_rect(origin);
+    let target = normalize_rect(target);
+
     let expanded_target = Rect {
         pos: target.pos,
         size: target.size + origin.size,
@@ -508,6 +2363,8 @@ pub fn ray_vs_rect_horizontal_time(
         return None;
     }
 
+    let target_rect = normalize_rect(target_rect);
+
     if ray_origin.y < target_rect.pos.y || ray_origin.y >= target_rect.pos.y + target_rect.size.y {
         return None;
     }
@@ -0,0 +1,70 @@
+/*
+ * Copyright (c) Peter Bjorklund. All rights reserved. https://github.com/piot/impact-rs
+ * Licensed under the MIT License. See LICENSE in the project root for license information.
+ */
+
+//! The range of motions that the swept queries can resolve precisely.
+//!
+//! A swept query reports `closest_time` as a fraction of `delta` with 16 fractional bits, so a
+//! contact can only be placed to within `|delta| / 65536` along the motion. For very large
+//! deltas a target that is close to, but not touching, the mover rounds to a time of zero and
+//! is reported as an immediate contact. The swept rectangle queries therefore reject deltas
+//! outside the safe range.
+
+use std::sync::{PoisonError, RwLock};
+
+use fixed32::Fp;
+use fixed32_math::Vector;
+
+/// The largest magnitude of each component of `delta` accepted by the swept rectangle
+/// queries, 4096 units.
+///
+/// At this magnitude one step of `closest_time` moves the mover by 1/16 of a unit, so a
+/// reported contact is never further than that from the true one. Larger motions should be
+/// split into several sweeps.
+pub const MAX_SWEEP_DELTA: Fp = Fp::from_raw(4096 * Fp::SCALE);
+
+static DELTA_WARNING_HOOK: RwLock<Option<fn(Vector)>> = RwLock::new(None);
+
+/// Sets a function that is called with every `delta` rejected for being outside the range
+/// given by [`MAX_SWEEP_DELTA`], or removes it with `None`.
+///
+/// The rejected query still returns `None`. The hook is meant for logging or asserting in
+/// development builds, so that an oversized motion does not silently pass through walls.
+///
+/// # Example
+///
+/// ```rust
+/// use fixed32_math::{Rect, Vector};
+/// use impact_rs::limits::set_delta_warning_hook;
+/// use impact_rs::prelude::*;
+///
+/// set_delta_warning_hook(Some(|delta| eprintln!("sweep too long: {delta:?}")));
+///
+/// let origin = Rect::from((0, 0, 1, 1));
+/// assert!(swept_rect_vs_rect(origin, Rect::from((2, 0, 1, 1)), Vector::from((20000, 0))).is_none());
+///
+/// set_delta_warning_hook(None);
+/// ```
+pub fn set_delta_warning_hook(hook: Option<fn(Vector)>) {
+    *DELTA_WARNING_HOOK
+        .write()
+        .unwrap_or_else(PoisonError::into_inner) = hook;
+}
+
+/// Checks that both components of `delta` are within [`MAX_SWEEP_DELTA`], calling the
+/// warning hook if they are not.
+pub(crate) fn delta_in_range(delta: Vector) -> bool {
+    let in_range = delta.x.abs() <= MAX_SWEEP_DELTA && delta.y.abs() <= MAX_SWEEP_DELTA;
+
+    if !in_range {
+        let hook = *DELTA_WARNING_HOOK
+            .read()
+            .unwrap_or_else(PoisonError::into_inner);
+        if let Some(hook) = hook {
+            hook(delta);
+        }
+    }
+
+    in_range
+}
@@ -0,0 +1,321 @@
+/*
+ * Copyright (c) Peter Bjorklund. All rights reserved. https://github.com/piot/impact-rs
+ * Licensed under the MIT License. See LICENSE in the project root for license information.
+ */
+
+//! Vector math primitives used by the queries, implemented purely in fixed point.
+
+use std::cmp::{max, min, Ordering};
+
+use fixed32::Fp;
+use fixed32_math::Vector;
+
+use crate::RayIntersectionResult;
+
+/// The tolerance used by the crate wherever a query needs one and the caller does not give
+/// it, 1/64 of a unit (1024 raw units).
+///
+/// This is coarse enough to absorb the few raw units that the rounding of a query can move a
+/// result by, even after several chained queries, and fine enough to stay well below the
+/// size of anything a game would place in the world. Pass it to [`approx_eq`] or
+/// [`is_grounded`](crate::contact::is_grounded) to get the same behavior as the crate's own
+/// checks, such as [`platform_carry`](crate::contact::platform_carry).
+pub const DEFAULT_EPSILON: Fp = Fp::from_raw(Fp::SCALE / 64);
+
+/// Computes the dot product of two vectors.
+///
+/// The result is zero for perpendicular vectors, positive when they point in roughly the
+/// same direction and negative when they point in roughly opposite directions.
+///
+/// # Example
+///
+/// ```rust
+/// use fixed32::Fp;
+/// use fixed32_math::Vector;
+/// use impact_rs::math::dot;
+///
+/// assert_eq!(dot(Vector::from((1, 2)), Vector::from((3, 4))), Fp::from(11));
+/// ```
+#[inline]
+#[must_use]
+pub fn dot(a: Vector, b: Vector) -> Fp {
+    a.x * b.x + a.y * b.y
+}
+
+/// Computes the 2D cross product (the z component of the 3D cross product).
+///
+/// The result is zero for parallel vectors, positive when `b` is counter-clockwise from `a`
+/// and negative when it is clockwise.
+///
+/// # Example
+///
+/// ```rust
+/// use fixed32::Fp;
+/// use fixed32_math::Vector;
+/// use impact_rs::math::cross;
+///
+/// assert_eq!(cross(Vector::right(), Vector::up()), Fp::one());
+/// ```
+#[inline]
+#[must_use]
+pub fn cross(a: Vector, b: Vector) -> Fp {
+    a.x * b.y - a.y * b.x
+}
+
+/// Rotates `v` by 90 degrees counter-clockwise, returning `(-v.y, v.x)`.
+///
+/// With the y-axis pointing up, the result is on the left of `v`, so
+/// `cross(v, perpendicular(v))` is never negative. The rotation is exact, which makes it the
+/// way to get the tangent of a contact normal to slide along. Use [`perpendicular_cw`] for
+/// the other tangent.
+///
+/// # Example
+///
+/// ```rust
+/// use fixed32_math::Vector;
+/// use impact_rs::math::perpendicular;
+///
+/// assert_eq!(perpendicular(Vector::right()), Vector::up());
+/// assert_eq!(perpendicular(Vector::from((2, 1))), Vector::from((-1, 2)));
+/// ```
+#[inline]
+#[must_use]
+pub fn perpendicular(v: Vector) -> Vector {
+    Vector::new(-v.y, v.x)
+}
+
+/// Rotates `v` by 90 degrees clockwise, returning `(v.y, -v.x)`.
+///
+/// This is the opposite of [`perpendicular`]: with the y-axis pointing up, the result is on
+/// the right of `v`.
+///
+/// # Example
+///
+/// ```rust
+/// use fixed32_math::Vector;
+/// use impact_rs::math::perpendicular_cw;
+///
+/// assert_eq!(perpendicular_cw(Vector::right()), Vector::down());
+/// assert_eq!(perpendicular_cw(Vector::from((2, 1))), Vector::from((1, -2)));
+/// ```
+#[inline]
+#[must_use]
+pub fn perpendicular_cw(v: Vector) -> Vector {
+    Vector::new(v.y, -v.x)
+}
+
+/// Returns the smaller of each component of `a` and `b`.
+///
+/// # Example
+///
+/// ```rust
+/// use fixed32_math::Vector;
+/// use impact_rs::math::vec_min;
+///
+/// assert_eq!(vec_min(Vector::from((1, -2)), Vector::from((-3, 4))), Vector::from((-3, -2)));
+/// ```
+#[inline]
+#[must_use]
+pub fn vec_min(a: Vector, b: Vector) -> Vector {
+    Vector::new(min(a.x, b.x), min(a.y, b.y))
+}
+
+/// Returns the larger of each component of `a` and `b`.
+///
+/// # Example
+///
+/// ```rust
+/// use fixed32_math::Vector;
+/// use impact_rs::math::vec_max;
+///
+/// assert_eq!(vec_max(Vector::from((1, -2)), Vector::from((-3, 4))), Vector::from((1, 4)));
+/// ```
+#[inline]
+#[must_use]
+pub fn vec_max(a: Vector, b: Vector) -> Vector {
+    Vector::new(max(a.x, b.x), max(a.y, b.y))
+}
+
+/// Divides `numerator` by `denominator`, saturating instead of panicking when the quotient
+/// does not fit in an [`Fp`].
+///
+/// The quotient is computed with a single rounding step, so `saturating_div(a, b)` is more
+/// precise than `a * (Fp::one() / b)`, where the rounding error of the reciprocal is scaled up
+/// by `a`. Small denominators produce large quotients that are clamped to [`Fp::MAX`] or
+/// [`Fp::MIN`] depending on the sign. A zero denominator is treated as an infinitely small
+/// one with the sign of the numerator, and `0 / 0` is zero.
+///
+/// # Example
+///
+/// ```rust
+/// use fixed32::Fp;
+/// use impact_rs::math::saturating_div;
+///
+/// assert_eq!(saturating_div(Fp::from(300), Fp::from(3)), Fp::from(100));
+/// assert_eq!(saturating_div(Fp::from(1000), Fp::from_raw(1)), Fp::MAX);
+/// assert_eq!(saturating_div(Fp::from(-1), Fp::zero()), Fp::MIN);
+/// ```
+#[must_use]
+pub fn saturating_div(numerator: Fp, denominator: Fp) -> Fp {
+    let numerator = i64::from(numerator.inner());
+    let denominator = i64::from(denominator.inner());
+
+    if denominator == 0 {
+        return match numerator.cmp(&0) {
+            Ordering::Greater => Fp::MAX,
+            Ordering::Less => Fp::MIN,
+            Ordering::Equal => Fp::zero(),
+        };
+    }
+
+    let quotient = numerator * Fp::SCALE_I64 / denominator;
+    Fp::from_raw(quotient.clamp(i64::from(i32::MIN), i64::from(i32::MAX)) as i32)
+}
+
+/// The direction in which a result that is not exactly representable is rounded.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Rounding {
+    /// Rounds down, so the result never overestimates the exact value.
+    #[default]
+    Floor,
+    /// Rounds up, so the result never underestimates the exact value.
+    Ceil,
+}
+
+/// Computes the length of `v`, rounded in the given direction.
+///
+/// The length is the exact integer square root of the squared raw components, computed in
+/// 64-bit integers, so the only error is the final rounding to the nearest raw unit in the
+/// direction of `rounding`. [`Rounding::Floor`] never reports more than the exact length and
+/// [`Rounding::Ceil`] never reports less, which lets a caller choose a conservative estimate.
+/// A length that does not fit in an [`Fp`] saturates to [`Fp::MAX`].
+///
+/// # Example
+///
+/// ```rust
+/// use fixed32::Fp;
+/// use fixed32_math::Vector;
+/// use impact_rs::math::{length, Rounding};
+///
+/// assert_eq!(length(Vector::from((3, 4)), Rounding::Floor), Fp::from(5));
+///
+/// let diagonal = Vector::from((1, 1));
+/// assert_eq!(
+///     length(diagonal, Rounding::Ceil) - length(diagonal, Rounding::Floor),
+///     Fp::from_raw(1)
+/// );
+/// ```
+#[must_use]
+pub fn length(v: Vector, rounding: Rounding) -> Fp {
+    raw_length(i64::from(v.x.inner()), i64::from(v.y.inner()), rounding)
+}
+
+/// Computes the length of a vector given by its raw components, see [`length`].
+pub(crate) fn raw_length(x: i64, y: i64, rounding: Rounding) -> Fp {
+    Fp::from_raw(i32::try_from(raw_root(x, y, rounding)).unwrap_or(i32::MAX))
+}
+
+/// Scales `v` to unit length, or returns `None` if it is zero.
+///
+/// Unlike [`Vector::normalize`], the length is computed with [`length`] in 64-bit integers,
+/// so vectors longer than about 181 units do not overflow.
+pub(crate) fn normalize(v: Vector) -> Option<Vector> {
+    let x = i64::from(v.x.inner());
+    let y = i64::from(v.y.inner());
+    let root = i64::try_from(raw_root(x, y, Rounding::Floor)).ok()?;
+    if root == 0 {
+        return None;
+    }
+
+    // Each component is at most the length, so the quotients are at most one
+    let scale = |component: i64| Fp::from_raw((component * Fp::SCALE_I64 / root) as i32);
+    Some(Vector::new(scale(x), scale(y)))
+}
+
+/// The exact integer square root of `x² + y²`, rounded in the given direction.
+fn raw_root(x: i64, y: i64, rounding: Rounding) -> u128 {
+    let squared = u128::from(x.unsigned_abs()).pow(2) + u128::from(y.unsigned_abs()).pow(2);
+    let mut root = squared.isqrt();
+    if rounding == Rounding::Ceil && root * root < squared {
+        root += 1;
+    }
+    root
+}
+
+/// Computes `to - from` on the raw values, so the difference can not wrap.
+pub(crate) fn raw_offset(from: Vector, to: Vector) -> [i64; 2] {
+    [
+        i64::from(to.x.inner()) - i64::from(from.x.inner()),
+        i64::from(to.y.inner()) - i64::from(from.y.inner()),
+    ]
+}
+
+/// Computes [`cross`] of two raw vectors, such as those from [`raw_offset`], in 128-bit
+/// integers. The result is in the square of the raw scale.
+pub(crate) fn raw_cross(a: [i64; 2], b: [i64; 2]) -> i128 {
+    i128::from(a[0]) * i128::from(b[1]) - i128::from(a[1]) * i128::from(b[0])
+}
+
+/// Divides two values in the same scale, such as two [`raw_cross`] results, and returns the
+/// raw [`Fp`] quotient rounded in the given direction, without clamping it. The `denominator`
+/// must not be zero.
+pub(crate) fn raw_quotient(numerator: i128, denominator: i128, rounding: Rounding) -> i128 {
+    let (numerator, denominator) = if denominator < 0 {
+        (-numerator, -denominator)
+    } else {
+        (numerator, denominator)
+    };
+    let scaled = numerator * i128::from(Fp::SCALE_I64);
+    match rounding {
+        Rounding::Floor => scaled.div_euclid(denominator),
+        Rounding::Ceil => -(-scaled).div_euclid(denominator),
+    }
+}
+
+/// Checks if two values differ by at most `eps`.
+///
+/// The difference is computed without overflow, so values at opposite ends of the [`Fp`]
+/// range compare as far apart instead of wrapping around. Useful for comparing results
+/// that can change by a few raw units when the rounding of a query changes.
+/// [`DEFAULT_EPSILON`] is a good `eps` when there is no better bound.
+///
+/// # Example
+///
+/// ```rust
+/// use fixed32::Fp;
+/// use impact_rs::math::approx_eq;
+///
+/// let eps = Fp::from(0.01);
+/// assert!(approx_eq(Fp::from(4) / Fp::from(3), Fp::from(1.333), eps));
+/// assert!(!approx_eq(Fp::from(1.5), Fp::from(1.333), eps));
+/// ```
+#[must_use]
+pub fn approx_eq(a: Fp, b: Fp, eps: Fp) -> bool {
+    (i64::from(a.inner()) - i64::from(b.inner())).abs() <= i64::from(eps.inner())
+}
+
+/// Checks if both components of two vectors differ by at most `eps`.
+///
+/// See [`approx_eq`].
+#[must_use]
+pub fn approx_eq_vector(a: Vector, b: Vector, eps: Fp) -> bool {
+    approx_eq(a.x, b.x, eps) && approx_eq(a.y, b.y, eps)
+}
+
+/// Checks if two query results are the same, allowing every value to differ by at most
+/// `eps`.
+///
+/// The `closest_time`, `contact_point` and `contact_normal` are compared with
+/// [`approx_eq`] and [`approx_eq_vector`]. The `face` must be equal, and `frame_fraction`
+/// must be `None` for both or compare with [`approx_eq`].
+#[must_use]
+pub fn approx_eq_result(a: &RayIntersectionResult, b: &RayIntersectionResult, eps: Fp) -> bool {
+    approx_eq(a.closest_time, b.closest_time, eps)
+        && approx_eq_vector(a.contact_point, b.contact_point, eps)
+        && approx_eq_vector(a.contact_normal, b.contact_normal, eps)
+        && a.face == b.face
+        && match (a.frame_fraction, b.frame_fraction) {
+            (Some(a_fraction), Some(b_fraction)) => approx_eq(a_fraction, b_fraction, eps),
+            (a_fraction, b_fraction) => a_fraction.is_none() && b_fraction.is_none(),
+        }
+}
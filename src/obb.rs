@@ -0,0 +1,133 @@
+/*
+ * Copyright (c) Peter Bjorklund. All rights reserved. https://github.com/piot/impact-rs
+ * Licensed under the MIT License. See LICENSE in the project root for license information.
+ */
+
+//! Collision queries against oriented (rotated) bounding boxes.
+
+use fixed32::Fp;
+use fixed32_math::{Rect, Vector};
+
+use crate::math::{dot, perpendicular};
+use crate::trig::rotate;
+use crate::{ray_vs_rect, ContactSide, RayIntersectionResult};
+
+/// An oriented bounding box (OBB): a rectangle rotated around its center.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Obb {
+    pub center: Vector,
+    /// Half the width and height of the box, measured along its own axes.
+    pub half_size: Vector,
+    /// The unit vector that the local x-axis of the box points along.
+    pub axis: Vector,
+}
+
+impl Obb {
+    /// Creates a box rotated counter-clockwise by `angle` radians.
+    #[must_use]
+    pub fn new(center: Vector, half_size: Vector, angle: Fp) -> Self {
+        Self {
+            center,
+            half_size,
+            axis: rotate(Vector::right(), angle),
+        }
+    }
+
+    /// Returns the unit vector that the local y-axis of the box points along.
+    #[must_use]
+    pub fn perpendicular_axis(&self) -> Vector {
+        perpendicular(self.axis)
+    }
+
+    /// Returns the smallest [`Rect`] containing the box.
+    #[must_use]
+    pub fn aabb(&self) -> Rect {
+        let perpendicular = self.perpendicular_axis();
+        let extent = Vector::new(
+            self.half_size.x * self.axis.x.abs() + self.half_size.y * perpendicular.x.abs(),
+            self.half_size.x * self.axis.y.abs() + self.half_size.y * perpendicular.y.abs(),
+        );
+        Rect {
+            pos: self.center - extent,
+            size: extent * 2,
+        }
+    }
+
+    /// Checks if a point is inside the box, including its edges.
+    #[must_use]
+    pub fn contains_point(&self, point: Vector) -> bool {
+        let local = self.local_vector(point - self.center);
+        local.x.abs() <= self.half_size.x && local.y.abs() <= self.half_size.y
+    }
+
+    fn local_vector(&self, v: Vector) -> Vector {
+        Vector::new(dot(v, self.axis), dot(v, self.perpendicular_axis()))
+    }
+
+    fn world_vector(&self, v: Vector) -> Vector {
+        self.axis * v.x + self.perpendicular_axis() * v.y
+    }
+}
+
+/// Performs a ray-OBB intersection test.
+///
+/// The ray is rotated into the local frame of the box, tested with [`ray_vs_rect`], and the
+/// result is rotated back. The parametric time is not changed by the rotation, so
+/// `closest_time` has the same meaning as for [`ray_vs_rect`].
+///
+/// # Returns
+///
+/// Returns `Some(RayIntersectionResult)` if the ray hits the box, where `contact_point` and
+/// `contact_normal` are in world space and `face` is the [`ContactSide`] of the dominant axis
+/// of the world space normal, or [`ContactSide::Corner`] for an exact corner hit.
+///
+/// Returns `None` if the ray misses or if the ray direction is zero.
+///
+/// # Example
+///
+/// ```rust
+/// use fixed32::Fp;
+/// use fixed32_math::Vector;
+/// use impact_rs::prelude::full::*;
+///
+/// // An unrotated box, given as its axis directly
+/// let obb = Obb {
+///     center: Vector::from((10, 0)),
+///     half_size: Vector::from((2, 2)),
+///     axis: Vector::right(),
+/// };
+///
+/// let result = ray_vs_obb(Vector::from((0, 0)), Vector::from((1, 0)), obb).unwrap();
+/// assert_eq!(result.closest_time, Fp::from(8));
+/// assert_eq!(result.contact_normal, Vector::right());
+/// ```
+#[must_use]
+pub fn ray_vs_obb(
+    ray_origin: Vector,
+    ray_direction: Vector,
+    target: Obb,
+) -> Option<RayIntersectionResult> {
+    let local_origin = target.local_vector(ray_origin - target.center);
+    let local_direction = target.local_vector(ray_direction);
+    let local_box = Rect {
+        pos: -target.half_size,
+        size: target.half_size * 2,
+    };
+
+    let local = ray_vs_rect(local_origin, local_direction, local_box)?;
+
+    let contact_normal = target.world_vector(local.contact_normal);
+    let face = if local.face == ContactSide::Corner {
+        ContactSide::Corner
+    } else {
+        ContactSide::from_normal(contact_normal)
+    };
+
+    Some(RayIntersectionResult {
+        contact_point: ray_origin + ray_direction * local.closest_time,
+        contact_normal,
+        closest_time: local.closest_time,
+        face,
+        frame_fraction: None,
+    })
+}
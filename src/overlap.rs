@@ -0,0 +1,540 @@
+/*
+ * Copyright (c) Peter Bjorklund. All rights reserved. https://github.com/piot/impact-rs
+ * Licensed under the MIT License. See LICENSE in the project root for license information.
+ */
+
+//! Static overlap tests and penetration resolution between rectangles.
+
+use std::cmp::{max, min};
+
+use fixed32::Fp;
+use fixed32_math::{Rect, Vector};
+
+use crate::broadphase::overlapping_pairs;
+use crate::math::dot;
+use crate::rect_util::normalize_rect;
+
+/// Checks if two rectangles overlap.
+///
+/// Rectangles that only share an edge or a corner are not considered overlapping, so a
+/// rectangle resting exactly on top of another is not reported.
+///
+/// # Example
+///
+/// ```rust
+/// use fixed32_math::Rect;
+/// use impact_rs::prelude::full::*;
+///
+/// assert!(rect_vs_rect(Rect::from((0, 0, 2, 2)), Rect::from((1, 1, 2, 2))));
+/// assert!(!rect_vs_rect(Rect::from((0, 0, 2, 2)), Rect::from((2, 0, 2, 2))));
+/// ```
+#[must_use]
+pub fn rect_vs_rect(a: Rect, b: Rect) -> bool {
+    overlap_axes(a, b) == (true, true)
+}
+
+/// Checks on which axes the projections of two rectangles overlap.
+///
+/// The rectangles overlap, as defined by [`rect_vs_rect`], exactly when both projections
+/// do. This is cheaper than [`rect_penetration`] and tells which axis keeps two rectangles
+/// apart, which is useful when diagnosing a separation. Projections that only touch do not
+/// overlap.
+///
+/// # Returns
+///
+/// Returns `(x_overlaps, y_overlaps)`.
+///
+/// # Example
+///
+/// ```rust
+/// use fixed32_math::Rect;
+/// use impact_rs::prelude::full::*;
+///
+/// // Side by side on the same row: apart on x only
+/// assert_eq!(overlap_axes(Rect::from((0, 0, 2, 2)), Rect::from((5, 1, 2, 2))), (false, true));
+/// ```
+#[must_use]
+pub fn overlap_axes(a: Rect, b: Rect) -> (bool, bool) {
+    let a = normalize_rect(a);
+    let b = normalize_rect(b);
+
+    (
+        a.left() < b.right() && a.right() > b.left(),
+        a.bottom() < b.top() && a.top() > b.bottom(),
+    )
+}
+
+/// Checks if a point is inside a rectangle.
+///
+/// The left and bottom edges are inside and the right and top edges are outside, so a
+/// point on the shared edge of two adjacent rectangles is inside exactly one of them.
+///
+/// # Example
+///
+/// ```rust
+/// use fixed32_math::{Rect, Vector};
+/// use impact_rs::prelude::full::*;
+///
+/// let r = Rect::from((0, 0, 2, 2));
+///
+/// assert!(point_in_rect(Vector::from((0, 1)), r));
+/// assert!(!point_in_rect(Vector::from((2, 1)), r));
+/// ```
+#[must_use]
+pub fn point_in_rect(point: Vector, r: Rect) -> bool {
+    normalize_rect(r).contains_point(&point)
+}
+
+/// Finds the rectangle that contains each point.
+///
+/// Every point is tested with [`point_in_rect`], so a point on the shared edge of two
+/// adjacent rectangles is assigned to exactly one of them. This is the same as calling
+/// [`point_in_rect`] for every pair, and is the place where a spatial lookup can be added
+/// later without changing callers.
+///
+/// # Returns
+///
+/// One entry per point, in the same order as `points`. Each entry is the index of the
+/// first rectangle in `rects` that contains the point, or `None` if no rectangle does.
+///
+/// # Example
+///
+/// ```rust
+/// use fixed32_math::{Rect, Vector};
+/// use impact_rs::prelude::full::*;
+///
+/// let tiles = [Rect::from((0, 0, 2, 2)), Rect::from((2, 0, 2, 2))];
+/// let points = [Vector::from((2, 1)), Vector::from((1, 1)), Vector::from((5, 1))];
+///
+/// assert_eq!(points_in_rects(&points, &tiles), vec![Some(1), Some(0), None]);
+/// ```
+#[must_use]
+pub fn points_in_rects(points: &[Vector], rects: &[Rect]) -> Vec<Option<usize>> {
+    let rects: Vec<Rect> = rects.iter().map(|&r| normalize_rect(r)).collect();
+
+    points
+        .iter()
+        .map(|point| rects.iter().position(|r| r.contains_point(point)))
+        .collect()
+}
+
+/// Checks if a rectangle overlaps any of the targets.
+///
+/// Uses the same test as [`rect_vs_rect`] and returns as soon as the first overlap is found,
+/// which makes it a cheap check before running a full resolution.
+///
+/// # Example
+///
+/// ```rust
+/// use fixed32_math::Rect;
+/// use impact_rs::prelude::full::*;
+///
+/// let targets = [Rect::from((4, 0, 2, 2)), Rect::from((1, 1, 2, 2))];
+///
+/// assert!(rect_overlaps_any(Rect::from((0, 0, 2, 2)), &targets));
+/// assert!(!rect_overlaps_any(Rect::from((10, 10, 2, 2)), &targets));
+/// ```
+#[must_use]
+pub fn rect_overlaps_any(r: Rect, targets: &[Rect]) -> bool {
+    targets.iter().any(|&target| rect_vs_rect(r, target))
+}
+
+/// Checks if a rectangle would overlap any of the targets after moving by `delta`.
+///
+/// Only the destination is tested, with [`rect_overlaps_any`], so this is much cheaper than
+/// a sweep and suits trying many candidate moves. It does not see targets that the motion
+/// passes through on the way; use a sweep such as
+/// [`swept_rect_vs_rects`](crate::swept_rect_vs_rects) when the move can be longer than the
+/// targets are thick.
+///
+/// # Example
+///
+/// ```rust
+/// use fixed32_math::{Rect, Vector};
+/// use impact_rs::prelude::full::*;
+///
+/// let wall = [Rect::from((4, 0, 2, 10))];
+/// let origin = Rect::from((0, 0, 2, 2));
+///
+/// assert!(would_overlap(origin, &wall, Vector::from((3, 0))));
+/// assert!(!would_overlap(origin, &wall, Vector::from((2, 0))));
+/// ```
+#[must_use]
+pub fn would_overlap(origin: Rect, targets: &[Rect], delta: Vector) -> bool {
+    rect_overlaps_any(origin.move_by(delta), targets)
+}
+
+/// Finds every target that overlaps a rectangle.
+///
+/// Uses the same test as [`rect_vs_rect`]. Use [`rect_overlaps_any`] when only a yes or no
+/// answer is needed.
+///
+/// # Returns
+///
+/// The indices into `targets` of all the overlapping rectangles, in ascending order.
+///
+/// # Example
+///
+/// ```rust
+/// use fixed32_math::Rect;
+/// use impact_rs::prelude::full::*;
+///
+/// let targets = [
+///     Rect::from((1, 1, 2, 2)),
+///     Rect::from((4, 0, 2, 2)),
+///     Rect::from((-1, -1, 2, 2)),
+/// ];
+///
+/// assert_eq!(rect_overlaps_all(Rect::from((0, 0, 2, 2)), &targets), vec![0, 2]);
+/// ```
+#[must_use]
+pub fn rect_overlaps_all(r: Rect, targets: &[Rect]) -> Vec<usize> {
+    targets
+        .iter()
+        .enumerate()
+        .filter(|(_, &target)| rect_vs_rect(r, target))
+        .map(|(index, _)| index)
+        .collect()
+}
+
+/// Computes the minimum translation vector (MTV) that pushes `a` out of `b`.
+///
+/// The translation is along the axis with the smallest overlap, and points away from
+/// the center of `b`. If the overlap is equal on both axes, the vertical axis is used.
+///
+/// # Returns
+///
+/// Returns `Some(Vector)` with the translation to apply to `a` so that it only touches
+/// `b`, or `None` if the rectangles do not overlap.
+///
+/// # Example
+///
+/// ```rust
+/// use fixed32_math::{Rect, Vector};
+/// use impact_rs::prelude::full::*;
+///
+/// let player = Rect::from((0, 3, 2, 2));
+/// let floor = Rect::from((-10, 0, 20, 4));
+///
+/// assert_eq!(rect_penetration(player, floor), Some(Vector::from((0, 1))));
+/// ```
+#[must_use]
+pub fn rect_penetration(a: Rect, b: Rect) -> Option<Vector> {
+    let x = penetration_on_axis(a, b, Axis::X)?;
+    let y = penetration_on_axis(a, b, Axis::Y)?;
+
+    if x.abs() < y.abs() {
+        Some(Vector::new(x, Fp::zero()))
+    } else {
+        Some(Vector::new(Fp::zero(), y))
+    }
+}
+
+/// One of the two coordinate axes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Axis {
+    X,
+    Y,
+}
+
+/// Computes how far `a` must move along `axis` to stop overlapping `b` on that axis.
+///
+/// Only the projections of the rectangles onto `axis` are compared, so this is the depth
+/// to resolve when moving one axis at a time, even if the rectangles are apart on the other
+/// axis. The sign follows [`rect_penetration`]: the depth points away from the center of `b`.
+///
+/// # Returns
+///
+/// Returns `Some(Fp)` with the signed depth to add to `a` along `axis`, or `None` if the
+/// projections do not overlap. Projections that only touch do not overlap.
+///
+/// # Example
+///
+/// ```rust
+/// use fixed32::Fp;
+/// use fixed32_math::Rect;
+/// use impact_rs::prelude::full::*;
+///
+/// let player = Rect::from((3, 1, 2, 2));
+/// let wall = Rect::from((4, 0, 4, 8));
+///
+/// assert_eq!(penetration_on_axis(player, wall, Axis::X), Some(Fp::from(-1)));
+/// ```
+#[must_use]
+pub fn penetration_on_axis(a: Rect, b: Rect, axis: Axis) -> Option<Fp> {
+    let a = normalize_rect(a);
+    let b = normalize_rect(b);
+
+    let ((a_min, a_max), (b_min, b_max)) = match axis {
+        Axis::X => ((a.left(), a.right()), (b.left(), b.right())),
+        Axis::Y => ((a.bottom(), a.top()), (b.bottom(), b.top())),
+    };
+
+    let overlap = min(a_max, b_max) - max(a_min, b_min);
+    if overlap <= Fp::zero() {
+        return None;
+    }
+
+    if a_min + a_max < b_min + b_max {
+        Some(-overlap)
+    } else {
+        Some(overlap)
+    }
+}
+
+/// Finds the target that a rectangle overlaps the most.
+///
+/// Every target is tested with [`rect_penetration`], and the one with the longest minimum
+/// translation vector is chosen. Pushing the rectangle out of that target first is usually
+/// the easiest way to free it. If several targets are overlapped equally deep, the one with
+/// the lowest index wins.
+///
+/// # Returns
+///
+/// Returns `Some((index, translation))` with the index into `targets` and the translation
+/// that pushes `r` out of that target, or `None` if `r` overlaps no target.
+///
+/// # Example
+///
+/// ```rust
+/// use fixed32_math::{Rect, Vector};
+/// use impact_rs::prelude::full::*;
+///
+/// let targets = [Rect::from((-10, 0, 20, 4)), Rect::from((2, 0, 4, 10))];
+///
+/// assert_eq!(
+///     deepest_overlap(Rect::from((0, 3, 4, 4)), &targets),
+///     Some((1, Vector::from((-2, 0))))
+/// );
+/// ```
+#[must_use]
+pub fn deepest_overlap(r: Rect, targets: &[Rect]) -> Option<(usize, Vector)> {
+    targets
+        .iter()
+        .enumerate()
+        .filter_map(|(index, target)| {
+            rect_penetration(r, *target).map(|translation| (index, translation))
+        })
+        .fold(
+            None,
+            |deepest: Option<(usize, Vector)>, (index, translation)| {
+                let depth = translation.x.abs() + translation.y.abs();
+                match deepest {
+                    Some((_, best)) if best.x.abs() + best.y.abs() >= depth => deepest,
+                    _ => Some((index, translation)),
+                }
+            },
+        )
+}
+
+/// Pushes a rectangle free of all the targets it overlaps.
+///
+/// Each iteration finds the target with the largest penetration with [`deepest_overlap`],
+/// and applies its minimum translation vector. Resolving the deepest
+/// overlap first means that a rectangle pressed into a floor and a wall is pushed out
+/// of the wall before being lifted out of the floor, which converges for regular tile layouts.
+///
+/// # Parameters
+///
+/// - `origin`: The rectangle to push free.
+/// - `targets`: The static rectangles to push `origin` out of.
+/// - `max_iterations`: The maximum number of translations to apply.
+///
+/// # Returns
+///
+/// The translated rectangle. It is overlap-free unless `max_iterations` was reached first.
+///
+/// # Example
+///
+/// ```rust
+/// use fixed32_math::Rect;
+/// use impact_rs::prelude::full::*;
+///
+/// let tiles = [Rect::from((0, 0, 4, 4)), Rect::from((4, 0, 4, 4))];
+/// let resolved = depenetrate(Rect::from((3, 3, 2, 2)), &tiles, 4);
+///
+/// assert_eq!(resolved, Rect::from((3, 4, 2, 2)));
+/// ```
+#[must_use]
+pub fn depenetrate(origin: Rect, targets: &[Rect], max_iterations: usize) -> Rect {
+    let mut resolved = normalize_rect(origin);
+
+    for _ in 0..max_iterations {
+        match deepest_overlap(resolved, targets) {
+            Some((_, translation)) => resolved = resolved.move_by(translation),
+            None => break,
+        }
+    }
+
+    resolved
+}
+
+/// Computes a contact manifold for two overlapping rectangles.
+///
+/// The manifold describes how `a` is pressed into `b`, which is what a simple impulse
+/// solver needs for stable resting contacts:
+///
+/// - The contact points are the corners of `a` that lie inside `b`. If there are none
+///   (for example when `a` is wider than `b`), the corners of `b` inside `a` are used instead.
+///   When more than two corners qualify, the two that are deepest along the normal are kept.
+///   Rectangles crossing each other without any corner inside the other use the two corners
+///   of the overlapping region that are deepest along the normal.
+/// - The normal is a unit axis vector pointing from `a` into `b`, the opposite of the
+///   translation returned by [`rect_penetration`].
+/// - The depth is the length of the translation returned by [`rect_penetration`].
+///
+/// A face-face overlap produces two distinct contact points, while a corner overlap produces
+/// a single point, which is then stored in both entries of the array.
+///
+/// # Returns
+///
+/// Returns `Some((points, normal, depth))`, or `None` if the rectangles do not overlap.
+///
+/// # Example
+///
+/// ```rust
+/// use fixed32::Fp;
+/// use fixed32_math::{Rect, Vector};
+/// use impact_rs::prelude::full::*;
+///
+/// let crate_box = Rect::from((2, 3, 2, 2));
+/// let floor = Rect::from((0, 0, 10, 4));
+///
+/// let (points, normal, depth) = rect_contact_manifold(crate_box, floor).unwrap();
+/// assert_eq!(points, [Vector::from((2, 3)), Vector::from((4, 3))]);
+/// assert_eq!(normal, Vector::down());
+/// assert_eq!(depth, Fp::one());
+/// ```
+#[must_use]
+pub fn rect_contact_manifold(a: Rect, b: Rect) -> Option<([Vector; 2], Vector, Fp)> {
+    let a = normalize_rect(a);
+    let b = normalize_rect(b);
+
+    let translation = rect_penetration(a, b)?;
+    let depth = translation.x.abs() + translation.y.abs();
+    let normal = -(translation / depth);
+
+    let mut points: Vec<Vector> = corners(a)
+        .into_iter()
+        .filter(|corner| contains_inclusive(b, *corner))
+        .collect();
+
+    if points.is_empty() {
+        points = corners(b)
+            .into_iter()
+            .filter(|corner| contains_inclusive(a, *corner))
+            .collect();
+    }
+
+    if points.is_empty() {
+        let overlap = a.intersection(&b)?;
+        points = corners(overlap).to_vec();
+    }
+
+    // Deepest along the normal first. The sort is stable, so equally deep points keep corner order
+    points.sort_by_key(|point| -dot(*point, normal));
+
+    let first = points[0];
+    let second = points.get(1).copied().unwrap_or(first);
+
+    Some(([first, second], normal, depth))
+}
+
+/// The corners in the order lower-left, lower-right, upper-left, upper-right.
+pub(crate) fn corners(r: Rect) -> [Vector; 4] {
+    [
+        Vector::new(r.left(), r.bottom()),
+        Vector::new(r.right(), r.bottom()),
+        Vector::new(r.left(), r.top()),
+        Vector::new(r.right(), r.top()),
+    ]
+}
+
+pub(crate) fn contains_inclusive(r: Rect, point: Vector) -> bool {
+    point.x >= r.left() && point.x <= r.right() && point.y >= r.bottom() && point.y <= r.top()
+}
+
+/// Pushes a set of overlapping rectangles apart.
+///
+/// Each iteration finds the overlapping pairs with [`overlapping_pairs`] and, for every
+/// pair, moves each rectangle by half of the minimum translation vector from
+/// [`rect_penetration`], so both are pushed apart symmetrically. The pairs are resolved in
+/// ascending index order, which makes the result deterministic. The iterations stop early
+/// once no rectangles overlap.
+///
+/// # Example
+///
+/// ```rust
+/// use fixed32_math::Rect;
+/// use impact_rs::prelude::full::*;
+///
+/// let mut rects = [Rect::from((0, 0, 4, 4)), Rect::from((2, 0, 4, 4))];
+/// separate_rects(&mut rects, 4);
+///
+/// assert_eq!(rects, [Rect::from((-1, 0, 4, 4)), Rect::from((3, 0, 4, 4))]);
+/// ```
+pub fn separate_rects(rects: &mut [Rect], iterations: usize) {
+    for _ in 0..iterations {
+        let pairs = overlapping_pairs(rects);
+        if pairs.is_empty() {
+            break;
+        }
+
+        for (first, second) in pairs {
+            if let Some(translation) = rect_penetration(rects[first], rects[second]) {
+                let half = translation / 2;
+                rects[first] = normalize_rect(rects[first]).move_by(half);
+                rects[second] = normalize_rect(rects[second]).move_by(half - translation);
+            }
+        }
+    }
+}
+
+/// Pushes two overlapping rectangles apart, sharing the push by weight.
+///
+/// The minimum translation vector from [`rect_penetration`] is split so that each rectangle
+/// moves inversely proportional to its weight: `a` moves by `weight_b / (weight_a + weight_b)`
+/// of it and `b` moves the rest in the opposite direction. A rectangle is immovable when the
+/// other weight is zero or its own weight is [`Fp::MAX`], which stands for an infinite weight.
+/// Negative weights are treated as zero, and if both weights are zero the push is split evenly.
+/// Rectangles that do not overlap are not moved.
+///
+/// # Example
+///
+/// ```rust
+/// use fixed32::Fp;
+/// use fixed32_math::Rect;
+/// use impact_rs::prelude::full::*;
+///
+/// let mut heavy = Rect::from((0, 0, 4, 4));
+/// let mut light = Rect::from((0, 3, 4, 4));
+/// resolve_pair(&mut heavy, &mut light, Fp::from(3), Fp::one());
+///
+/// assert_eq!(heavy, Rect::from((0.0, -0.25, 4.0, 4.0)));
+/// assert_eq!(light, Rect::from((0.0, 3.75, 4.0, 4.0)));
+/// ```
+pub fn resolve_pair(a: &mut Rect, b: &mut Rect, weight_a: Fp, weight_b: Fp) {
+    let Some(translation) = rect_penetration(*a, *b) else {
+        return;
+    };
+
+    // An infinite weight against a finite one acts like a zero weight on the other side
+    let (weight_a, weight_b) = match (weight_a == Fp::MAX, weight_b == Fp::MAX) {
+        (true, false) => (Fp::one(), Fp::zero()),
+        (false, true) => (Fp::zero(), Fp::one()),
+        _ => (max(weight_a, Fp::zero()), max(weight_b, Fp::zero())),
+    };
+    let weight_b = i64::from(weight_b.inner());
+    let total = i64::from(weight_a.inner()) + weight_b;
+
+    let share_a = if total == 0 {
+        translation / 2
+    } else {
+        // Scales by `weight_b / total`, which is at most one, so the result always fits
+        let scale = |value: Fp| Fp::from_raw((i64::from(value.inner()) * weight_b / total) as i32);
+        Vector::new(scale(translation.x), scale(translation.y))
+    };
+
+    *a = normalize_rect(*a).move_by(share_a);
+    *b = normalize_rect(*b).move_by(share_a - translation);
+}
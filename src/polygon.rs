@@ -0,0 +1,74 @@
+/*
+ * Copyright (c) Peter Bjorklund. All rights reserved. https://github.com/piot/impact-rs
+ * Licensed under the MIT License. See LICENSE in the project root for license information.
+ */
+
+//! Helpers for validating polygon input.
+//!
+//! Polygons are given as a list of vertices, where the last vertex connects back to the
+//! first. Counter-clockwise (CCW) winding is the convention used by this crate: the inside
+//! of the polygon is to the left of every edge. [`swept_rect_vs_triangle`] accepts either
+//! winding, but code that builds edge normals from the vertices should call [`ensure_ccw`]
+//! first.
+//!
+//! [`swept_rect_vs_triangle`]: crate::triangle::swept_rect_vs_triangle
+
+use fixed32_math::Vector;
+
+/// Checks if the vertices of a polygon are in counter-clockwise order.
+///
+/// The winding is taken from the sign of the signed area (the shoelace formula), which is
+/// computed on the raw fixed-point values in 128-bit integers so it can not overflow.
+/// Degenerate polygons, with fewer than three vertices or all vertices on one line, have
+/// zero area and are not counter-clockwise.
+///
+/// # Example
+///
+/// ```rust
+/// use fixed32_math::Vector;
+/// use impact_rs::polygon::polygon_is_ccw;
+///
+/// let ccw = [Vector::from((0, 0)), Vector::from((4, 0)), Vector::from((0, 4))];
+/// let cw = [Vector::from((0, 0)), Vector::from((0, 4)), Vector::from((4, 0))];
+///
+/// assert!(polygon_is_ccw(&ccw));
+/// assert!(!polygon_is_ccw(&cw));
+/// ```
+#[must_use]
+pub fn polygon_is_ccw(vertices: &[Vector]) -> bool {
+    twice_signed_area(vertices) > 0
+}
+
+/// Reverses the vertices of a clockwise polygon, so that it is counter-clockwise.
+///
+/// Counter-clockwise and degenerate polygons, as defined by [`polygon_is_ccw`], are left
+/// unchanged.
+///
+/// # Example
+///
+/// ```rust
+/// use fixed32_math::Vector;
+/// use impact_rs::polygon::{ensure_ccw, polygon_is_ccw};
+///
+/// let mut vertices = vec![Vector::from((0, 0)), Vector::from((0, 4)), Vector::from((4, 0))];
+/// ensure_ccw(&mut vertices);
+///
+/// assert!(polygon_is_ccw(&vertices));
+/// ```
+pub fn ensure_ccw(vertices: &mut [Vector]) {
+    if twice_signed_area(vertices) < 0 {
+        vertices.reverse();
+    }
+}
+
+/// Returns twice the signed area of the polygon, in raw units squared.
+fn twice_signed_area(vertices: &[Vector]) -> i128 {
+    vertices
+        .iter()
+        .zip(vertices.iter().cycle().skip(1))
+        .map(|(a, b)| {
+            i128::from(a.x.inner()) * i128::from(b.y.inner())
+                - i128::from(a.y.inner()) * i128::from(b.x.inner())
+        })
+        .sum()
+}
@@ -3,7 +3,83 @@
  * Licensed under the MIT License. See LICENSE in the project root for license information.
  */
 
-pub use crate::{
-    ray_vs_rect, ray_vs_rect_horizontal_time, ray_vs_rect_vertical_time, swept_rect_vs_rect,
-    swept_rect_vs_rect_horizontal_time, swept_rect_vs_rect_vertical_time,
-};
+/*!
+Convenience re-exports.
+
+[`core`] contains the ray and sweep functions for rectangles, and
+[`full`] adds every shape, broadphase structure and helper in the crate. The
+`prelude` itself re-exports [`core`], so `use impact_rs::prelude::*;` keeps
+importing only the rectangle queries.
+
+```rust
+use impact_rs::prelude::*;
+
+let _ = ray_vs_rect;
+```
+
+```rust
+use impact_rs::prelude::core::*;
+
+let _ = swept_rect_vs_rect;
+```
+
+```rust
+use impact_rs::prelude::full::*;
+
+let _ = ray_vs_rect;
+let _ = World::new();
+```
+ */
+
+pub use self::core::*;
+
+/// The ray and sweep queries for rectangles.
+pub mod core {
+    pub use crate::{
+        ray_vs_rect, ray_vs_rect_horizontal_time, ray_vs_rect_vertical_time, swept_rect_vs_rect,
+        swept_rect_vs_rect_horizontal_time, swept_rect_vs_rect_vertical_time,
+    };
+}
+
+/// Everything in [`core`], plus all shapes, broadphase structures and helpers.
+pub mod full {
+    pub use super::core::*;
+    pub use crate::broadphase::{overlapping_pairs, overlapping_pairs_grouped};
+    pub use crate::bvh::AabbTree;
+    pub use crate::checked::{try_ray_vs_rect_checked, OverflowError};
+    pub use crate::circle::{ray_vs_circle, toi_circle_vs_circle, Circle};
+    pub use crate::collidable::{ray_vs_collidables, Collidable};
+    pub use crate::cone::cone_cast;
+    pub use crate::contact::{is_grounded, platform_carry, ContactSet};
+    pub use crate::debug::{ray_vs_rect_debug, result_debug_lines, RayDebug};
+    pub use crate::grid::SpatialGrid;
+    pub use crate::obb::{ray_vs_obb, Obb};
+    pub use crate::overlap::{
+        deepest_overlap, depenetrate, overlap_axes, penetration_on_axis, point_in_rect,
+        points_in_rects, rect_contact_manifold, rect_overlaps_all, rect_overlaps_any,
+        rect_penetration, rect_vs_rect, resolve_pair, separate_rects, would_overlap, Axis,
+    };
+    pub use crate::polygon::{ensure_ccw, polygon_is_ccw};
+    pub use crate::ray::{Ray, RayCastCache, RayQuery};
+    pub use crate::rect_util::{
+        clamp_rect_into, clamp_to_rect, distance_to_rect, inflate_rect, normalize_rect,
+        rect_from_center_size, rect_from_min_max, swept_aabb,
+    };
+    pub use crate::response::{
+        contact_impulse, move_and_ground, move_and_slide, move_and_slide_events,
+        move_and_slide_limited, move_and_slide_trace, move_axis_separated, resolve_velocity,
+        safe_position, ResolveStatus,
+    };
+    pub use crate::segment::Segment;
+    pub use crate::triangle::swept_rect_vs_triangle;
+    pub use crate::world::{ColliderId, ColliderShape, World, WorldSnapshot};
+    pub use crate::{
+        anti_tunnel_sweep, is_moving_toward, line_vs_rect, ray_vs_rect_distance,
+        ray_vs_rect_forward, ray_vs_rect_in_space, ray_vs_rect_normalized, ray_vs_rect_oneway,
+        ray_vs_rect_with, ray_vs_rect_with_boundary, ray_vs_rects, ray_vs_rects_all,
+        ray_vs_rects_all_into, ray_vs_swept_rect, swept_rect_overlap_interval, swept_rect_to,
+        swept_rect_vs_rect_inclusive, swept_rect_vs_rects, swept_rect_vs_rects_all,
+        swept_rect_vs_trigger, target_face, thick_ray_vs_rect, BoundaryMode, ContactNormal,
+        ContactSide, CoordinateSpace, CornerPolicy, RayIntersectionResult, RayPrecomputed,
+    };
+}
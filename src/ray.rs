@@ -0,0 +1,295 @@
+/*
+ * Copyright (c) Peter Bjorklund. All rights reserved. https://github.com/piot/impact-rs
+ * Licensed under the MIT License. See LICENSE in the project root for license information.
+ */
+
+//! A ray type bundling an origin and a direction, a builder for configuring ray queries, and a
+//! cache for casting the same ray repeatedly.
+
+use fixed32::Fp;
+use fixed32_math::{Rect, Vector};
+
+use crate::rect_util::normalize_rect;
+use crate::{
+    ray_vs_rect, ray_vs_rect_with, ray_vs_rects, CornerPolicy, RayIntersectionResult,
+    RayPrecomputed,
+};
+
+/// A ray starting at `origin` and cast along `direction`.
+///
+/// The methods forward to the free query functions, which stay available for code that
+/// passes the origin and direction separately. Keeping them together makes it impossible to
+/// swap the two by accident.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Ray {
+    pub origin: Vector,
+    /// The direction and length of the ray. Times along the ray are multiples of it.
+    pub direction: Vector,
+}
+
+impl Ray {
+    #[must_use]
+    pub const fn new(origin: Vector, direction: Vector) -> Self {
+        Self { origin, direction }
+    }
+
+    /// Returns the point at the parametric time `t`, `origin + t * direction`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fixed32::Fp;
+    /// use fixed32_math::Vector;
+    /// use impact_rs::ray::Ray;
+    ///
+    /// let ray = Ray::new(Vector::from((1, 1)), Vector::from((4, 2)));
+    /// assert_eq!(ray.point_at(Fp::from(0.5)), Vector::from((3, 2)));
+    /// ```
+    #[must_use]
+    pub fn point_at(&self, t: Fp) -> Vector {
+        self.origin + self.direction * t
+    }
+
+    /// Tests the ray against a rectangle with [`ray_vs_rect`].
+    #[must_use]
+    pub fn vs_rect(&self, target: Rect) -> Option<RayIntersectionResult> {
+        ray_vs_rect(self.origin, self.direction, target)
+    }
+
+    /// Finds the nearest rectangle hit by the ray with [`ray_vs_rects`].
+    #[must_use]
+    pub fn vs_rects(&self, targets: &[Rect]) -> Option<(usize, RayIntersectionResult)> {
+        ray_vs_rects(self.origin, self.direction, targets)
+    }
+}
+
+/// Creates a ray from an `(origin, direction)` pair of anything that converts into a
+/// [`Vector`], so both `Ray::from((origin, direction))` and `Ray::from(((0, 0), (1, 2)))`
+/// work.
+impl<O: Into<Vector>, D: Into<Vector>> From<(O, D)> for Ray {
+    fn from((origin, direction): (O, D)) -> Self {
+        Self::new(origin.into(), direction.into())
+    }
+}
+
+/// A ray query with options, built from a [`Ray`].
+///
+/// The defaults match [`ray_vs_rect`]. Each option is set with a method that returns the
+/// changed query, so they can be chained.
+///
+/// # Example
+///
+/// ```rust
+/// use fixed32_math::{Rect, Vector};
+/// use impact_rs::prelude::full::*;
+///
+/// let query = RayQuery::new(Ray::new(Vector::from((0, 0)), Vector::from((1, 1))))
+///     .corner_policy(CornerPolicy::PreferY)
+///     .conservative(true);
+///
+/// let result = query.vs_rect(Rect::from((5, 5, 10, 10))).unwrap();
+/// assert_eq!(result.contact_normal, Vector::up());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RayQuery {
+    ray: Ray,
+    corner_policy: CornerPolicy,
+    conservative: bool,
+}
+
+impl RayQuery {
+    #[must_use]
+    pub const fn new(ray: Ray) -> Self {
+        Self {
+            ray,
+            corner_policy: CornerPolicy::None,
+            conservative: false,
+        }
+    }
+
+    /// Sets how the normal of an exact corner hit is picked, see [`ray_vs_rect_with`].
+    #[must_use]
+    pub const fn corner_policy(mut self, corner_policy: CornerPolicy) -> Self {
+        self.corner_policy = corner_policy;
+        self
+    }
+
+    /// Sets if `closest_time` is rounded so that the contact point is never inside the target.
+    ///
+    /// `closest_time` and the `contact_point` are rounded to the precision of [`Fp`], which
+    /// can leave the contact point a raw unit inside the target surface. In conservative mode,
+    /// the time is moved back along the ray until `contact_point` is outside the target or on
+    /// its surface. For a ray starting outside the target this rounds the time toward the
+    /// origin. Resting a mover at a conservative contact point never leaves it overlapping
+    /// the target.
+    #[must_use]
+    pub const fn conservative(mut self, conservative: bool) -> Self {
+        self.conservative = conservative;
+        self
+    }
+
+    /// Tests the ray against a rectangle with the options of this query.
+    #[must_use]
+    pub fn vs_rect(&self, target: Rect) -> Option<RayIntersectionResult> {
+        let result = ray_vs_rect_with(
+            self.ray.origin,
+            self.ray.direction,
+            target,
+            self.corner_policy,
+        )?;
+
+        if self.conservative {
+            Some(self.pull_back(result, normalize_rect(target)))
+        } else {
+            Some(result)
+        }
+    }
+
+    /// Moves the contact back along the ray until it is not strictly inside `target`.
+    fn pull_back(&self, mut result: RayIntersectionResult, target: Rect) -> RayIntersectionResult {
+        // Every non-zero component of the direction moves by at least one raw unit per step
+        let smallest = [self.ray.direction.x, self.ray.direction.y]
+            .into_iter()
+            .filter(|component| !component.is_zero())
+            .map(|component| i64::from(component.inner()).abs())
+            .min()
+            .unwrap_or(1);
+        let step = Fp::from_raw(((Fp::SCALE_I64 + smallest - 1) / smallest) as i32);
+
+        while strictly_inside(target, result.contact_point) {
+            result.closest_time -= step;
+            result.contact_point = self.ray.point_at(result.closest_time);
+        }
+
+        result
+    }
+}
+
+fn strictly_inside(target: Rect, point: Vector) -> bool {
+    point.x > target.left()
+        && point.x < target.right()
+        && point.y > target.bottom()
+        && point.y < target.top()
+}
+
+/// Caches the results of casting one ray against a list of targets.
+///
+/// Editors and other interactive tools often cast the same ray again after changing a single
+/// target. The cache keeps the result of every target and only recasts the ones that were
+/// changed with [`RayCastCache::set_target`] or [`RayCastCache::invalidate`]. Results are
+/// computed lazily, the first time they are asked for, and are the same as [`ray_vs_rect`].
+///
+/// # Example
+///
+/// ```rust
+/// use fixed32::Fp;
+/// use fixed32_math::{Rect, Vector};
+/// use impact_rs::prelude::full::*;
+///
+/// let ray = Ray::new(Vector::from((0, 0)), Vector::from((1, 0)));
+/// let mut cache = RayCastCache::new(ray, &[Rect::from((10, -1, 2, 2)), Rect::from((20, -1, 2, 2))]);
+///
+/// assert_eq!(cache.nearest().unwrap().0, 0);
+///
+/// // Dragging the first target out of the way only recasts that target
+/// cache.set_target(0, Rect::from((10, 5, 2, 2)));
+/// assert_eq!(cache.nearest().unwrap().0, 1);
+/// assert_eq!(cache.nearest().unwrap().1.closest_time, Fp::from(20));
+/// ```
+#[derive(Debug, Clone)]
+pub struct RayCastCache {
+    ray: RayPrecomputed,
+    targets: Vec<Rect>,
+    /// The cached result for each target, `None` until the target has been cast against.
+    results: Vec<Option<Option<RayIntersectionResult>>>,
+}
+
+impl RayCastCache {
+    #[must_use]
+    pub fn new(ray: Ray, targets: &[Rect]) -> Self {
+        Self {
+            ray: RayPrecomputed::new(ray.origin, ray.direction),
+            targets: targets.to_vec(),
+            results: vec![None; targets.len()],
+        }
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.targets.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.targets.is_empty()
+    }
+
+    /// Returns the target at `index`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    #[must_use]
+    pub fn target(&self, index: usize) -> Rect {
+        self.targets[index]
+    }
+
+    /// Replaces the target at `index`, discarding its cached result if the target changed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn set_target(&mut self, index: usize, target: Rect) {
+        if self.targets[index] != target {
+            self.targets[index] = target;
+            self.invalidate(index);
+        }
+    }
+
+    /// Discards the cached result of the target at `index`, so that it is cast again.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn invalidate(&mut self, index: usize) {
+        self.results[index] = None;
+    }
+
+    /// Checks if the result of the target at `index` is cached.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    #[must_use]
+    pub fn is_cached(&self, index: usize) -> bool {
+        self.results[index].is_some()
+    }
+
+    /// Returns the result of the ray against the target at `index`, casting it if the result
+    /// is not cached.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn result(&mut self, index: usize) -> Option<&RayIntersectionResult> {
+        let target = self.targets[index];
+        let ray = self.ray;
+        self.results[index]
+            .get_or_insert_with(|| ray.vs_rect(target))
+            .as_ref()
+    }
+
+    /// Finds the nearest target hit by the ray, the same as
+    /// [`ray_vs_rects`](crate::ray_vs_rects), casting only the targets that are not cached.
+    pub fn nearest(&mut self) -> Option<(usize, &RayIntersectionResult)> {
+        for index in 0..self.targets.len() {
+            let _ = self.result(index);
+        }
+
+        self.results
+            .iter()
+            .enumerate()
+            .filter_map(|(index, result)| result.as_ref()?.as_ref().map(|result| (index, result)))
+            .min_by_key(|(_, result)| result.closest_time)
+    }
+}
@@ -0,0 +1,314 @@
+/*
+ * Copyright (c) Peter Bjorklund. All rights reserved. https://github.com/piot/impact-rs
+ * Licensed under the MIT License. See LICENSE in the project root for license information.
+ */
+
+//! Helpers for constructing and adjusting [`Rect`]s before they are used in queries.
+
+use fixed32::Fp;
+use fixed32_math::{Rect, Vector};
+
+use crate::math::{raw_length, vec_max, vec_min, Rounding};
+use crate::ContactSide;
+
+/// Returns a rectangle covering the same region as `r`, but with non-negative size.
+///
+/// A [`Rect`] with a negative width or height (for example built from a drag selection
+/// going left or down) describes the region between `pos` and `pos + size`. This function
+/// moves `pos` to the lower-left corner of that region and flips the negative extents.
+/// Rectangles that already have a non-negative size are returned unchanged.
+///
+/// All the query functions in this crate call this on their rectangle parameters, so it
+/// is only needed when working with the rectangle directly.
+///
+/// # Example
+///
+/// ```rust
+/// use fixed32_math::Rect;
+/// use impact_rs::prelude::full::*;
+///
+/// let dragged = Rect::from((10, 10, -4, 6));
+/// assert_eq!(normalize_rect(dragged), Rect::from((6, 10, 4, 6)));
+/// ```
+#[must_use]
+pub fn normalize_rect(r: Rect) -> Rect {
+    let (x, width) = normalize_extent(r.pos.x, r.size.x);
+    let (y, height) = normalize_extent(r.pos.y, r.size.y);
+
+    Rect {
+        pos: Vector::new(x, y),
+        size: Vector::new(width, height),
+    }
+}
+
+/// Creates a rectangle spanning from the `min` corner to the `max` corner.
+///
+/// # Panics
+///
+/// Panics if a component of `max` is smaller than the same component of `min`.
+///
+/// # Example
+///
+/// ```rust
+/// use fixed32_math::{Rect, Vector};
+/// use impact_rs::prelude::full::*;
+///
+/// let rect = rect_from_min_max(Vector::from((2, 1)), Vector::from((6, 4)));
+/// assert_eq!(rect, Rect::from((2, 1, 4, 3)));
+/// ```
+#[must_use]
+pub fn rect_from_min_max(min: Vector, max: Vector) -> Rect {
+    assert!(
+        max.x >= min.x && max.y >= min.y,
+        "max must not be smaller than min"
+    );
+
+    Rect {
+        pos: min,
+        size: max - min,
+    }
+}
+
+/// Creates a rectangle of `size` centered on `center`.
+///
+/// # Panics
+///
+/// Panics if a component of `size` is negative.
+///
+/// # Example
+///
+/// ```rust
+/// use fixed32_math::{Rect, Vector};
+/// use impact_rs::prelude::full::*;
+///
+/// let rect = rect_from_center_size(Vector::from((5, 5)), Vector::from((4, 2)));
+/// assert_eq!(rect, Rect::from((3, 4, 4, 2)));
+/// ```
+#[must_use]
+pub fn rect_from_center_size(center: Vector, size: Vector) -> Rect {
+    assert!(
+        size.x >= Fp::zero() && size.y >= Fp::zero(),
+        "size must not be negative"
+    );
+
+    Rect {
+        pos: center - size / 2,
+        size,
+    }
+}
+
+/// Grows a rectangle by `amount` on every side, or shrinks it for a negative `amount`.
+///
+/// The rectangle is normalized with [`normalize_rect`] first, and each side moves outwards
+/// by `amount`, so the width and height change by twice `amount` and the center stays put.
+/// Shrinking by more than half the width or height clamps that size to zero instead of
+/// letting it go negative: the rectangle collapses onto its center line on that axis.
+///
+/// # Example
+///
+/// ```rust
+/// use fixed32::Fp;
+/// use fixed32_math::Rect;
+/// use impact_rs::prelude::full::*;
+///
+/// let rect = Rect::from((0, 0, 10, 4));
+///
+/// assert_eq!(inflate_rect(rect, Fp::one()), Rect::from((-1, -1, 12, 6)));
+/// assert_eq!(inflate_rect(rect, Fp::from(-3)), Rect::from((3, 2, 4, 0)));
+/// ```
+#[must_use]
+pub fn inflate_rect(r: Rect, amount: Fp) -> Rect {
+    let r = normalize_rect(r);
+    let (x, width) = inflate_extent(r.pos.x, r.size.x, amount);
+    let (y, height) = inflate_extent(r.pos.y, r.size.y, amount);
+
+    Rect {
+        pos: Vector::new(x, y),
+        size: Vector::new(width, height),
+    }
+}
+
+/// Clamps each component of `point` into the range covered by `rect`.
+///
+/// A point inside the rectangle, including its edges, is returned unchanged. A point outside
+/// is moved to the closest point on the edge of the rectangle, which makes this the
+/// building block for closest-point queries and for keeping a position inside bounds.
+///
+/// # Example
+///
+/// ```rust
+/// use fixed32_math::{Rect, Vector};
+/// use impact_rs::prelude::full::*;
+///
+/// let bounds = Rect::from((0, 0, 10, 4));
+///
+/// assert_eq!(clamp_to_rect(Vector::from((12, -3)), bounds), Vector::from((10, 0)));
+/// assert_eq!(clamp_to_rect(Vector::from((5, 2)), bounds), Vector::from((5, 2)));
+/// ```
+#[must_use]
+pub fn clamp_to_rect(point: Vector, rect: Rect) -> Vector {
+    let rect = normalize_rect(rect);
+
+    Vector::new(
+        point.x.clamp(rect.left(), rect.right()),
+        point.y.clamp(rect.bottom(), rect.top()),
+    )
+}
+
+/// Computes the distance from `point` to the closest point of `rect`, rounded in the given
+/// direction.
+///
+/// The distance is zero for a point inside the rectangle, including its edges. It is computed
+/// with [`length`](crate::math::length) from the offset to [`clamp_to_rect`], in 64-bit
+/// integers so that points far outside the rectangle do not overflow. [`Rounding::Floor`]
+/// never overestimates the exact distance and [`Rounding::Ceil`] never underestimates it.
+///
+/// # Example
+///
+/// ```rust
+/// use fixed32::Fp;
+/// use fixed32_math::{Rect, Vector};
+/// use impact_rs::math::Rounding;
+/// use impact_rs::prelude::full::*;
+///
+/// let rect = Rect::from((0, 0, 10, 4));
+///
+/// assert_eq!(distance_to_rect(Vector::from((13, 8)), rect, Rounding::Floor), Fp::from(5));
+/// assert_eq!(distance_to_rect(Vector::from((5, 2)), rect, Rounding::Ceil), Fp::zero());
+/// ```
+#[must_use]
+pub fn distance_to_rect(point: Vector, rect: Rect, rounding: Rounding) -> Fp {
+    let closest = clamp_to_rect(point, rect);
+
+    raw_length(
+        i64::from(point.x.inner()) - i64::from(closest.x.inner()),
+        i64::from(point.y.inner()) - i64::from(closest.y.inner()),
+        rounding,
+    )
+}
+
+/// Returns the bounding rect of `origin` at the start and at the end of a motion by `delta`.
+///
+/// The result covers every position of `origin` during the motion, so anything it does not
+/// overlap can be skipped before running a swept query. It has a non-negative size.
+///
+/// # Example
+///
+/// ```rust
+/// use fixed32_math::{Rect, Vector};
+/// use impact_rs::prelude::full::*;
+///
+/// let bounds = swept_aabb(Rect::from((0, 0, 2, 2)), Vector::from((5, -3)));
+/// assert_eq!(bounds, Rect::from((0, -3, 7, 5)));
+/// ```
+#[must_use]
+pub fn swept_aabb(origin: Rect, delta: Vector) -> Rect {
+    let origin = normalize_rect(origin);
+    let end = origin.move_by(delta);
+
+    let min = vec_min(origin.pos, end.pos);
+    let max = vec_max(origin.pos + origin.size, end.pos + end.size);
+
+    Rect {
+        pos: min,
+        size: max - min,
+    }
+}
+
+/// Moves `inner` the shortest distance that puts it fully inside `bounds`.
+///
+/// This keeps a character or camera inside the level. Each axis is handled on its own, so a
+/// rect poking out of a corner is moved back along both axes. A rect that is larger than
+/// `bounds` along an axis can not fit, so it is aligned with the left (or bottom) edge of
+/// `bounds` and pokes out of the opposite side.
+///
+/// # Returns
+///
+/// Returns the moved rect, with a non-negative size, and the side of `inner` that was
+/// pressed against the boundary: [`ContactSide::Right`] if it poked out of the right of
+/// `bounds`, [`ContactSide::Corner`] if it was moved along both axes, and `None` if it
+/// already was inside. A rect that only touches the boundary is not moved and reports
+/// `None`.
+///
+/// # Example
+///
+/// ```rust
+/// use fixed32_math::Rect;
+/// use impact_rs::prelude::full::*;
+///
+/// let level = Rect::from((0, 0, 100, 50));
+///
+/// let (clamped, side) = clamp_rect_into(Rect::from((98, 10, 4, 4)), level);
+/// assert_eq!(clamped, Rect::from((96, 10, 4, 4)));
+/// assert_eq!(side, Some(ContactSide::Right));
+/// ```
+#[must_use]
+pub fn clamp_rect_into(inner: Rect, bounds: Rect) -> (Rect, Option<ContactSide>) {
+    let inner = normalize_rect(inner);
+    let bounds = normalize_rect(bounds);
+
+    let (x, x_side) = clamp_extent(
+        inner.pos.x,
+        inner.size.x,
+        bounds.pos.x,
+        bounds.size.x,
+        (ContactSide::Left, ContactSide::Right),
+    );
+    let (y, y_side) = clamp_extent(
+        inner.pos.y,
+        inner.size.y,
+        bounds.pos.y,
+        bounds.size.y,
+        (ContactSide::Bottom, ContactSide::Top),
+    );
+
+    let side = match (x_side, y_side) {
+        (Some(_), Some(_)) => Some(ContactSide::Corner),
+        (side, None) | (None, side) => side,
+    };
+
+    (
+        Rect {
+            pos: Vector::new(x, y),
+            size: inner.size,
+        },
+        side,
+    )
+}
+
+/// Clamps the start of a range of `length` into `[bounds_start, bounds_start + bounds_length]`
+/// and returns the side it was pressed against, given as `(low, high)`.
+fn clamp_extent(
+    start: Fp,
+    length: Fp,
+    bounds_start: Fp,
+    bounds_length: Fp,
+    (low, high): (ContactSide, ContactSide),
+) -> (Fp, Option<ContactSide>) {
+    if start < bounds_start || length > bounds_length {
+        (bounds_start, (start != bounds_start).then_some(low))
+    } else if start + length > bounds_start + bounds_length {
+        (bounds_start + bounds_length - length, Some(high))
+    } else {
+        (start, None)
+    }
+}
+
+/// Moves both ends of a range outwards by `amount`, collapsing it onto its center when it
+/// would get a negative length.
+fn inflate_extent(start: Fp, length: Fp, amount: Fp) -> (Fp, Fp) {
+    let inflated = length + amount * 2;
+    if inflated < Fp::zero() {
+        (start + Fp::from_raw(length.inner() / 2), Fp::zero())
+    } else {
+        (start - amount, inflated)
+    }
+}
+
+fn normalize_extent(start: Fp, length: Fp) -> (Fp, Fp) {
+    if length < Fp::zero() {
+        (start + length, -length)
+    } else {
+        (start, length)
+    }
+}
@@ -0,0 +1,544 @@
+/*
+ * Copyright (c) Peter Bjorklund. All rights reserved. https://github.com/piot/impact-rs
+ * Licensed under the MIT License. See LICENSE in the project root for license information.
+ */
+
+//! Collision response helpers that turn a contact into a new motion.
+
+use std::cmp::max;
+
+use fixed32::Fp;
+use fixed32_math::{Rect, Vector};
+
+use crate::contact::ContactSet;
+use crate::math::{dot, length, saturating_div, Rounding};
+use crate::rect_util::normalize_rect;
+use crate::{
+    swept_rect_vs_rect, swept_rect_vs_rect_horizontal_time, swept_rect_vs_rect_inclusive,
+    swept_rect_vs_rect_vertical_time, ContactSide, RayIntersectionResult,
+};
+
+/// The most contacts that [`move_and_slide`] resolves in one move.
+const MAX_SLIDES: usize = 4;
+
+/// Tells if a slide used up its motion or was cut short by its iteration limit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ResolveStatus {
+    /// All of the motion was applied, or a corner hit stopped it.
+    Completed,
+    /// The iteration limit was reached with motion left over, which usually means the
+    /// geometry traps the mover.
+    IterationLimit,
+}
+
+impl ResolveStatus {
+    /// Returns `true` if the motion was fully resolved.
+    #[must_use]
+    pub fn is_completed(self) -> bool {
+        self == Self::Completed
+    }
+}
+
+/// Computes the velocity after a collision, accounting for bounce and friction.
+///
+/// The velocity is split into a component along `normal` and a tangential component. The
+/// normal component is scaled by `-restitution` and the tangential component by
+/// `1 - friction`:
+///
+/// - `restitution`: `0` absorbs the impact completely, `1` is a perfectly elastic bounce.
+/// - `friction`: `0` keeps all tangential motion, `1` stops it completely.
+///
+/// Both coefficients are clamped to `[0, 1]`. The `normal` must have unit length; its sign
+/// does not matter, so both the contact normals of this crate and outward surface normals work.
+///
+/// # Example
+///
+/// ```rust
+/// use fixed32::Fp;
+/// use fixed32_math::Vector;
+/// use impact_rs::prelude::full::*;
+///
+/// let bounced = resolve_velocity(Vector::from((3, -4)), Vector::up(), Fp::one(), Fp::zero());
+/// assert_eq!(bounced, Vector::from((3, 4)));
+/// ```
+#[must_use]
+pub fn resolve_velocity(velocity: Vector, normal: Vector, restitution: Fp, friction: Fp) -> Vector {
+    let restitution = restitution.clamp(Fp::zero(), Fp::one());
+    let friction = friction.clamp(Fp::zero(), Fp::one());
+
+    let normal_velocity = dot(velocity, normal) * normal;
+    let tangent_velocity = velocity - normal_velocity;
+
+    tangent_velocity * (Fp::one() - friction) - normal_velocity * restitution
+}
+
+/// Computes the impulse that bounces a body off a contact.
+///
+/// The impulse is `-(1 + restitution) * mass * dot(velocity, normal) * normal`. It only acts
+/// along `normal`: dividing it by `mass` and adding it to `velocity` gives the velocity of
+/// [`resolve_velocity`] without friction.
+///
+/// The impulse is in units of mass times the units of `velocity`, so with a velocity in world
+/// units per second and a mass in kilograms it is in kilogram world units per second.
+///
+/// # Parameters
+///
+/// - `velocity`: The velocity of the body at the contact.
+/// - `normal`: The contact normal. It must have unit length; its sign does not matter.
+/// - `restitution`: `0` absorbs the impact completely, `1` is a perfectly elastic bounce.
+///   Clamped to `[0, 1]`.
+/// - `mass`: The mass of the body. Must be positive.
+///
+/// # Example
+///
+/// ```rust
+/// use fixed32::Fp;
+/// use fixed32_math::Vector;
+/// use impact_rs::prelude::full::*;
+///
+/// let impulse = contact_impulse(Vector::from((3, -4)), Vector::up(), Fp::one(), Fp::from(2));
+/// assert_eq!(impulse, Vector::from((0, 16)));
+/// ```
+#[must_use]
+pub fn contact_impulse(velocity: Vector, normal: Vector, restitution: Fp, mass: Fp) -> Vector {
+    let restitution = restitution.clamp(Fp::zero(), Fp::one());
+
+    let normal_speed = dot(velocity, normal);
+    normal * -(normal_speed * (Fp::one() + restitution) * mass)
+}
+
+/// Computes where a swept rectangle should stop so that it rests just shy of the contact.
+///
+/// The rectangle is advanced along `delta` to the `closest_time` of `result`, and then
+/// moved back along the motion by `skin` world units. The skin keeps the rectangle from
+/// ending up exactly on the surface, where rounding could make the next query report an
+/// overlap. The rectangle is never moved back past `origin`.
+///
+/// # Parameters
+///
+/// - `origin`: The rectangle at the start of the motion.
+/// - `delta`: The motion that was swept, for example with [`swept_rect_vs_rect`](crate::swept_rect_vs_rect).
+/// - `result`: The contact reported by the sweep.
+/// - `skin`: The distance to keep from the contact. Negative values are treated as zero.
+///
+/// # Returns
+///
+/// The rectangle at its safe stopping position. It has the same size as `origin`.
+///
+/// # Example
+///
+/// ```rust
+/// use fixed32::Fp;
+/// use fixed32_math::{Rect, Vector};
+/// use impact_rs::prelude::full::*;
+///
+/// let origin = Rect::from((0, 0, 2, 2));
+/// let target = Rect::from((10, 0, 2, 2));
+/// let delta = Vector::from((16, 0));
+/// let result = swept_rect_vs_rect(origin, target, delta).unwrap();
+///
+/// let stopped = safe_position(origin, delta, &result, Fp::from(0.5));
+/// assert!(!rect_vs_rect(stopped, target));
+/// assert_eq!(stopped.pos.x, result.closest_time * Fp::from(16) - Fp::from(0.5));
+/// ```
+#[must_use]
+pub fn safe_position(
+    origin: Rect,
+    delta: Vector,
+    result: &RayIntersectionResult,
+    skin: Fp,
+) -> Rect {
+    let origin = normalize_rect(origin);
+    let distance = length(delta, Rounding::Floor);
+
+    if distance.is_zero() {
+        return origin;
+    }
+
+    let skin_time = saturating_div(max(skin, Fp::zero()), distance);
+    let time = max(result.closest_time - skin_time, Fp::zero());
+
+    origin.move_by(delta * time)
+}
+
+/// Moves a rectangle through static targets, resolving the horizontal and vertical motion
+/// separately.
+///
+/// The horizontal part of `delta` is applied first: every target is tested with
+/// [`swept_rect_vs_rect_horizontal_time`] and the rectangle is placed flush against the
+/// earliest contacted target. The vertical part is then applied from that new position in the
+/// same way with [`swept_rect_vs_rect_vertical_time`]. Targets that only touch the edge of the
+/// rectangle beside the motion do not block it, so falling along a wall of stacked tiles does
+/// not stop at the first tile.
+///
+/// Resolving one axis at a time is the standard platformer technique. A blocked axis only
+/// stops its own motion, so a character running along the floor keeps sliding even though
+/// gravity pushes it into the floor, and it does not snag on the seams between floor tiles.
+/// The order matters when both axes are blocked by different targets: because horizontal
+/// motion is resolved first, a character moving diagonally past the corner of a ledge is
+/// stopped by the side of the ledge rather than landing on its top.
+///
+/// # Parameters
+///
+/// - `origin`: The rectangle to move.
+/// - `targets`: The static rectangles that block the motion.
+/// - `delta`: The desired movement of `origin`.
+///
+/// # Returns
+///
+/// Returns `(rect, blocked_x, blocked_y)`, where `rect` is the rectangle after the motion and
+/// `blocked_x` and `blocked_y` tell if the horizontal or vertical motion was stopped by a
+/// target.
+///
+/// # Example
+///
+/// ```rust
+/// use fixed32_math::{Rect, Vector};
+/// use impact_rs::prelude::full::*;
+///
+/// let floor = [Rect::from((0, 0, 4, 4)), Rect::from((4, 0, 4, 4))];
+///
+/// let (moved, blocked_x, blocked_y) =
+///     move_axis_separated(Rect::from((1, 4, 2, 2)), &floor, Vector::from((4, -1)));
+/// assert_eq!(moved, Rect::from((5, 4, 2, 2)));
+/// assert!(!blocked_x);
+/// assert!(blocked_y);
+/// ```
+#[must_use]
+pub fn move_axis_separated(origin: Rect, targets: &[Rect], delta: Vector) -> (Rect, bool, bool) {
+    let mut moved = normalize_rect(origin);
+
+    let x_face = if delta.x > Fp::zero() {
+        ContactSide::Right
+    } else {
+        ContactSide::Left
+    };
+    let x_contact = targets
+        .iter()
+        .filter_map(|target| {
+            swept_rect_vs_rect_horizontal_time(moved, *target, delta.x)
+                .map(|time| (time, normalize_rect(*target)))
+        })
+        .filter(|(_, target)| !grazes(moved, *target, x_face))
+        .min_by_key(|(time, _)| *time);
+    moved.pos.x = match x_contact {
+        Some((_, target)) if delta.x > Fp::zero() => target.left() - moved.size.x,
+        Some((_, target)) => target.right(),
+        None => moved.pos.x + delta.x,
+    };
+
+    let y_face = if delta.y > Fp::zero() {
+        ContactSide::Top
+    } else {
+        ContactSide::Bottom
+    };
+    let y_contact = targets
+        .iter()
+        .filter_map(|target| {
+            swept_rect_vs_rect_vertical_time(moved, *target, delta.y)
+                .map(|time| (time, normalize_rect(*target)))
+        })
+        .filter(|(_, target)| !grazes(moved, *target, y_face))
+        .min_by_key(|(time, _)| *time);
+    moved.pos.y = match y_contact {
+        Some((_, target)) if delta.y > Fp::zero() => target.bottom() - moved.size.y,
+        Some((_, target)) => target.top(),
+        None => moved.pos.y + delta.y,
+    };
+
+    (moved, x_contact.is_some(), y_contact.is_some())
+}
+
+/// Moves a rectangle through static targets, sliding along the surfaces it hits.
+///
+/// The whole `delta` is swept with [`swept_rect_vs_rect`]. At the earliest contact the
+/// rectangle is placed flush against the target, the part of the leftover motion that points
+/// into the contacted face is removed, and the rest is swept again. A corner hit stops the
+/// motion. Contacts that only graze the edge of a target do not block, so sliding along a
+/// row of tiles does not snag on the seams between them. A row of adjacent tiles behaves as
+/// one surface: when a face and a corner are hit at the same time, the corner is the seam
+/// between two tiles and the face is resolved. At most four contacts are resolved, any
+/// motion left after that is dropped.
+///
+/// Use [`move_and_slide_events`] to also get the contacts, and [`move_and_slide_limited`] to
+/// choose the limit and find out if it was reached.
+///
+/// # Example
+///
+/// ```rust
+/// use fixed32_math::{Rect, Vector};
+/// use impact_rs::prelude::full::*;
+///
+/// let floor = [Rect::from((0, 0, 20, 4))];
+///
+/// let moved = move_and_slide(Rect::from((0, 6, 2, 2)), &floor, Vector::from((8, -4)));
+/// assert_eq!(moved, Rect::from((8, 4, 2, 2)));
+/// ```
+#[must_use]
+pub fn move_and_slide(origin: Rect, targets: &[Rect], delta: Vector) -> Rect {
+    slide(origin, targets, delta, MAX_SLIDES, |_, _| {}).0
+}
+
+/// Performs [`move_and_slide`] with at most `max_iterations` contacts and reports if the
+/// motion was fully resolved.
+///
+/// # Returns
+///
+/// Returns `(rect, status)`, where `rect` is the rectangle after the motion and `status` is
+/// [`ResolveStatus::IterationLimit`] if motion was still left after `max_iterations`
+/// contacts. The leftover motion is dropped.
+///
+/// # Example
+///
+/// ```rust
+/// use fixed32_math::{Rect, Vector};
+/// use impact_rs::prelude::full::*;
+///
+/// let targets = [Rect::from((0, 0, 20, 4)), Rect::from((12, 0, 2, 10))];
+/// let origin = Rect::from((0, 6, 2, 2));
+///
+/// let (moved, status) = move_and_slide_limited(origin, &targets, Vector::from((16, -4)), 1);
+/// assert_eq!(moved, Rect::from((8, 4, 2, 2)));
+/// assert_eq!(status, ResolveStatus::IterationLimit);
+///
+/// let (moved, status) = move_and_slide_limited(origin, &targets, Vector::from((16, -4)), 4);
+/// assert_eq!(moved, Rect::from((10, 4, 2, 2)));
+/// assert!(status.is_completed());
+/// ```
+#[must_use]
+pub fn move_and_slide_limited(
+    origin: Rect,
+    targets: &[Rect],
+    delta: Vector,
+    max_iterations: usize,
+) -> (Rect, ResolveStatus) {
+    slide(origin, targets, delta, max_iterations, |_, _| {})
+}
+
+/// Performs [`move_and_slide`] and reports every contact in the order it happened.
+///
+/// # Returns
+///
+/// A [`ContactSet`] with one `(time, index, side)` entry per contact, where `time` is the
+/// fraction of the whole frame that had passed at the contact, `index` is the index into
+/// `targets` and `side` is the [`ContactSide`] of the mover that made contact. The times are
+/// increasing, which lets gameplay code tell which contact happened first and when.
+///
+/// # Example
+///
+/// ```rust
+/// use fixed32::Fp;
+/// use fixed32_math::{Rect, Vector};
+/// use impact_rs::prelude::full::*;
+///
+/// let floor = [Rect::from((0, 0, 20, 4))];
+///
+/// let events = move_and_slide_events(Rect::from((0, 6, 2, 2)), &floor, Vector::from((8, -4)));
+/// assert_eq!(events.as_slice(), [(Fp::from(0.5), 0, ContactSide::Bottom)]);
+/// assert!(events.grounded());
+/// ```
+#[must_use]
+pub fn move_and_slide_events(origin: Rect, targets: &[Rect], delta: Vector) -> ContactSet {
+    let mut events = ContactSet::new();
+    slide(origin, targets, delta, MAX_SLIDES, |_, contact| {
+        if let Some((index, frame_time, result)) = contact {
+            events.push(frame_time, index, result.face);
+        }
+    });
+    events
+}
+
+/// Performs [`move_and_slide`] and checks if the rectangle is standing on a target afterwards.
+///
+/// After the move, the rectangle is swept down by `ground_probe` with
+/// [`swept_rect_vs_rect_inclusive`]. It is grounded if the probe hits the top of a target,
+/// which includes resting exactly on it and hovering at most `ground_probe` above it. A probe
+/// that only grazes the side of a target, for example against a wall, does not count.
+///
+/// # Parameters
+///
+/// - `origin`: The rectangle to move.
+/// - `targets`: The static rectangles that block the motion.
+/// - `delta`: The desired movement of `origin`.
+/// - `ground_probe`: How far below the rectangle to look for ground. Must be positive, the
+///   rectangle is never grounded otherwise.
+///
+/// # Returns
+///
+/// Returns `(rect, grounded)`, where `rect` is the rectangle after the motion.
+///
+/// # Example
+///
+/// ```rust
+/// use fixed32::Fp;
+/// use fixed32_math::{Rect, Vector};
+/// use impact_rs::prelude::full::*;
+///
+/// let floor = [Rect::from((0, 0, 20, 4))];
+///
+/// let (moved, grounded) =
+///     move_and_ground(Rect::from((0, 6, 2, 2)), &floor, Vector::from((8, -4)), Fp::from(0.5));
+/// assert_eq!(moved, Rect::from((8, 4, 2, 2)));
+/// assert!(grounded);
+/// ```
+#[must_use]
+pub fn move_and_ground(
+    origin: Rect,
+    targets: &[Rect],
+    delta: Vector,
+    ground_probe: Fp,
+) -> (Rect, bool) {
+    let moved = move_and_slide(origin, targets, delta);
+
+    if ground_probe <= Fp::zero() {
+        return (moved, false);
+    }
+
+    let probe = Vector::new(Fp::zero(), -ground_probe);
+    let grounded = targets.iter().any(|target| {
+        swept_rect_vs_rect_inclusive(moved, *target, probe).is_some_and(|result| {
+            let at_contact = moved.move_by(probe * result.closest_time);
+            result.face == ContactSide::Bottom
+                && !grazes(at_contact, normalize_rect(*target), result.face)
+        })
+    });
+
+    (moved, grounded)
+}
+
+/// Performs [`move_and_slide`] and returns the position of the rectangle after each step.
+///
+/// Every contact redirects the motion, and each redirect is one step. At most
+/// `max_iterations` steps are taken; [`move_and_slide`] uses four.
+///
+/// # Returns
+///
+/// One `(rect, contact)` entry per step, where `rect` is the rectangle after the step and
+/// `contact` is the contact that ended it. The `closest_time` of a contact is relative to the
+/// motion that was left at the start of its step, and its `frame_fraction` is relative to the
+/// whole `delta`. The last entry has a `contact` of `None` if the move ended with a step that
+/// hit nothing. Replaying the entries in order reproduces the path of the rectangle.
+///
+/// # Example
+///
+/// ```rust
+/// use fixed32_math::{Rect, Vector};
+/// use impact_rs::prelude::full::*;
+///
+/// let floor = [Rect::from((0, 0, 20, 4))];
+///
+/// let trace = move_and_slide_trace(Rect::from((0, 6, 2, 2)), &floor, Vector::from((8, -4)), 4);
+/// assert_eq!(trace.len(), 2);
+/// assert_eq!(trace[0].0, Rect::from((4, 4, 2, 2)));
+/// assert_eq!(trace[1].0, Rect::from((8, 4, 2, 2)));
+/// assert!(trace[1].1.is_none());
+/// ```
+#[must_use]
+pub fn move_and_slide_trace(
+    origin: Rect,
+    targets: &[Rect],
+    delta: Vector,
+    max_iterations: usize,
+) -> Vec<(Rect, Option<RayIntersectionResult>)> {
+    let mut trace = Vec::new();
+    slide(origin, targets, delta, max_iterations, |moved, contact| {
+        let contact = contact.map(|(_, frame_time, result)| RayIntersectionResult {
+            frame_fraction: Some(frame_time),
+            ..result.clone()
+        });
+        trace.push((moved, contact));
+    });
+    trace
+}
+
+/// Slides `origin` through `targets`, calling `on_step` with the rectangle after each step
+/// and the `(index, frame_time, result)` of the contact that ended it, if any.
+fn slide<F>(
+    origin: Rect,
+    targets: &[Rect],
+    delta: Vector,
+    max_iterations: usize,
+    mut on_step: F,
+) -> (Rect, ResolveStatus)
+where
+    F: FnMut(Rect, Option<(usize, Fp, &RayIntersectionResult)>),
+{
+    let mut moved = normalize_rect(origin);
+    let mut remaining = delta;
+    let mut frame_time = Fp::zero();
+
+    for _ in 0..max_iterations {
+        if remaining.x.is_zero() && remaining.y.is_zero() {
+            break;
+        }
+
+        let contact = targets
+            .iter()
+            .enumerate()
+            .filter_map(|(index, target)| {
+                swept_rect_vs_rect(moved, *target, remaining).map(|result| (index, result))
+            })
+            .filter(|(index, result)| {
+                let at_contact = moved.move_by(remaining * result.closest_time);
+                !grazes(at_contact, normalize_rect(targets[*index]), result.face)
+            })
+            // A corner at the same time as a face is the seam between two coplanar targets
+            .min_by_key(|(_, result)| (result.closest_time, result.face == ContactSide::Corner));
+
+        let Some((index, result)) = contact else {
+            moved = moved.move_by(remaining);
+            on_step(moved, None);
+            return (moved, ResolveStatus::Completed);
+        };
+
+        // The rest of the frame is consumed at the same rate as `remaining`
+        frame_time = frame_time + (Fp::one() - frame_time) * result.closest_time;
+
+        let target = normalize_rect(targets[index]);
+        moved = moved.move_by(remaining * result.closest_time);
+        remaining = result.remaining(remaining);
+
+        match result.face {
+            ContactSide::Right => {
+                moved.pos.x = target.left() - moved.size.x;
+                remaining.x = Fp::zero();
+            }
+            ContactSide::Left => {
+                moved.pos.x = target.right();
+                remaining.x = Fp::zero();
+            }
+            ContactSide::Top => {
+                moved.pos.y = target.bottom() - moved.size.y;
+                remaining.y = Fp::zero();
+            }
+            ContactSide::Bottom => {
+                moved.pos.y = target.top();
+                remaining.y = Fp::zero();
+            }
+            ContactSide::Corner => remaining = Vector::default(),
+        }
+
+        on_step(moved, Some((index, frame_time, &result)));
+    }
+
+    let status = if remaining.x.is_zero() && remaining.y.is_zero() {
+        ResolveStatus::Completed
+    } else {
+        ResolveStatus::IterationLimit
+    };
+
+    (moved, status)
+}
+
+/// Checks if a contact on `face` only touches the edge of `target` instead of hitting it.
+fn grazes(mover: Rect, target: Rect, face: ContactSide) -> bool {
+    match face {
+        ContactSide::Left | ContactSide::Right => {
+            mover.bottom() >= target.top() || mover.top() <= target.bottom()
+        }
+        ContactSide::Top | ContactSide::Bottom => {
+            mover.left() >= target.right() || mover.right() <= target.left()
+        }
+        ContactSide::Corner => false,
+    }
+}
@@ -0,0 +1,145 @@
+/*
+ * Copyright (c) Peter Bjorklund. All rights reserved. https://github.com/piot/impact-rs
+ * Licensed under the MIT License. See LICENSE in the project root for license information.
+ */
+
+//! A line segment type for finite-length queries.
+
+use fixed32::Fp;
+use fixed32_math::{Rect, Vector};
+
+use crate::math::{length, raw_cross, raw_offset, raw_quotient, Rounding};
+use crate::{ray_vs_rect, RayIntersectionResult};
+
+/// A line segment from `start` to `end`.
+///
+/// Unlike a [`Ray`](crate::ray::Ray), a segment has no extent past its end points, so its
+/// queries only report contacts at times in `[0, 1]`, where `0` is `start` and `1` is `end`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Segment {
+    pub start: Vector,
+    pub end: Vector,
+}
+
+impl Segment {
+    #[must_use]
+    pub const fn new(start: Vector, end: Vector) -> Self {
+        Self { start, end }
+    }
+
+    /// Returns the distance between `start` and `end`, rounded down.
+    #[must_use]
+    pub fn length(&self) -> Fp {
+        length(self.end - self.start, Rounding::Floor)
+    }
+
+    /// Tests the segment against a rectangle.
+    ///
+    /// The segment is tested with [`ray_vs_rect`], and contacts past `end` are dropped. If
+    /// `start` is inside the rectangle, the contact is at `start` with a `closest_time` of
+    /// zero.
+    ///
+    /// The `contact_point` never lies past `end`. The time of a contact is rounded towards
+    /// zero, so a rectangle just past `end`, closer than one raw unit of time, is reported with
+    /// a `closest_time` of one. Such a contact is clamped to the segment: its `contact_point`
+    /// is exactly `end`. A contact with a `closest_time` above one is dropped.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Some(RayIntersectionResult)` if the segment enters the rectangle between
+    /// `start` and `end`, otherwise `None`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fixed32::Fp;
+    /// use fixed32_math::{Rect, Vector};
+    /// use impact_rs::segment::Segment;
+    ///
+    /// let target = Rect::from((6, -1, 2, 2));
+    ///
+    /// let crossing = Segment::new(Vector::from((0, 0)), Vector::from((8, 0)));
+    /// assert_eq!(crossing.vs_rect(target).unwrap().closest_time, Fp::from(0.75));
+    ///
+    /// let short = Segment::new(Vector::from((0, 0)), Vector::from((4, 0)));
+    /// assert!(short.vs_rect(target).is_none());
+    /// ```
+    #[must_use]
+    pub fn vs_rect(&self, target: Rect) -> Option<RayIntersectionResult> {
+        let result = ray_vs_rect(self.start, self.end - self.start, target)?;
+
+        if result.closest_time > Fp::one() {
+            None
+        } else if result.closest_time < Fp::zero() {
+            Some(RayIntersectionResult {
+                contact_point: self.start,
+                closest_time: Fp::zero(),
+                ..result
+            })
+        } else if result.closest_time == Fp::one() {
+            Some(RayIntersectionResult {
+                contact_point: self.end,
+                ..result
+            })
+        } else {
+            Some(result)
+        }
+    }
+
+    /// Finds the point where two segments cross.
+    ///
+    /// Touching end points count as crossing. Parallel segments, including overlapping
+    /// collinear ones, do not have a single crossing point and are reported as not crossing.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Some((time, point))`, where `time` is in `[0, 1]` along `self` and `point`
+    /// is the crossing point, or `None` if the segments do not cross.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fixed32::Fp;
+    /// use fixed32_math::Vector;
+    /// use impact_rs::segment::Segment;
+    ///
+    /// let a = Segment::new(Vector::from((0, 0)), Vector::from((4, 4)));
+    /// let b = Segment::new(Vector::from((0, 4)), Vector::from((4, 0)));
+    ///
+    /// assert_eq!(a.vs_segment(b), Some((Fp::from(0.5), Vector::from((2, 2)))));
+    /// ```
+    #[must_use]
+    pub fn vs_segment(&self, other: Segment) -> Option<(Fp, Vector)> {
+        let direction = raw_offset(self.start, self.end);
+        let other_direction = raw_offset(other.start, other.end);
+
+        let denominator = raw_cross(direction, other_direction);
+        if denominator == 0 {
+            return None;
+        }
+
+        // Solves `start + time * direction == other.start + other_time * other_direction` on the
+        // raw values, as the cross products overflow `Fp` for long segments
+        let offset = raw_offset(self.start, other.start);
+        let time = raw_quotient(
+            raw_cross(offset, other_direction),
+            denominator,
+            Rounding::Floor,
+        );
+        let other_time = raw_quotient(raw_cross(offset, direction), denominator, Rounding::Floor);
+
+        let in_range = |t: i128| (0..=i128::from(Fp::SCALE_I64)).contains(&t);
+        (in_range(time) && in_range(other_time)).then(|| {
+            let time = Fp::from_raw(time as i32);
+            (time, self.start + (self.end - self.start) * time)
+        })
+    }
+}
+
+/// Creates a segment from a `(start, end)` pair of anything that converts into a [`Vector`],
+/// so both `Segment::from((start, end))` and `Segment::from(((0, 0), (8, 0)))` work.
+impl<S: Into<Vector>, E: Into<Vector>> From<(S, E)> for Segment {
+    fn from((start, end): (S, E)) -> Self {
+        Self::new(start.into(), end.into())
+    }
+}
@@ -0,0 +1,373 @@
+/*
+ * Copyright (c) Peter Bjorklund. All rights reserved. https://github.com/piot/impact-rs
+ * Licensed under the MIT License. See LICENSE in the project root for license information.
+ */
+
+//! Conversions between world-space positions and integer tile coordinates.
+//!
+//! Tile `(0, 0)` covers `[0, tile_size)` on both axes, tile `(-1, -1)` covers
+//! `[-tile_size, 0)`, and so on. All conversions use floor division, so negative
+//! positions map to negative tiles instead of being truncated towards tile zero.
+
+use std::collections::{BTreeSet, HashSet};
+
+use fixed32::Fp;
+use fixed32_math::{Rect, Vector};
+
+use crate::rect_util::swept_aabb;
+use crate::{swept_rect_vs_rect, RayIntersectionResult};
+
+/// Returns the coordinates of the tile containing `pos`.
+///
+/// The division is done on the raw fixed-point values, so positions exactly on a tile
+/// boundary always belong to the tile above or to the right of it.
+///
+/// # Panics
+///
+/// Panics if `tile_size` is not positive.
+///
+/// # Example
+///
+/// ```rust
+/// use fixed32::Fp;
+/// use fixed32_math::Vector;
+/// use impact_rs::tiles::world_to_tile;
+///
+/// assert_eq!(world_to_tile(Vector::from((-0.5, 16.0)), Fp::from(16)), (-1, 1));
+/// ```
+#[must_use]
+pub fn world_to_tile(pos: Vector, tile_size: Fp) -> (i32, i32) {
+    assert!(tile_size > Fp::zero(), "tile_size must be positive");
+
+    (
+        pos.x.inner().div_euclid(tile_size.inner()),
+        pos.y.inner().div_euclid(tile_size.inner()),
+    )
+}
+
+/// Returns the world-space rectangle covered by the tile at `(tx, ty)`.
+///
+/// # Example
+///
+/// ```rust
+/// use fixed32::Fp;
+/// use fixed32_math::Rect;
+/// use impact_rs::tiles::tile_to_rect;
+///
+/// assert_eq!(tile_to_rect(-1, 2, Fp::from(16)), Rect::from((-16, 32, 16, 16)));
+/// ```
+#[must_use]
+pub fn tile_to_rect(tx: i32, ty: i32, tile_size: Fp) -> Rect {
+    Rect {
+        pos: Vector::new(
+            Fp::from_raw(tx * tile_size.inner()),
+            Fp::from_raw(ty * tile_size.inner()),
+        ),
+        size: Vector::new(tile_size, tile_size),
+    }
+}
+
+/// Sweeps a rectangle against a tilemap, testing only the tiles its path overlaps.
+///
+/// The tiles covered by the bounding box of the whole motion are enumerated, and every
+/// solid one is tested with [`swept_rect_vs_rect`]. The tilemap is never materialized as a
+/// list of rectangles, so this works with arbitrarily large (or procedural) maps.
+///
+/// # Parameters
+///
+/// - `origin`: The rectangle that is swept along `delta`.
+/// - `delta`: The movement of `origin` during the frame.
+/// - `tile_size`: The width and height of a tile. Must be positive.
+/// - `is_solid`: Returns `true` if the tile at the given tile coordinates is solid.
+///
+/// # Returns
+///
+/// Returns the earliest contact among the solid tiles, or `None` if the path is free. If
+/// several tiles are contacted at the same time, the one with the lowest `y` and then the
+/// lowest `x` tile coordinate is reported.
+///
+/// # Example
+///
+/// ```rust
+/// use fixed32::Fp;
+/// use fixed32_math::{Rect, Vector};
+/// use impact_rs::tiles::swept_rect_vs_tilemap;
+///
+/// let origin = Rect::from((0.0, 0.5, 1.0, 1.0));
+///
+/// let result = swept_rect_vs_tilemap(origin, Vector::from((8, 0)), Fp::one(), |tx, _| tx >= 6);
+/// assert!(result.is_some());
+/// ```
+#[must_use]
+pub fn swept_rect_vs_tilemap<F: Fn(i32, i32) -> bool>(
+    origin: Rect,
+    delta: Vector,
+    tile_size: Fp,
+    is_solid: F,
+) -> Option<RayIntersectionResult> {
+    let ((min_x, min_y), (max_x, max_y)) = tile_range(swept_aabb(origin, delta), tile_size);
+
+    let mut closest: Option<RayIntersectionResult> = None;
+
+    for ty in min_y..=max_y {
+        for tx in min_x..=max_x {
+            if !is_solid(tx, ty) {
+                continue;
+            }
+
+            let tile = tile_to_rect(tx, ty, tile_size);
+            if let Some(result) = swept_rect_vs_rect(origin, tile, delta) {
+                let is_closer = closest
+                    .as_ref()
+                    .is_none_or(|best| result.closest_time < best.closest_time);
+                if is_closer {
+                    closest = Some(result);
+                }
+            }
+        }
+    }
+
+    closest
+}
+
+/// Returns the tiles covered by the bounding box of a rectangle's whole motion.
+///
+/// This is the same set of tiles that [`swept_rect_vs_tilemap`] tests, without any collision
+/// testing, for example to decide which parts of a map to load before a move. A tile that
+/// only touches the edge of the bounding box is included.
+///
+/// # Returns
+///
+/// The coordinates of every covered tile, each once, ordered by `y` and then by `x`.
+///
+/// # Panics
+///
+/// Panics if `tile_size` is not positive.
+///
+/// # Example
+///
+/// ```rust
+/// use fixed32::Fp;
+/// use fixed32_math::{Rect, Vector};
+/// use impact_rs::tiles::swept_rect_tiles;
+///
+/// let tiles = swept_rect_tiles(Rect::from((0.25, 0.25, 0.5, 0.5)), Vector::from((1, 0)), Fp::one());
+/// assert_eq!(tiles, [(0, 0), (1, 0)]);
+/// ```
+#[must_use]
+pub fn swept_rect_tiles(origin: Rect, delta: Vector, tile_size: Fp) -> Vec<(i32, i32)> {
+    let ((min_x, min_y), (max_x, max_y)) = tile_range(swept_aabb(origin, delta), tile_size);
+
+    (min_y..=max_y)
+        .flat_map(|ty| (min_x..=max_x).map(move |tx| (tx, ty)))
+        .collect()
+}
+
+/// Returns the lowest and highest tile coordinates covered by `bounds`, edges included.
+fn tile_range(bounds: Rect, tile_size: Fp) -> ((i32, i32), (i32, i32)) {
+    (
+        world_to_tile(bounds.pos, tile_size),
+        world_to_tile(bounds.pos + bounds.size, tile_size),
+    )
+}
+
+/// Merges solid tiles into larger rectangles that cover exactly the same area.
+///
+/// The tiles are visited from the bottom row up, left to right. Each tile that is not yet
+/// covered starts a new rectangle, which is first grown to the right along its row for as
+/// long as the tiles are solid and uncovered, and then upwards for as long as the whole
+/// width of the next row is. This greedy merge is not guaranteed to find the fewest
+/// rectangles, but it is fast and turns a solid block of tiles into a single rectangle.
+///
+/// Every solid tile is covered by exactly one rectangle and the rectangles do not overlap.
+/// Duplicate tiles in `solid` are merged like a single tile. The result is the same for any
+/// order of `solid`.
+///
+/// # Example
+///
+/// ```rust
+/// use fixed32::Fp;
+/// use fixed32_math::Rect;
+/// use impact_rs::tiles::merge_tiles;
+///
+/// let solid = [(0, 0), (1, 0), (0, 1), (1, 1), (2, 0)];
+///
+/// assert_eq!(
+///     merge_tiles(&solid, Fp::from(16)),
+///     [Rect::from((0, 0, 48, 16)), Rect::from((0, 16, 32, 16))]
+/// );
+/// ```
+#[must_use]
+pub fn merge_tiles(solid: &[(i32, i32)], tile_size: Fp) -> Vec<Rect> {
+    let rows: BTreeSet<(i32, i32)> = solid.iter().map(|&(tx, ty)| (ty, tx)).collect();
+    let mut covered = HashSet::with_capacity(rows.len());
+    let mut merged = Vec::new();
+
+    let is_free = |covered: &HashSet<(i32, i32)>, tile: (i32, i32)| {
+        rows.contains(&tile) && !covered.contains(&tile)
+    };
+
+    for &(ty, tx) in &rows {
+        if covered.contains(&(ty, tx)) {
+            continue;
+        }
+
+        let mut width = 1;
+        while is_free(&covered, (ty, tx + width)) {
+            width += 1;
+        }
+
+        let mut height = 1;
+        while (tx..tx + width).all(|x| is_free(&covered, (ty + height, x))) {
+            height += 1;
+        }
+
+        for y in ty..ty + height {
+            covered.extend((tx..tx + width).map(|x| (y, x)));
+        }
+
+        let mut rect = tile_to_rect(tx, ty, tile_size);
+        rect.size = Vector::new(
+            Fp::from_raw(width * tile_size.inner()),
+            Fp::from_raw(height * tile_size.inner()),
+        );
+        merged.push(rect);
+    }
+
+    merged
+}
+
+/// Iterates over the tiles a ray passes through, in the order it enters them.
+///
+/// The tiles are visited with a digital differential analyzer (DDA): each call to
+/// [`Iterator::next`] steps into whichever neighbouring tile the ray reaches first, so the
+/// iterator is lazy and never allocates. The ray is unbounded, so stop consuming it with
+/// [`Iterator::take`], [`Iterator::take_while`] or a `break` once the tile of interest is
+/// found.
+///
+/// The first tile is the one containing the ray origin, as returned by [`world_to_tile`].
+/// When the ray passes exactly through a tile corner, both coordinates change in the same
+/// step and the tiles that are only touched at that corner are skipped, matching
+/// [`ray_vs_rect`](crate::ray_vs_rect), which does not report grazing contacts. A zero
+/// direction yields only the origin tile.
+///
+/// # Example
+///
+/// ```rust
+/// use fixed32::Fp;
+/// use fixed32_math::Vector;
+/// use impact_rs::tiles::GridRayIter;
+///
+/// let tiles: Vec<_> =
+///     GridRayIter::new(Vector::from((0.5, 0.5)), Vector::from((1, 0)), Fp::one())
+///         .take(3)
+///         .collect();
+/// assert_eq!(tiles, [(0, 0), (1, 0), (2, 0)]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct GridRayIter {
+    origin: Vector,
+    direction: Vector,
+    tile_size: Fp,
+    tile: Option<(i32, i32)>,
+}
+
+impl GridRayIter {
+    /// Creates an iterator over the tiles of size `tile_size` crossed by the ray starting at
+    /// `origin` and going along `direction`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `tile_size` is not positive.
+    #[must_use]
+    pub fn new(origin: Vector, direction: Vector, tile_size: Fp) -> Self {
+        Self {
+            origin,
+            direction,
+            tile_size,
+            tile: Some(world_to_tile(origin, tile_size)),
+        }
+    }
+
+    /// Returns the raw parametric time at which the ray leaves `tile` along one axis, or
+    /// `None` if the ray does not move along that axis.
+    fn exit_time(&self, origin: Fp, direction: Fp, tile: i32) -> Option<i64> {
+        if direction.is_zero() {
+            return None;
+        }
+
+        let boundary_tile = i64::from(tile) + i64::from(direction > Fp::zero());
+        let boundary = boundary_tile * i64::from(self.tile_size.inner());
+        let distance = boundary - i64::from(origin.inner());
+
+        Some(distance * Fp::SCALE_I64 / i64::from(direction.inner()))
+    }
+}
+
+impl Iterator for GridRayIter {
+    type Item = (i32, i32);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = self.tile?;
+        let (tx, ty) = current;
+
+        let exit_x = self.exit_time(self.origin.x, self.direction.x, tx);
+        let exit_y = self.exit_time(self.origin.y, self.direction.y, ty);
+
+        let (step_x, step_y) = match (exit_x, exit_y) {
+            (None, None) => (false, false),
+            (Some(_), None) => (true, false),
+            (None, Some(_)) => (false, true),
+            (Some(time_x), Some(time_y)) => (time_x <= time_y, time_y <= time_x),
+        };
+
+        self.tile = if step_x || step_y {
+            let next_x = if step_x {
+                tx.checked_add(self.direction.x.inner().signum())
+            } else {
+                Some(tx)
+            };
+            let next_y = if step_y {
+                ty.checked_add(self.direction.y.inner().signum())
+            } else {
+                Some(ty)
+            };
+            next_x.zip(next_y)
+        } else {
+            None
+        };
+
+        Some(current)
+    }
+}
+
+/// Returns the first `max_tiles` tiles crossed by a ray, in the order it enters them.
+///
+/// This collects [`GridRayIter`]; use the iterator directly to stop at a condition instead
+/// of a fixed count.
+///
+/// # Panics
+///
+/// Panics if `tile_size` is not positive.
+///
+/// # Example
+///
+/// ```rust
+/// use fixed32::Fp;
+/// use fixed32_math::Vector;
+/// use impact_rs::tiles::raycast_grid;
+///
+/// let tiles = raycast_grid(Vector::from((0.5, 0.5)), Vector::from((-1, -1)), Fp::one(), 3);
+/// assert_eq!(tiles, [(0, 0), (-1, -1), (-2, -2)]);
+/// ```
+#[must_use]
+pub fn raycast_grid(
+    origin: Vector,
+    direction: Vector,
+    tile_size: Fp,
+    max_tiles: usize,
+) -> Vec<(i32, i32)> {
+    GridRayIter::new(origin, direction, tile_size)
+        .take(max_tiles)
+        .collect()
+}
@@ -0,0 +1,638 @@
+/*
+ * Copyright (c) Peter Bjorklund. All rights reserved. https://github.com/piot/impact-rs
+ * Licensed under the MIT License. See LICENSE in the project root for license information.
+ */
+
+//! A collection of rectangle colliders that can be queried as a whole.
+
+use std::cmp::max;
+use std::collections::BTreeMap;
+
+use fixed32::Fp;
+use fixed32_math::{Rect, Vector};
+
+use crate::circle::Circle;
+use crate::collidable::Collidable;
+use crate::math::{dot, length, saturating_div, Rounding};
+use crate::obb::Obb;
+use crate::overlap::{point_in_rect, rect_vs_rect};
+use crate::rect_util::{distance_to_rect, normalize_rect};
+use crate::tiles::tile_to_rect;
+use crate::{swept_rect_vs_rect, RayIntersectionResult};
+
+/// Identifies a collider in a [`World`].
+///
+/// Ids are handed out in increasing order by [`World::insert`] and are never reused, so
+/// a larger id always belongs to a collider that was inserted later.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ColliderId(pub u32);
+
+/// The shape of a collider in a [`World`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColliderShape {
+    Rect(Rect),
+    Obb(Obb),
+    Circle(Circle),
+}
+
+impl ColliderShape {
+    /// Checks if a point is inside the shape.
+    ///
+    /// Rectangles use [`point_in_rect`]. Oriented boxes and circles include their edges.
+    #[must_use]
+    pub fn contains_point(&self, point: Vector) -> bool {
+        match self {
+            Self::Rect(rect) => point_in_rect(point, *rect),
+            Self::Obb(obb) => obb.contains_point(point),
+            Self::Circle(circle) => circle.contains_point(point),
+        }
+    }
+}
+
+impl Collidable for ColliderShape {
+    fn ray_cast(&self, origin: Vector, direction: Vector) -> Option<RayIntersectionResult> {
+        match self {
+            Self::Rect(rect) => rect.ray_cast(origin, direction),
+            Self::Obb(obb) => obb.ray_cast(origin, direction),
+            Self::Circle(circle) => circle.ray_cast(origin, direction),
+        }
+    }
+
+    fn aabb(&self) -> Rect {
+        match self {
+            Self::Rect(rect) => *rect,
+            Self::Obb(obb) => obb.aabb(),
+            Self::Circle(circle) => circle.aabb(),
+        }
+    }
+}
+
+impl From<Rect> for ColliderShape {
+    fn from(rect: Rect) -> Self {
+        Self::Rect(normalize_rect(rect))
+    }
+}
+
+impl From<Obb> for ColliderShape {
+    fn from(obb: Obb) -> Self {
+        Self::Obb(obb)
+    }
+}
+
+impl From<Circle> for ColliderShape {
+    fn from(circle: Circle) -> Self {
+        Self::Circle(circle)
+    }
+}
+
+/// A set of colliders.
+///
+/// Colliders are kept in insertion order. Queries that can match several colliders treat
+/// later inserted colliders as being on top of earlier ones.
+#[derive(Debug, Clone, Default)]
+pub struct World {
+    colliders: Vec<(ColliderId, ColliderShape)>,
+    user_data: BTreeMap<ColliderId, u64>,
+    next_id: u32,
+    skin_width: Fp,
+}
+
+impl World {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the distance that [`World::swept_rect`] keeps between a mover and the
+    /// surface it hits. The default is zero.
+    #[must_use]
+    pub const fn skin_width(&self) -> Fp {
+        self.skin_width
+    }
+
+    /// Sets the distance that [`World::swept_rect`] keeps between a mover and the surface it
+    /// hits. Negative values are treated as zero.
+    pub fn set_skin_width(&mut self, skin_width: Fp) {
+        self.skin_width = max(skin_width, Fp::zero());
+    }
+
+    /// Adds a collider and returns its id.
+    ///
+    /// Accepts a [`Rect`], an [`Obb`], a [`Circle`] or a [`ColliderShape`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fixed32_math::Rect;
+    /// use impact_rs::world::{ColliderShape, World};
+    ///
+    /// let mut world = World::new();
+    /// let id = world.insert(Rect::from((0, 0, 2, 2)));
+    ///
+    /// assert_eq!(world.get(id), Some(ColliderShape::Rect(Rect::from((0, 0, 2, 2)))));
+    /// ```
+    pub fn insert(&mut self, shape: impl Into<ColliderShape>) -> ColliderId {
+        let id = ColliderId(self.next_id);
+        self.next_id += 1;
+        self.colliders.push((id, shape.into()));
+        id
+    }
+
+    /// Adds a collider tagged with `user_data` and returns its id.
+    ///
+    /// The tag is not used by the world itself. It is meant for the caller's own handle of
+    /// the collider, such as a game entity, and is returned by [`World::query_ray`] and
+    /// [`World::user_data`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fixed32_math::Rect;
+    /// use impact_rs::world::World;
+    ///
+    /// let mut world = World::new();
+    /// let id = world.insert_with_user_data(Rect::from((0, 0, 2, 2)), 42);
+    ///
+    /// assert_eq!(world.user_data(id), Some(42));
+    /// ```
+    pub fn insert_with_user_data(
+        &mut self,
+        shape: impl Into<ColliderShape>,
+        user_data: u64,
+    ) -> ColliderId {
+        let id = self.insert(shape);
+        self.user_data.insert(id, user_data);
+        id
+    }
+
+    /// Adds one rectangle collider per solid tile and returns their ids in the order of
+    /// `tiles`.
+    ///
+    /// Each `(tx, ty)` is converted with [`tile_to_rect`], so the tiles line up with the
+    /// other tile helpers for the same `tile_size`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fixed32::Fp;
+    /// use fixed32_math::Rect;
+    /// use impact_rs::world::{ColliderShape, World};
+    ///
+    /// let mut world = World::new();
+    /// let ids = world.insert_tilemap(&[(0, 0), (3, -1)], Fp::from(16));
+    ///
+    /// assert_eq!(world.get(ids[1]), Some(ColliderShape::Rect(Rect::from((48, -16, 16, 16)))));
+    /// ```
+    pub fn insert_tilemap(&mut self, tiles: &[(i32, i32)], tile_size: Fp) -> Vec<ColliderId> {
+        self.colliders.reserve(tiles.len());
+        tiles
+            .iter()
+            .map(|&(tx, ty)| self.insert(tile_to_rect(tx, ty, tile_size)))
+            .collect()
+    }
+
+    /// Removes a collider, returning its shape if it was in the world.
+    pub fn remove(&mut self, id: ColliderId) -> Option<ColliderShape> {
+        let position = self.position(id)?;
+        self.user_data.remove(&id);
+        Some(self.colliders.remove(position).1)
+    }
+
+    /// Returns the shape of a collider, or `None` if it is not in the world.
+    #[must_use]
+    pub fn get(&self, id: ColliderId) -> Option<ColliderShape> {
+        self.position(id).map(|position| self.colliders[position].1)
+    }
+
+    /// Returns the tag that a collider was inserted with, or `None` if it was inserted
+    /// without one or is not in the world.
+    #[must_use]
+    pub fn user_data(&self, id: ColliderId) -> Option<u64> {
+        self.user_data.get(&id).copied()
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.colliders.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.colliders.is_empty()
+    }
+
+    /// Iterates over all colliders in insertion order.
+    pub fn iter(&self) -> impl Iterator<Item = (ColliderId, ColliderShape)> + '_ {
+        self.colliders.iter().copied()
+    }
+
+    /// Casts a ray against every collider and returns the nearest hit.
+    ///
+    /// Each shape is tested with its own query through [`Collidable::ray_cast`], so the
+    /// `contact_point` and `contact_normal` are in world space for every shape. If two
+    /// colliders are hit at the same time, the one inserted first wins.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Some((id, RayIntersectionResult))` for the nearest hit, or `None` if no
+    /// collider is hit.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fixed32::Fp;
+    /// use fixed32_math::{Rect, Vector};
+    /// use impact_rs::prelude::full::*;
+    ///
+    /// let mut world = World::new();
+    /// let _far = world.insert(Rect::from((20, -2, 4, 4)));
+    /// let near = world.insert(Circle::new(Vector::from((10, 0)), Fp::from(2)));
+    ///
+    /// let (id, result) = world.raycast(Vector::from((0, 0)), Vector::from((1, 0))).unwrap();
+    /// assert_eq!(id, near);
+    /// assert_eq!(result.closest_time, Fp::from(8));
+    /// ```
+    #[must_use]
+    pub fn raycast(
+        &self,
+        origin: Vector,
+        direction: Vector,
+    ) -> Option<(ColliderId, RayIntersectionResult)> {
+        let mut closest: Option<(ColliderId, RayIntersectionResult)> = None;
+
+        for (id, shape) in &self.colliders {
+            if let Some(result) = shape.ray_cast(origin, direction) {
+                // Strictly closer, so the collider inserted first wins a tie
+                let is_closer = closest
+                    .as_ref()
+                    .is_none_or(|(_, best)| result.closest_time < best.closest_time);
+                if is_closer {
+                    closest = Some((*id, result));
+                }
+            }
+        }
+
+        closest
+    }
+
+    /// Performs [`World::raycast`] and also returns the tag of the collider that was hit.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Some((id, user_data, RayIntersectionResult))` for the nearest hit, where
+    /// `user_data` is the tag given to [`World::insert_with_user_data`], or `None` for a
+    /// collider inserted without one. Returns `None` if no collider is hit.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fixed32::Fp;
+    /// use fixed32_math::{Rect, Vector};
+    /// use impact_rs::world::World;
+    ///
+    /// let mut world = World::new();
+    /// let wall = world.insert_with_user_data(Rect::from((10, -2, 2, 4)), 7);
+    ///
+    /// let (id, user_data, result) =
+    ///     world.query_ray(Vector::from((0, 0)), Vector::from((1, 0))).unwrap();
+    /// assert_eq!(id, wall);
+    /// assert_eq!(user_data, Some(7));
+    /// assert_eq!(result.closest_time, Fp::from(10));
+    /// ```
+    #[must_use]
+    pub fn query_ray(
+        &self,
+        origin: Vector,
+        direction: Vector,
+    ) -> Option<(ColliderId, Option<u64>, RayIntersectionResult)> {
+        self.raycast(origin, direction)
+            .map(|(id, result)| (id, self.user_data(id), result))
+    }
+
+    /// Finds the topmost collider that contains `point`.
+    ///
+    /// Containment is tested with [`ColliderShape::contains_point`]. When several colliders
+    /// contain the point, the one inserted last is returned, which matches picking the collider
+    /// drawn on top when colliders are drawn in insertion order.
+    /// # Example
+    ///
+    /// ```rust
+    /// use fixed32_math::{Rect, Vector};
+    /// use impact_rs::world::World;
+    ///
+    /// let mut world = World::new();
+    /// let _floor = world.insert(Rect::from((0, 0, 20, 4)));
+    /// let crate_box = world.insert(Rect::from((2, 2, 2, 2)));
+    ///
+    /// assert_eq!(world.query_point(Vector::from((3, 3))), Some(crate_box));
+    /// ```
+    #[must_use]
+    pub fn query_point(&self, point: Vector) -> Option<ColliderId> {
+        self.colliders
+            .iter()
+            .rev()
+            .find(|(_, shape)| shape.contains_point(point))
+            .map(|(id, _)| *id)
+    }
+
+    /// Finds every collider that contains `point`.
+    ///
+    /// # Returns
+    ///
+    /// The ids of all the containing colliders, topmost first. The first id is the one
+    /// returned by [`World::query_point`].
+    #[must_use]
+    pub fn query_point_all(&self, point: Vector) -> Vec<ColliderId> {
+        self.colliders
+            .iter()
+            .rev()
+            .filter(|(_, shape)| shape.contains_point(point))
+            .map(|(id, _)| *id)
+            .collect()
+    }
+
+    /// Finds the collider nearest to `point` that is at most `radius` away.
+    ///
+    /// The distance to a collider is measured with [`distance_to_rect`] to its bounding
+    /// rectangle, rounded down, and is zero when `point` is inside it. When several colliders
+    /// are equally near, the one inserted last is returned, as in [`World::query_point`].
+    ///
+    /// # Returns
+    ///
+    /// Returns the id of the nearest collider, or `None` if no collider is within `radius`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fixed32::Fp;
+    /// use fixed32_math::{Rect, Vector};
+    /// use impact_rs::world::World;
+    ///
+    /// let mut world = World::new();
+    /// let _far = world.insert(Rect::from((10, 0, 2, 2)));
+    /// let near = world.insert(Rect::from((-4, 0, 2, 2)));
+    ///
+    /// assert_eq!(world.nearest_within(Vector::from((0, 1)), Fp::from(5)), Some(near));
+    /// assert_eq!(world.nearest_within(Vector::from((0, 1)), Fp::from(1)), None);
+    /// ```
+    #[must_use]
+    pub fn nearest_within(&self, point: Vector, radius: Fp) -> Option<ColliderId> {
+        self.colliders
+            .iter()
+            .rev()
+            .filter_map(|(id, shape)| {
+                let distance = distance_to_rect(point, shape.aabb(), Rounding::Floor);
+                (distance <= radius).then_some((*id, distance))
+            })
+            .min_by_key(|(_, distance)| *distance)
+            .map(|(id, _)| id)
+    }
+
+    /// Finds every collider that overlaps `area`.
+    ///
+    /// Overlap is tested with [`rect_vs_rect`], so colliders that only touch the edge of
+    /// `area` are not included. Oriented boxes and circles are tested with their bounding
+    /// rectangle, so they can be reported when only a corner of the bounds is inside `area`.
+    /// Each collider is reported at most once.
+    ///
+    /// # Returns
+    ///
+    /// The ids of all the overlapping colliders, in insertion order.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fixed32_math::Rect;
+    /// use impact_rs::world::World;
+    ///
+    /// let mut world = World::new();
+    /// let a = world.insert(Rect::from((0, 0, 2, 2)));
+    /// let _b = world.insert(Rect::from((10, 0, 2, 2)));
+    ///
+    /// assert_eq!(world.query_aabb(Rect::from((1, 1, 4, 4))), vec![a]);
+    /// ```
+    #[must_use]
+    pub fn query_aabb(&self, area: Rect) -> Vec<ColliderId> {
+        self.colliders
+            .iter()
+            .filter(|(_, shape)| rect_vs_rect(area, shape.aabb()))
+            .map(|(id, _)| *id)
+            .collect()
+    }
+
+    /// Captures every collider and its id, along with the skin width.
+    ///
+    /// The snapshot keeps the colliders in insertion order, along with their tags, and
+    /// remembers the next id to hand out and the [skin width](World::set_skin_width), so a
+    /// world restored from it answers queries and assigns ids exactly like the original did
+    /// at the time of the snapshot.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fixed32_math::Rect;
+    /// use impact_rs::world::World;
+    ///
+    /// let mut world = World::new();
+    /// let wall = world.insert(Rect::from((0, 0, 2, 2)));
+    /// let snapshot = world.snapshot();
+    ///
+    /// world.remove(wall);
+    /// world.restore(&snapshot);
+    ///
+    /// assert_eq!(world.get(wall), Some(Rect::from((0, 0, 2, 2)).into()));
+    /// ```
+    #[must_use]
+    pub fn snapshot(&self) -> WorldSnapshot {
+        WorldSnapshot {
+            colliders: self
+                .colliders
+                .iter()
+                .map(|(id, shape)| (id.0, SnapshotShape::from(*shape)))
+                .collect(),
+            user_data: self
+                .user_data
+                .iter()
+                .map(|(id, user_data)| (id.0, *user_data))
+                .collect(),
+            next_id: self.next_id,
+            skin_width: self.skin_width.inner(),
+        }
+    }
+
+    /// Replaces every collider and the skin width with the ones captured in `snapshot`.
+    pub fn restore(&mut self, snapshot: &WorldSnapshot) {
+        self.colliders = snapshot
+            .colliders
+            .iter()
+            .map(|(id, shape)| (ColliderId(*id), ColliderShape::from(*shape)))
+            .collect();
+        self.user_data = snapshot
+            .user_data
+            .iter()
+            .map(|(id, user_data)| (ColliderId(*id), *user_data))
+            .collect();
+        self.next_id = snapshot.next_id;
+        self.skin_width = Fp::from_raw(snapshot.skin_width);
+    }
+
+    /// Sweeps a rectangle through the world and returns the first collider it hits.
+    ///
+    /// Every collider is tested with [`swept_rect_vs_rect`]. Oriented boxes and circles are
+    /// tested with their bounding rectangle. If two colliders are hit at the same time, the
+    /// one inserted first wins.
+    ///
+    /// The contact is moved back by the [skin width](World::set_skin_width), measured along
+    /// the contact normal, so that a rectangle moved by `delta * closest_time` rests that far
+    /// from the surface instead of touching it. The time is rounded so that the gap is never
+    /// smaller than the skin width. Keeping this small gap stops resting bodies
+    /// from jittering between touching and overlapping due to rounding. Exact corner hits
+    /// have no normal, so the gap is measured along `delta` instead. The time never goes
+    /// below zero.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Some((id, RayIntersectionResult))` for the first hit within the time range
+    /// `[0, 1)`, or `None` if no collider is hit.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fixed32::Fp;
+    /// use fixed32_math::{Rect, Vector};
+    /// use impact_rs::world::World;
+    ///
+    /// let mut world = World::new();
+    /// let wall = world.insert(Rect::from((10, -4, 2, 8)));
+    /// world.set_skin_width(Fp::from(0.5));
+    ///
+    /// let origin = Rect::from((0, 0, 2, 2));
+    /// let delta = Vector::from((16, 0));
+    /// let (id, result) = world.swept_rect(origin, delta).unwrap();
+    ///
+    /// assert_eq!(id, wall);
+    /// let gap = Fp::from(10) - origin.move_by(delta * result.closest_time).right();
+    /// assert!(gap >= Fp::from(0.5) && gap < Fp::from(0.51));
+    /// ```
+    #[must_use]
+    pub fn swept_rect(
+        &self,
+        origin: Rect,
+        delta: Vector,
+    ) -> Option<(ColliderId, RayIntersectionResult)> {
+        let (id, mut result) = self
+            .colliders
+            .iter()
+            .filter_map(|(id, shape)| {
+                swept_rect_vs_rect(origin, shape.aabb(), delta).map(|result| (*id, result))
+            })
+            .min_by_key(|(_, result)| result.closest_time)?;
+
+        if !self.skin_width.is_zero() {
+            let approach = dot(delta, result.contact_normal).abs();
+            let distance = if approach.is_zero() {
+                length(delta, Rounding::Floor)
+            } else {
+                approach
+            };
+            // Rounded up, so the gap is never smaller than the skin
+            let skin_time = saturating_div(self.skin_width, distance) + Fp::from_raw(1);
+            let time = max(result.closest_time - skin_time, Fp::zero());
+
+            result.contact_point = result.contact_point - delta * (result.closest_time - time);
+            result.closest_time = time;
+            result.frame_fraction = Some(time);
+        }
+
+        Some((id, result))
+    }
+
+    fn position(&self, id: ColliderId) -> Option<usize> {
+        self.colliders
+            .binary_search_by_key(&id, |(collider_id, _)| *collider_id)
+            .ok()
+    }
+}
+
+/// The colliders of a [`World`] at one point in time, created by [`World::snapshot`].
+///
+/// The shapes are stored as raw fixed-point values, so a snapshot can be serialized with the
+/// `serde` feature and restored bit for bit on any platform.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct WorldSnapshot {
+    colliders: Vec<(u32, SnapshotShape)>,
+    user_data: Vec<(u32, u64)>,
+    next_id: u32,
+    skin_width: i32,
+}
+
+/// A [`ColliderShape`] with every [`Fp`] replaced by its raw value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+enum SnapshotShape {
+    Rect {
+        pos: [i32; 2],
+        size: [i32; 2],
+    },
+    Obb {
+        center: [i32; 2],
+        half_size: [i32; 2],
+        axis: [i32; 2],
+    },
+    Circle {
+        center: [i32; 2],
+        radius: i32,
+    },
+}
+
+impl From<ColliderShape> for SnapshotShape {
+    fn from(shape: ColliderShape) -> Self {
+        match shape {
+            ColliderShape::Rect(rect) => Self::Rect {
+                pos: raw_vector(rect.pos),
+                size: raw_vector(rect.size),
+            },
+            ColliderShape::Obb(obb) => Self::Obb {
+                center: raw_vector(obb.center),
+                half_size: raw_vector(obb.half_size),
+                axis: raw_vector(obb.axis),
+            },
+            ColliderShape::Circle(circle) => Self::Circle {
+                center: raw_vector(circle.center),
+                radius: circle.radius.inner(),
+            },
+        }
+    }
+}
+
+impl From<SnapshotShape> for ColliderShape {
+    fn from(shape: SnapshotShape) -> Self {
+        match shape {
+            SnapshotShape::Rect { pos, size } => Self::Rect(Rect {
+                pos: from_raw_vector(pos),
+                size: from_raw_vector(size),
+            }),
+            SnapshotShape::Obb {
+                center,
+                half_size,
+                axis,
+            } => Self::Obb(Obb {
+                center: from_raw_vector(center),
+                half_size: from_raw_vector(half_size),
+                axis: from_raw_vector(axis),
+            }),
+            SnapshotShape::Circle { center, radius } => {
+                Self::Circle(Circle::new(from_raw_vector(center), Fp::from_raw(radius)))
+            }
+        }
+    }
+}
+
+const fn raw_vector(v: Vector) -> [i32; 2] {
+    [v.x.inner(), v.y.inner()]
+}
+
+const fn from_raw_vector(raw: [i32; 2]) -> Vector {
+    Vector::new(Fp::from_raw(raw[0]), Fp::from_raw(raw[1]))
+}
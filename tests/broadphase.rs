@@ -0,0 +1,88 @@
+/*
+ * Copyright (c) Peter Bjorklund. All rights reserved. https://github.com/piot/impact-rs
+ * Licensed under the MIT License. See LICENSE in the project root for license information.
+ */
+
+use fixed32_math::Rect;
+use impact_rs::prelude::full::*;
+
+fn brute_force_pairs(rects: &[Rect]) -> Vec<(usize, usize)> {
+    let mut pairs = Vec::new();
+    for first in 0..rects.len() {
+        for second in first + 1..rects.len() {
+            if rect_vs_rect(rects[first], rects[second]) {
+                pairs.push((first, second));
+            }
+        }
+    }
+    pairs
+}
+
+#[test]
+fn test_overlapping_pairs_matches_brute_force() {
+    let rects = [
+        Rect::from((0, 0, 4, 4)),
+        Rect::from((3, 3, 4, 4)),
+        Rect::from((-2, 1, 3, 1)),
+        Rect::from((10, 10, 2, 2)),
+        Rect::from((4, -5, 1, 20)),
+        Rect::from((11, 0, 2, 11)),
+    ];
+
+    assert_eq!(overlapping_pairs(&rects), brute_force_pairs(&rects));
+}
+
+#[test]
+fn test_overlapping_pairs_touching_edges_are_not_pairs() {
+    let rects = [
+        Rect::from((0, 0, 2, 2)),
+        Rect::from((2, 0, 2, 2)),
+        Rect::from((0, 2, 2, 2)),
+    ];
+
+    assert!(overlapping_pairs(&rects).is_empty());
+}
+
+#[test]
+fn test_overlapping_pairs_grouped_same_group_not_paired() {
+    let rects = [Rect::from((0, 0, 4, 4)), Rect::from((2, 2, 4, 4))];
+
+    assert!(overlapping_pairs_grouped(&rects, &[7, 7]).is_empty());
+    assert_eq!(overlapping_pairs(&rects), vec![(0, 1)]);
+}
+
+#[test]
+fn test_overlapping_pairs_grouped_different_groups_paired() {
+    let rects = [Rect::from((0, 0, 4, 4)), Rect::from((2, 2, 4, 4))];
+
+    assert_eq!(overlapping_pairs_grouped(&rects, &[1, 2]), vec![(0, 1)]);
+    // Group zero collides with everything, including other rects of group zero
+    assert_eq!(overlapping_pairs_grouped(&rects, &[0, 2]), vec![(0, 1)]);
+    assert_eq!(overlapping_pairs_grouped(&rects, &[0, 0]), vec![(0, 1)]);
+}
+
+#[test]
+fn test_overlapping_pairs_grouped_multi_part_entity() {
+    let rects = [
+        Rect::from((0, 0, 4, 4)),
+        Rect::from((3, 3, 4, 4)),
+        Rect::from((-2, 1, 3, 1)),
+        Rect::from((10, 10, 2, 2)),
+        Rect::from((4, -5, 1, 20)),
+        Rect::from((11, 0, 2, 11)),
+    ];
+    let groups = [3, 3, 0, 1, 3, 1];
+
+    let expected: Vec<(usize, usize)> = brute_force_pairs(&rects)
+        .into_iter()
+        .filter(|&(a, b)| groups[a] == 0 || groups[a] != groups[b])
+        .collect();
+    assert_eq!(overlapping_pairs_grouped(&rects, &groups), expected);
+    assert_eq!(expected, vec![(0, 2)]);
+}
+
+#[test]
+#[should_panic(expected = "every rect must have a group id")]
+fn test_overlapping_pairs_grouped_length_mismatch() {
+    let _ = overlapping_pairs_grouped(&[Rect::from((0, 0, 1, 1))], &[]);
+}
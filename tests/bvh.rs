@@ -0,0 +1,80 @@
+/*
+ * Copyright (c) Peter Bjorklund. All rights reserved. https://github.com/piot/impact-rs
+ * Licensed under the MIT License. See LICENSE in the project root for license information.
+ */
+
+use fixed32::Fp;
+use fixed32_math::{Rect, Vector};
+use impact_rs::prelude::full::*;
+
+struct Lcg(u32);
+
+impl Lcg {
+    fn next_in(&mut self, low: i32, high: i32) -> i32 {
+        self.0 = self.0.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+        low + ((self.0 >> 8) % (high - low) as u32) as i32
+    }
+
+    fn next_fp(&mut self, low: i32, high: i32) -> Fp {
+        Fp::from_raw(self.next_in(low * Fp::SCALE, high * Fp::SCALE))
+    }
+}
+
+#[test]
+fn test_aabb_tree_matches_brute_force() {
+    let mut random = Lcg(0x1234_5678);
+
+    let rects: Vec<Rect> = (0..300)
+        .map(|_| {
+            Rect::new(
+                Vector::new(random.next_fp(-200, 200), random.next_fp(-200, 200)),
+                Vector::new(random.next_fp(1, 20), random.next_fp(1, 20)),
+            )
+        })
+        .collect();
+    let tree = AabbTree::build(&rects);
+    let mut hits = 0;
+
+    for _ in 0..200 {
+        let origin = Vector::new(random.next_fp(-250, 250), random.next_fp(-250, 250));
+        let direction = Vector::new(random.next_fp(-50, 50), random.next_fp(-50, 50));
+
+        let expected = ray_vs_rects(origin, direction, &rects);
+        let actual = tree.raycast(origin, direction);
+        hits += usize::from(expected.is_some());
+
+        assert_eq!(
+            expected
+                .as_ref()
+                .map(|(index, hit)| (*index, hit.closest_time)),
+            actual
+                .as_ref()
+                .map(|(index, hit)| (*index, hit.closest_time)),
+        );
+    }
+
+    assert!(hits > 50);
+}
+
+#[test]
+fn test_aabb_tree_axis_aligned_rays_and_empty_tree() {
+    let rects = [
+        Rect::from((10, 0, 4, 4)),
+        Rect::from((20, 0, 4, 4)),
+        Rect::from((10, 10, 4, 4)),
+    ];
+    let tree = AabbTree::build(&rects);
+
+    let (index, hit) = tree
+        .raycast(Vector::from((12, 20)), Vector::from((0, -1)))
+        .expect("should hit");
+    assert_eq!(index, 2);
+    assert_eq!(hit.closest_time, Fp::from(6));
+
+    assert!(tree
+        .raycast(Vector::from((0, 20)), Vector::from((1, 0)))
+        .is_none());
+    assert!(AabbTree::build(&[])
+        .raycast(Vector::from((0, 0)), Vector::from((1, 0)))
+        .is_none());
+}
@@ -0,0 +1,50 @@
+/*
+ * Copyright (c) Peter Bjorklund. All rights reserved. https://github.com/piot/impact-rs
+ * Licensed under the MIT License. See LICENSE in the project root for license information.
+ */
+
+use fixed32::Fp;
+use fixed32_math::{Rect, Vector};
+use impact_rs::prelude::full::*;
+
+#[test]
+fn test_checked_ray_near_fp_max_overflows() {
+    let ray_direction = Vector::from((1, 0));
+
+    // The right edge of the target is beyond Fp::MAX
+    let edge_target = Rect::new(
+        Vector::new(Fp::MAX - Fp::from(5), Fp::zero()),
+        Vector::from((10, 2)),
+    );
+    assert_eq!(
+        try_ray_vs_rect_checked(Vector::from((0, 1)), ray_direction, edge_target).unwrap_err(),
+        OverflowError
+    );
+
+    // A tiny direction turns a moderate distance into a slab time beyond Fp::MAX
+    let slow_direction = Vector::new(Fp::from_raw(1), Fp::zero());
+    let target = Rect::from((100, -1, 10, 2));
+    assert_eq!(
+        try_ray_vs_rect_checked(Vector::from((0, 0)), slow_direction, target).unwrap_err(),
+        OverflowError
+    );
+}
+
+#[test]
+fn test_checked_ray_in_range_matches_unchecked() {
+    let ray_origin = Vector::from((1, 2));
+    let ray_direction = Vector::from((3, 4));
+    let target = Rect::from((5, 6, 7, 8));
+
+    let checked = try_ray_vs_rect_checked(ray_origin, ray_direction, target)
+        .expect("should not overflow")
+        .expect("should have intersected");
+    let unchecked =
+        ray_vs_rect(ray_origin, ray_direction, target).expect("should have intersected");
+
+    assert_eq!(checked.closest_time, unchecked.closest_time);
+    assert_eq!(checked.contact_point, unchecked.contact_point);
+
+    let miss = try_ray_vs_rect_checked(ray_origin, Vector::from((-1, 0)), target);
+    assert!(matches!(miss, Ok(None)));
+}
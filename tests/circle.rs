@@ -0,0 +1,130 @@
+/*
+ * Copyright (c) Peter Bjorklund. All rights reserved. https://github.com/piot/impact-rs
+ * Licensed under the MIT License. See LICENSE in the project root for license information.
+ */
+
+use fixed32::Fp;
+use fixed32_math::Vector;
+use impact_rs::math::approx_eq;
+use impact_rs::prelude::full::*;
+
+#[test]
+fn test_toi_circle_head_on() {
+    let time = toi_circle_vs_circle(
+        Vector::from((0, 0)),
+        Fp::one(),
+        Vector::from((10, 0)),
+        Vector::from((10, 0)),
+        Fp::one(),
+        Vector::from((0, 0)),
+    )
+    .expect("should collide");
+
+    assert!(approx_eq(time, Fp::from(0.8), Fp::from(0.001)));
+}
+
+#[test]
+fn test_toi_circle_both_moving_head_on() {
+    let time = toi_circle_vs_circle(
+        Vector::from((0, 0)),
+        Fp::one(),
+        Vector::from((5, 0)),
+        Vector::from((10, 0)),
+        Fp::one(),
+        Vector::from((-5, 0)),
+    )
+    .expect("should collide");
+
+    assert!(approx_eq(time, Fp::from(0.8), Fp::from(0.001)));
+}
+
+#[test]
+fn test_toi_circle_grazing_pass() {
+    let time = toi_circle_vs_circle(
+        Vector::from((0.0, 0.0)),
+        Fp::one(),
+        Vector::from((10.0, 0.0)),
+        Vector::from((5.0, 1.9)),
+        Fp::one(),
+        Vector::from((0.0, 0.0)),
+    )
+    .expect("should graze");
+
+    // Touches shortly before the centers line up at t = 0.5
+    assert!(time > Fp::from(0.4));
+    assert!(time < Fp::from(0.5));
+}
+
+#[test]
+fn test_toi_circle_parallel_no_collision() {
+    let same_motion = toi_circle_vs_circle(
+        Vector::from((0, 0)),
+        Fp::one(),
+        Vector::from((10, 0)),
+        Vector::from((0, 5)),
+        Fp::one(),
+        Vector::from((10, 0)),
+    );
+    assert!(same_motion.is_none());
+
+    let passing_lanes = toi_circle_vs_circle(
+        Vector::from((0, 0)),
+        Fp::one(),
+        Vector::from((10, 0)),
+        Vector::from((10, 3)),
+        Fp::one(),
+        Vector::from((-10, 0)),
+    );
+    assert!(passing_lanes.is_none());
+}
+
+#[test]
+fn test_toi_circle_starting_overlap_and_out_of_frame() {
+    let overlapping = toi_circle_vs_circle(
+        Vector::from((0, 0)),
+        Fp::one(),
+        Vector::from((10, 0)),
+        Vector::from((1, 0)),
+        Fp::one(),
+        Vector::from((0, 0)),
+    );
+    assert!(overlapping.is_none());
+
+    let too_far = toi_circle_vs_circle(
+        Vector::from((0, 0)),
+        Fp::one(),
+        Vector::from((5, 0)),
+        Vector::from((20, 0)),
+        Fp::one(),
+        Vector::from((0, 0)),
+    );
+    assert!(too_far.is_none());
+}
+
+#[test]
+fn test_toi_circle_long_deltas() {
+    // Squaring the distance between these circles does not fit in an `Fp`
+    let time = toi_circle_vs_circle(
+        Vector::from((0, 0)),
+        Fp::one(),
+        Vector::from((150, 0)),
+        Vector::from((150, 0)),
+        Fp::one(),
+        Vector::from((0, 0)),
+    )
+    .expect("should collide");
+    assert!(approx_eq(time, Fp::from(148.0 / 150.0), Fp::from_raw(1)));
+
+    // Near the ends of the fixed-point range, where the inputs are scaled down
+    let time = toi_circle_vs_circle(
+        Vector::from((-20000, 3)),
+        Fp::from(2),
+        Vector::from((30000, 0)),
+        Vector::from((9000, 0)),
+        Fp::from(3),
+        Vector::from((-1000, 0)),
+    )
+    .expect("should collide");
+    let expected = (29000.0 - 4.0) / 31000.0;
+    assert!(approx_eq(time, Fp::from(expected), Fp::from(0.0001)));
+}
@@ -0,0 +1,103 @@
+/*
+ * Copyright (c) Peter Bjorklund. All rights reserved. https://github.com/piot/impact-rs
+ * Licensed under the MIT License. See LICENSE in the project root for license information.
+ */
+
+use fixed32::Fp;
+use fixed32_math::{Rect, Vector};
+use impact_rs::math::{approx_eq, approx_eq_vector};
+use impact_rs::prelude::full::*;
+
+#[test]
+fn test_heterogeneous_collidables() {
+    let shapes: Vec<Box<dyn Collidable>> = vec![
+        Box::new(Rect::from((20, -2, 4, 4))),
+        Box::new(Circle::new(Vector::from((10, 0)), Fp::from(2))),
+    ];
+
+    let (index, result) = ray_vs_collidables(Vector::from((0, 0)), Vector::from((1, 0)), &shapes)
+        .expect("should hit");
+    assert_eq!(index, 1);
+    assert_eq!(result.closest_time, Fp::from(8));
+    assert_eq!(result.contact_point, Vector::from((8, 0)));
+
+    let (index, result) = ray_vs_collidables(Vector::from((0, 4)), Vector::from((1, 0)), &shapes)
+        .map_or((usize::MAX, None), |(index, result)| (index, Some(result)));
+    assert_eq!(index, usize::MAX);
+    assert!(result.is_none());
+
+    assert_eq!(shapes[0].aabb(), Rect::from((20, -2, 4, 4)));
+    assert_eq!(shapes[1].aabb(), Rect::from((8, -2, 4, 4)));
+}
+
+#[test]
+fn test_rect_collidable_matches_ray_vs_rect() {
+    let target = Rect::from((5, 6, 7, 8));
+    let origin = Vector::from((1, 2));
+    let direction = Vector::from((3, 4));
+
+    let direct = ray_vs_rect(origin, direction, target).expect("should have intersected");
+    let through_trait = target
+        .ray_cast(origin, direction)
+        .expect("should have intersected");
+
+    assert_eq!(direct.closest_time, through_trait.closest_time);
+    assert_eq!(direct.contact_normal, through_trait.contact_normal);
+}
+
+#[test]
+fn test_ray_vs_circle_misses_and_hits_from_inside() {
+    let circle = Circle::new(Vector::from((10, 0)), Fp::from(2));
+
+    assert!(ray_vs_circle(Vector::from((0, 3)), Vector::from((1, 0)), circle).is_none());
+    assert!(ray_vs_circle(Vector::from((0, 0)), Vector::from((-1, 0)), circle).is_none());
+
+    let inside = ray_vs_circle(Vector::from((10, 0)), Vector::from((1, 0)), circle)
+        .expect("should report the hit from inside");
+    assert!(inside.closest_time < Fp::zero());
+}
+
+#[test]
+fn test_ray_vs_circle_zero_radius() {
+    let point = Circle::new(Vector::from((10, 0)), Fp::zero());
+
+    assert!(ray_vs_circle(Vector::from((0, 1)), Vector::from((1, 0)), point).is_none());
+
+    let result = ray_vs_circle(Vector::from((0, 0)), Vector::from((2, 0)), point)
+        .expect("should hit the point");
+    assert_eq!(result.closest_time, Fp::from(5));
+    assert_eq!(result.contact_point, Vector::from((10, 0)));
+    assert_eq!(result.contact_normal, Vector::right());
+    assert_eq!(result.face, ContactSide::Right);
+}
+
+#[test]
+fn test_ray_vs_circle_long_direction() {
+    let circle = Circle::new(Vector::from((150, 0)), Fp::from(2));
+
+    // Squaring the length of these directions does not fit in an `Fp`
+    let result =
+        ray_vs_circle(Vector::from((0, 0)), Vector::from((200, 0)), circle).expect("should hit");
+    assert_eq!(result.closest_time, Fp::from(148.0 / 200.0));
+    let near = Fp::from(0.01);
+    assert!(approx_eq_vector(
+        result.contact_point,
+        Vector::from((148, 0)),
+        near
+    ));
+    assert!(approx_eq_vector(
+        result.contact_normal,
+        Vector::right(),
+        near
+    ));
+
+    let far = Circle::new(Vector::from((-3000, 4000)), Fp::from(10));
+    let result =
+        ray_vs_circle(Vector::from((0, 0)), Vector::from((-300, 400)), far).expect("should hit");
+    assert!(approx_eq(
+        result.closest_time,
+        Fp::from(9.98),
+        Fp::from(0.0001)
+    ));
+    assert!(ray_vs_circle(Vector::from((0, 0)), Vector::from((300, 400)), far).is_none());
+}
@@ -0,0 +1,88 @@
+/*
+ * Copyright (c) Peter Bjorklund. All rights reserved. https://github.com/piot/impact-rs
+ * Licensed under the MIT License. See LICENSE in the project root for license information.
+ */
+
+use fixed32::Fp;
+use fixed32_math::{Rect, Vector};
+use impact_rs::prelude::full::*;
+use impact_rs::trig::rotate;
+
+#[test]
+fn test_cone_cast_inside_and_outside() {
+    let inside = Rect::from((10.0, -1.0, 2.0, 2.0));
+    let just_outside = Rect::from((10.0, 7.5, 2.0, 1.0));
+    let targets = [inside, just_outside];
+
+    let hits = cone_cast(
+        Vector::from((0.0, 0.0)),
+        Vector::from((1.0, 0.0)),
+        Fp::from(0.5),
+        5,
+        &targets,
+    );
+
+    assert_eq!(hits.len(), 5);
+
+    let (index, result) = hits[2].as_ref().expect("center ray should hit");
+    assert_eq!(*index, 0);
+    assert_eq!(result.closest_time, Fp::from(10));
+
+    assert!(hits.iter().flatten().all(|(index, _)| *index != 1));
+}
+
+#[test]
+fn test_cone_cast_edge_rays_reach_half_angle() {
+    // A tall wall above the cone edge is only hit by the upper edge ray.
+    let targets = [Rect::from((10.0, 4.0, 1.0, 4.0))];
+
+    let hits = cone_cast(
+        Vector::from((0.0, 0.0)),
+        Vector::from((1.0, 0.0)),
+        Fp::from(0.5),
+        3,
+        &targets,
+    );
+
+    assert!(hits[0].is_none());
+    assert!(hits[1].is_none());
+    assert!(hits[2].is_some());
+}
+
+#[test]
+fn test_cone_cast_ray_counts() {
+    let targets = [Rect::from((10.0, -1.0, 2.0, 2.0))];
+    let origin = Vector::from((0.0, 0.0));
+    let forward = Vector::from((1.0, 0.0));
+
+    assert!(cone_cast(origin, forward, Fp::from(0.5), 0, &targets).is_empty());
+
+    let single = cone_cast(origin, forward, Fp::from(0.5), 1, &targets);
+    assert_eq!(single.len(), 1);
+    assert!(single[0].is_some());
+}
+
+#[test]
+fn test_cone_cast_edge_rays_lie_on_the_edges() {
+    // A wall that every ray of the fan hits
+    let targets = [Rect::from((10, -20, 2, 40))];
+    let origin = Vector::from((0, 0));
+    let forward = Vector::from((1, 0));
+    let half_angle = Fp::from(0.5);
+
+    // 2 * half_angle is not a multiple of three raw units, so a fixed step would not reach
+    // the last edge
+    let hits = cone_cast(origin, forward, half_angle, 4, &targets);
+    let edge_point = |angle: Fp| {
+        ray_vs_rects(origin, rotate(forward, angle), &targets)
+            .expect("should hit")
+            .1
+            .contact_point
+    };
+
+    let first = hits[0].as_ref().expect("should hit");
+    let last = hits[3].as_ref().expect("should hit");
+    assert_eq!(first.1.contact_point, edge_point(-half_angle));
+    assert_eq!(last.1.contact_point, edge_point(half_angle));
+    assert_eq!(last.1.contact_point.y, -first.1.contact_point.y);
+}
@@ -0,0 +1,119 @@
+/*
+ * Copyright (c) Peter Bjorklund. All rights reserved. https://github.com/piot/impact-rs
+ * Licensed under the MIT License. See LICENSE in the project root for license information.
+ */
+
+use fixed32::Fp;
+use fixed32_math::{Rect, Vector};
+use impact_rs::math::DEFAULT_EPSILON;
+use impact_rs::prelude::full::*;
+
+#[test]
+fn test_platform_carry_rider_on_top() {
+    let platform = Rect::from((0, 0, 10, 2));
+    let rider = Rect::from((4, 2, 2, 3));
+    let delta = Vector::from((1, 2));
+
+    assert_eq!(platform_carry(rider, platform, delta), Some(delta));
+}
+
+#[test]
+fn test_platform_carry_rider_to_the_side() {
+    let platform = Rect::from((0, 0, 10, 2));
+    let beside = Rect::from((10, 0, 2, 3));
+    let corner = Rect::from((10, 2, 2, 3));
+
+    assert_eq!(platform_carry(beside, platform, Vector::from((1, 0))), None);
+    assert_eq!(platform_carry(corner, platform, Vector::from((1, 0))), None);
+}
+
+#[test]
+fn test_platform_carry_rider_slightly_above() {
+    let platform = Rect::from((0, 0, 10, 2));
+    let delta = Vector::from((-1, 0));
+
+    let hovering = Rect::from((4.0, 2.01, 2.0, 3.0));
+    assert_eq!(platform_carry(hovering, platform, delta), Some(delta));
+
+    let jumping = Rect::from((4.0, 2.5, 2.0, 3.0));
+    assert_eq!(platform_carry(jumping, platform, delta), None);
+}
+
+#[test]
+fn test_is_grounded_tolerance() {
+    let ground = Rect::from((0, 0, 10, 2));
+    let rider = Rect::from((4.0, 2.25, 2.0, 3.0));
+
+    assert!(!is_grounded(rider, ground, Fp::from(0.125)));
+    assert!(is_grounded(rider, ground, Fp::from(0.25)));
+}
+
+#[test]
+fn test_contact_set_orders_and_queries() {
+    let mut contacts = ContactSet::new();
+    assert!(contacts.is_empty());
+    assert!(!contacts.grounded());
+    assert_eq!(contacts.strongest(), None);
+
+    contacts.push(Fp::from(0.75), 3, ContactSide::Left);
+    contacts.push(Fp::from(0.25), 1, ContactSide::Right);
+    contacts.push(Fp::from(0.5), 0, ContactSide::Top);
+    contacts.push(Fp::from(0.25), 4, ContactSide::Bottom);
+
+    assert_eq!(contacts.len(), 4);
+    let times: Vec<(Fp, usize)> = contacts
+        .iter()
+        .map(|(time, index, _)| (*time, *index))
+        .collect();
+    assert_eq!(
+        times,
+        [
+            (Fp::from(0.25), 1),
+            (Fp::from(0.25), 4),
+            (Fp::from(0.5), 0),
+            (Fp::from(0.75), 3),
+        ]
+    );
+
+    assert!(contacts.grounded());
+    assert_eq!(
+        contacts.strongest(),
+        Some(&(Fp::from(0.25), 1, ContactSide::Right))
+    );
+
+    let walls: Vec<usize> = contacts.walls().map(|(_, index, _)| *index).collect();
+    assert_eq!(walls, [1, 3]);
+
+    assert_eq!((&contacts).into_iter().count(), 4);
+}
+
+#[test]
+fn test_contact_set_from_move_and_slide() {
+    let targets = [Rect::from((0, 0, 20, 4)), Rect::from((12, 0, 2, 10))];
+
+    let contacts = move_and_slide_events(Rect::from((0, 6, 2, 2)), &targets, Vector::from((8, -4)));
+    assert!(contacts.grounded());
+    assert_eq!(contacts.walls().count(), 0);
+    assert_eq!(contacts.strongest().map(|(_, index, _)| *index), Some(0));
+
+    let blocked = move_and_slide_events(Rect::from((0, 4, 2, 2)), &targets, Vector::from((12, 0)));
+    assert!(!blocked.grounded());
+    assert_eq!(blocked.walls().count(), 1);
+}
+
+#[test]
+fn test_platform_carry_uses_default_epsilon() {
+    let platform = Rect::from((0, 0, 10, 2));
+    let delta = Vector::from((1, 0));
+
+    for gap in [DEFAULT_EPSILON, -DEFAULT_EPSILON] {
+        let rider = Rect::from((4, 2, 2, 3)).move_by(Vector::new(Fp::zero(), gap));
+        assert!(is_grounded(rider, platform, DEFAULT_EPSILON));
+        assert_eq!(platform_carry(rider, platform, delta), Some(delta));
+    }
+
+    let just_above = Rect::from((4, 2, 2, 3))
+        .move_by(Vector::new(Fp::zero(), DEFAULT_EPSILON + Fp::from_raw(1)));
+    assert!(!is_grounded(just_above, platform, DEFAULT_EPSILON));
+    assert_eq!(platform_carry(just_above, platform, delta), None);
+}
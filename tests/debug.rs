@@ -0,0 +1,83 @@
+/*
+ * Copyright (c) Peter Bjorklund. All rights reserved. https://github.com/piot/impact-rs
+ * Licensed under the MIT License. See LICENSE in the project root for license information.
+ */
+
+use fixed32::Fp;
+use fixed32_math::{Rect, Vector};
+use impact_rs::prelude::full::*;
+
+#[test]
+fn test_result_debug_lines_mark_contact_point() {
+    let ray_origin = Vector::from((0, 2));
+    let ray_direction = Vector::from((0, 8));
+    let result = ray_vs_rect(ray_origin, ray_direction, Rect::from((-2, 6, 4, 4))).unwrap();
+
+    let lines = result_debug_lines(&result, ray_origin, ray_direction);
+
+    assert_eq!(lines.len(), 4);
+    assert_eq!(lines[0], (ray_origin, Vector::from((0, 10))));
+    assert!(lines
+        .iter()
+        .any(|(start, end)| *start == result.contact_point && *end == Vector::from((0, 7))));
+    for (start, end) in &lines[2..] {
+        assert_eq!((*start + *end) / 2, result.contact_point);
+    }
+}
+
+#[test]
+fn test_ray_vs_rect_debug_times_reconstruct_closest_time() {
+    let targets = [
+        Rect::from((4, -2, 4, 4)),
+        Rect::from((-3, 5, 2, 8)),
+        Rect::from((1, 1, 6, 6)),
+        Rect::from((-1, -1, 2, 2)),
+    ];
+    let rays = [
+        (Vector::from((0, 0)), Vector::from((3, 1))),
+        (Vector::from((0, 0)), Vector::from((-1, 4))),
+        (Vector::from((0, 0)), Vector::from((0, 5))),
+    ];
+
+    for (ray_origin, ray_direction) in rays {
+        for target in targets {
+            let debug = ray_vs_rect_debug(ray_origin, ray_direction, target);
+            let expected = ray_vs_rect(ray_origin, ray_direction, target);
+
+            assert_eq!(debug.result.is_some(), expected.is_some());
+            if let (Some(result), Some(expected)) = (debug.result, expected) {
+                assert_eq!(result.closest_time, expected.closest_time);
+                assert_eq!(
+                    result.closest_time,
+                    debug.time_near.x.max(debug.time_near.y)
+                );
+                assert!(debug.time_near.x < debug.time_far.y);
+                assert!(debug.time_near.y < debug.time_far.x);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_ray_vs_rect_debug_parallel_axis() {
+    let inside = ray_vs_rect_debug(
+        Vector::from((0, 1)),
+        Vector::from((2, 0)),
+        Rect::from((4, 0, 2, 2)),
+    );
+    assert_eq!(inside.time_near, Vector::new(Fp::from(2), Fp::MIN));
+    assert_eq!(inside.time_far, Vector::new(Fp::from(3), Fp::MAX));
+    assert_eq!(
+        inside.inverted_direction,
+        Vector::new(Fp::from(0.5), Fp::MAX)
+    );
+
+    let outside = ray_vs_rect_debug(
+        Vector::from((0, 5)),
+        Vector::from((2, 0)),
+        Rect::from((4, 0, 2, 2)),
+    );
+    assert_eq!(outside.time_near.y, Fp::MAX);
+    assert_eq!(outside.time_far.y, Fp::MIN);
+    assert!(outside.result.is_none());
+}
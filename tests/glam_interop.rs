@@ -0,0 +1,46 @@
+/*
+ * Copyright (c) Peter Bjorklund. All rights reserved. https://github.com/piot/impact-rs
+ * Licensed under the MIT License. See LICENSE in the project root for license information.
+ */
+
+#![cfg(feature = "glam")]
+
+use fixed32_math::{Rect, Vector};
+use glam::Vec2;
+use impact_rs::glam_interop::{rect_from_vec2_min_max, vec2_to_vector, vector_to_vec2};
+
+const TOLERANCE: f32 = 1.0 / 65536.0;
+
+#[test]
+fn test_vec2_round_trip() {
+    for original in [
+        Vec2::new(0.0, 0.0),
+        Vec2::new(1.5, -2.25),
+        Vec2::new(123.456, -0.001),
+        Vec2::new(-3000.1, 7.77),
+    ] {
+        let round_tripped = vector_to_vec2(vec2_to_vector(original));
+        assert!((round_tripped.x - original.x).abs() <= TOLERANCE);
+        assert!((round_tripped.y - original.y).abs() <= TOLERANCE);
+    }
+}
+
+#[test]
+fn test_vector_to_vec2_is_exact() {
+    let v = Vector::from((3.25, -8.5));
+    assert_eq!(vector_to_vec2(v), Vec2::new(3.25, -8.5));
+}
+
+#[test]
+fn test_rect_from_vec2_min_max() {
+    let expected = Rect::from((1.0, 2.0, 3.0, 4.0));
+
+    assert_eq!(
+        rect_from_vec2_min_max(Vec2::new(1.0, 2.0), Vec2::new(4.0, 6.0)),
+        expected
+    );
+    assert_eq!(
+        rect_from_vec2_min_max(Vec2::new(4.0, 2.0), Vec2::new(1.0, 6.0)),
+        expected
+    );
+}
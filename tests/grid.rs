@@ -0,0 +1,67 @@
+/*
+ * Copyright (c) Peter Bjorklund. All rights reserved. https://github.com/piot/impact-rs
+ * Licensed under the MIT License. See LICENSE in the project root for license information.
+ */
+
+use fixed32::Fp;
+use fixed32_math::{Rect, Vector};
+use impact_rs::prelude::full::*;
+
+fn grid_with_neighbours() -> SpatialGrid {
+    let mut grid = SpatialGrid::new(Fp::from(16));
+    grid.insert(1, Rect::from((0, 0, 40, 8)));
+    grid.insert(2, Rect::from((20, 20, 4, 4)));
+    grid
+}
+
+#[test]
+fn test_move_collider_one_cell_matches_rebuild() {
+    let old = Rect::from((4, 4, 20, 4));
+    let new = Rect::from((20, 4, 20, 4));
+
+    let mut moved = grid_with_neighbours();
+    moved.insert(7, old);
+    moved.move_collider(7, old, new);
+
+    let mut rebuilt = grid_with_neighbours();
+    rebuilt.insert(7, old);
+    rebuilt.remove(7, old);
+    rebuilt.insert(7, new);
+
+    assert_eq!(moved, rebuilt);
+    assert_eq!(moved.cell(0, 0), &[1]);
+    assert_eq!(moved.cell(1, 0), &[1, 7]);
+    assert_eq!(moved.cell(2, 0), &[1, 7]);
+}
+
+#[test]
+fn test_move_collider_within_and_across_cells() {
+    let mut grid = grid_with_neighbours();
+    let start = Rect::from((2, 2, 4, 4));
+    grid.insert(7, start);
+
+    let nudged = Rect::from((3, 3, 4, 4));
+    grid.move_collider(7, start, nudged);
+    assert_eq!(grid.cell(0, 0), &[1, 7]);
+
+    let far = Rect::from((-40, 50, 4, 4));
+    grid.move_collider(7, nudged, far);
+
+    let mut rebuilt = grid_with_neighbours();
+    rebuilt.insert(7, far);
+    assert_eq!(grid, rebuilt);
+    assert_eq!(grid.query(Rect::from((-36, 52, 1, 1))), vec![7]);
+}
+
+#[test]
+fn test_query_swept_covers_whole_motion() {
+    let grid = grid_with_neighbours();
+    let mover = Rect::from((-40, 18, 4, 4));
+
+    assert!(grid.query_swept(mover, Vector::from((0, 0))).is_empty());
+    assert_eq!(grid.query_swept(mover, Vector::from((64, 0))), vec![2]);
+    assert_eq!(
+        grid.query_swept(mover, Vector::from((64, 0))),
+        grid.query(swept_aabb(mover, Vector::from((64, 0))))
+    );
+}
@@ -5,7 +5,9 @@
 
 use fixed32::Fp;
 use fixed32_math::{Rect, Vector};
-use impact_rs::prelude::*;
+use impact_rs::math::{approx_eq, length, Rounding};
+use impact_rs::prelude::full::*;
+use impact_rs::RayIntersectionResult;
 
 #[test]
 fn test_ray_vs_rect() {
@@ -15,5 +17,1577 @@ fn test_ray_vs_rect() {
 
     let collision_result = ray_vs_rect(ray_origin, ray_direction, target_rect);
     let ray_intersect = collision_result.expect("should have intersected");
-    assert_eq!(ray_intersect.closest_time, Fp::from(1.33332));
+    assert!(approx_eq(
+        ray_intersect.closest_time,
+        Fp::from(1.33333),
+        Fp::from(0.0001)
+    ));
+}
+
+#[test]
+fn test_ray_vs_rect_parametric_vs_distance() {
+    let ray_origin = Vector::from((0, 0));
+    let ray_direction = Vector::from((4, 3));
+    let target_rect = Rect::from((8, 0, 10, 20));
+
+    let parametric =
+        ray_vs_rect(ray_origin, ray_direction, target_rect).expect("should have intersected");
+    let distance = ray_vs_rect_distance(ray_origin, ray_direction, target_rect)
+        .expect("should have intersected");
+
+    assert_eq!(parametric.closest_time, Fp::from(2));
+    assert_eq!(distance.closest_time, Fp::from(10));
+    assert_eq!(
+        distance.closest_time,
+        parametric.closest_time * ray_direction.len()
+    );
+    assert_eq!(parametric.contact_point, distance.contact_point);
+    assert_eq!(parametric.contact_point, Vector::from((8, 6)));
+}
+
+#[test]
+fn test_ray_vs_rect_distance_unit_direction_matches_parametric() {
+    let ray_origin = Vector::from((0, 1));
+    let ray_direction = Vector::from((1, 0));
+    let target_rect = Rect::from((5, 0, 2, 2));
+
+    let parametric =
+        ray_vs_rect(ray_origin, ray_direction, target_rect).expect("should have intersected");
+    let distance = ray_vs_rect_distance(ray_origin, ray_direction, target_rect)
+        .expect("should have intersected");
+
+    assert_eq!(parametric.closest_time, distance.closest_time);
+    assert_eq!(distance.closest_time, Fp::from(5));
+}
+
+#[test]
+fn test_ray_vs_rect_distance_long_direction() {
+    // Squaring the length of these directions does not fit in an `Fp`
+    let distance = ray_vs_rect_distance(
+        Vector::from((0, 1)),
+        Vector::from((200, 0)),
+        Rect::from((150, 0, 2, 2)),
+    )
+    .expect("should have intersected");
+    assert_eq!(distance.closest_time, Fp::from(150));
+
+    let distance = ray_vs_rect_distance(
+        Vector::from((0, 0)),
+        Vector::from((-300, 400)),
+        Rect::from((-60, 40, 200, 200)),
+    )
+    .expect("should have intersected");
+    // The time 1/10 is not exact in fixed point, and the error is scaled up by the length
+    assert!(approx_eq(
+        distance.closest_time,
+        Fp::from(50),
+        Fp::from(0.01)
+    ));
+}
+
+#[test]
+fn test_thick_ray_clips_target_thin_ray_misses_horizontal() {
+    let origin = Vector::from((0, 0));
+    let direction = Vector::from((1, 0));
+    let target_rect = Rect::from((10, 1, 2, 2));
+
+    assert!(ray_vs_rect(origin, direction, target_rect).is_none());
+    assert!(thick_ray_vs_rect(origin, direction, Fp::from(1), target_rect).is_none());
+
+    let result = thick_ray_vs_rect(origin, direction, Fp::from(4), target_rect)
+        .expect("thick ray should clip the target");
+    assert_eq!(result.closest_time, Fp::from(10));
+    assert_eq!(result.contact_normal, Vector::right());
+}
+
+#[test]
+fn test_thick_ray_clips_target_thin_ray_misses_vertical() {
+    let origin = Vector::from((0, 0));
+    let direction = Vector::from((0, -1));
+    let target_rect = Rect::from((-3, -10, 2, 2));
+
+    assert!(ray_vs_rect(origin, direction, target_rect).is_none());
+
+    let result = thick_ray_vs_rect(origin, direction, Fp::from(4), target_rect)
+        .expect("thick ray should clip the target");
+    assert_eq!(result.closest_time, Fp::from(8));
+    assert_eq!(result.contact_normal, Vector::down());
+}
+
+#[test]
+fn test_thick_ray_clips_target_thin_ray_misses_diagonal() {
+    let origin = Vector::from((0, 0));
+    let direction = Vector::from((1, 1));
+    let target_rect = Rect::from((10, 6, 2, 2));
+
+    assert!(ray_vs_rect(origin, direction, target_rect).is_none());
+    assert!(thick_ray_vs_rect(origin, direction, Fp::from(1), target_rect).is_none());
+    assert!(thick_ray_vs_rect(origin, direction, Fp::from(8), target_rect).is_some());
+}
+
+#[test]
+fn test_thick_ray_long_direction() {
+    let origin = Vector::from((0, 0));
+
+    // The squared length of these directions does not fit in an `Fp`
+    let cases = [
+        (Vector::from((300, 0)), Rect::from((250, 1, 2, 2))),
+        (Vector::from((0, 300)), Rect::from((1, 250, 2, 2))),
+    ];
+
+    for (direction, target_rect) in cases {
+        assert!(thick_ray_vs_rect(origin, direction, Fp::from(1), target_rect).is_none());
+
+        let result = thick_ray_vs_rect(origin, direction, Fp::from(4), target_rect)
+            .expect("thick ray should clip the target");
+        assert!(approx_eq(
+            result.closest_time,
+            Fp::from(250.0 / 300.0),
+            Fp::from_raw(1)
+        ));
+    }
+
+    // A diagonal beam passing 2.5 units below a target needs a thickness of at least 4
+    let direction = Vector::from((200, -150));
+    let target_rect = Rect::from((160.0, -117.5, 2.0, 1.5));
+    assert!(thick_ray_vs_rect(origin, direction, Fp::from(3), target_rect).is_none());
+    assert!(thick_ray_vs_rect(origin, direction, Fp::from(5), target_rect).is_some());
+}
+
+#[test]
+fn test_thick_ray_zero_thickness_matches_ray() {
+    let origin = Vector::from((1, 2));
+    let direction = Vector::from((3, 4));
+    let target_rect = Rect::from((5, 6, 7, 8));
+
+    let thin = ray_vs_rect(origin, direction, target_rect).expect("should have intersected");
+    let thick = thick_ray_vs_rect(origin, direction, Fp::zero(), target_rect)
+        .expect("should have intersected");
+
+    assert_eq!(thin.closest_time, thick.closest_time);
+    assert_eq!(thin.contact_point, thick.contact_point);
+}
+
+/// Small deterministic generator, so the tests do not need a random number crate.
+struct Lcg(u32);
+
+impl Lcg {
+    fn next_in(&mut self, min: i16, max: i16) -> Fp {
+        self.0 = self.0.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+        let span = i32::from(max - min) * Fp::SCALE;
+        Fp::from(min) + Fp::from_raw((self.0 >> 8) as i32 % span)
+    }
+}
+
+/// The sweep without the bounding box early-out.
+fn swept_rect_vs_rect_reference(
+    origin: Rect,
+    target: Rect,
+    delta: Vector,
+) -> Option<RayIntersectionResult> {
+    let expanded_target = Rect {
+        pos: target.pos,
+        size: target.size + origin.size,
+    };
+    ray_vs_rect(origin.pos + origin.size, delta, expanded_target)
+        .filter(|result| result.closest_time >= Fp::zero() && result.closest_time < Fp::one())
+}
+
+#[test]
+fn test_swept_rect_vs_rect_early_out_keeps_results() {
+    let mut random = Lcg(42);
+
+    for _ in 0..2000 {
+        let origin = Rect::new(
+            Vector::new(random.next_in(-20, 20), random.next_in(-20, 20)),
+            Vector::new(random.next_in(1, 5), random.next_in(1, 5)),
+        );
+        let target = Rect::new(
+            Vector::new(random.next_in(-20, 20), random.next_in(-20, 20)),
+            Vector::new(random.next_in(1, 8), random.next_in(1, 8)),
+        );
+        let delta = Vector::new(random.next_in(-30, 30), random.next_in(-30, 30));
+
+        let expected = swept_rect_vs_rect_reference(origin, target, delta);
+        let actual = swept_rect_vs_rect(origin, target, delta);
+
+        assert_eq!(
+            expected.as_ref().map(|result| result.closest_time),
+            actual.as_ref().map(|result| result.closest_time),
+            "origin {origin:?} target {target:?} delta {delta:?}"
+        );
+        assert_eq!(
+            expected.map(|result| result.contact_normal),
+            actual.map(|result| result.contact_normal)
+        );
+    }
+}
+
+#[test]
+fn test_swept_rect_vs_rect_early_out_far_targets() {
+    // Every target is out of reach of the motion, so all of these are rejected by the
+    // bounding box check without running the slab test.
+    let origin = Rect::from((0, 0, 2, 2));
+    let delta = Vector::from((5, 3));
+
+    for offset in 0..1000 {
+        let far = i16::try_from(offset % 100).expect("fits") + 20;
+        let target = Rect::from((far, -far, 4, 4));
+        assert!(swept_rect_vs_rect(origin, target, delta).is_none());
+    }
+}
+
+#[test]
+fn test_ray_vs_rect_face_matches_normal() {
+    let target = Rect::from((-2, -2, 4, 4));
+    let cases = [
+        (
+            Vector::from((-10, 0)),
+            Vector::from((1, 0)),
+            ContactSide::Right,
+        ),
+        (
+            Vector::from((10, 1)),
+            Vector::from((-1, 0)),
+            ContactSide::Left,
+        ),
+        (
+            Vector::from((1, -10)),
+            Vector::from((0, 2)),
+            ContactSide::Top,
+        ),
+        (
+            Vector::from((0, 10)),
+            Vector::from((0, -1)),
+            ContactSide::Bottom,
+        ),
+        (
+            Vector::from((-10, -5)),
+            Vector::from((4, 3)),
+            ContactSide::Right,
+        ),
+    ];
+
+    for (origin, direction, expected_face) in cases {
+        let result = ray_vs_rect(origin, direction, target).expect("should have intersected");
+        assert_eq!(result.face, expected_face);
+        assert_eq!(ContactSide::from_normal(result.contact_normal), result.face);
+    }
+}
+
+#[test]
+fn test_ray_vs_rect_corner_hit_face() {
+    let result = ray_vs_rect(
+        Vector::from((0, 0)),
+        Vector::from((1, 1)),
+        Rect::from((5, 5, 10, 10)),
+    )
+    .expect("should have intersected");
+
+    assert_eq!(result.face, ContactSide::Corner);
+}
+
+#[test]
+fn test_swept_rect_vs_rect_inclusive_catches_contact_at_end() {
+    let origin = Rect::from((0, 0, 2, 2));
+    let target = Rect::from((11, 0, 2, 2));
+    // The motion ends exactly at the contact
+    let delta = Vector::from((9, 0));
+
+    assert!(swept_rect_vs_rect(origin, target, delta).is_none());
+
+    let result =
+        swept_rect_vs_rect_inclusive(origin, target, delta).expect("should catch the contact");
+    assert_eq!(result.closest_time, Fp::one());
+    assert_eq!(result.contact_normal, Vector::right());
+}
+
+#[test]
+fn test_swept_rect_vs_rect_inclusive_matches_before_end() {
+    let origin = Rect::from((0, 0, 2, 2));
+    let target = Rect::from((11, 0, 2, 2));
+    let delta = Vector::from((16, 0));
+
+    let half_open = swept_rect_vs_rect(origin, target, delta).expect("should hit");
+    let inclusive = swept_rect_vs_rect_inclusive(origin, target, delta).expect("should hit");
+    assert_eq!(half_open.closest_time, inclusive.closest_time);
+
+    assert!(swept_rect_vs_rect_inclusive(origin, target, Vector::from((4, 0))).is_none());
+}
+
+#[test]
+fn test_ray_vs_rect_divides_without_reciprocal_error() {
+    let ray_origin = Vector::from((0, 0));
+    let ray_direction = Vector::from((3, 0));
+    let target_rect = Rect::from((300, -1, 10, 2));
+
+    // Multiplying by the rounded reciprocal of 3 undershoots 100 by almost 0.002
+    let reciprocal_time = Fp::from(300) * (Fp::one() / Fp::from(3));
+    assert!(reciprocal_time < Fp::from(99.999));
+
+    let result =
+        ray_vs_rect(ray_origin, ray_direction, target_rect).expect("should have intersected");
+    assert_eq!(result.closest_time, Fp::from(100));
+    assert_eq!(result.contact_point, Vector::from((300, 0)));
+}
+
+#[test]
+fn test_ray_vs_rect_with_corner_policies() {
+    let ray_origin = Vector::from((0, 0));
+    let ray_direction = Vector::from((1, 1));
+    let target = Rect::from((5, 5, 10, 10));
+
+    let normal_for = |policy| {
+        let result = ray_vs_rect_with(ray_origin, ray_direction, target, policy)
+            .expect("should have intersected");
+        assert_eq!(result.face, ContactSide::Corner);
+        assert_eq!(result.closest_time, Fp::from(5));
+        result.contact_normal
+    };
+
+    assert_eq!(normal_for(CornerPolicy::PreferX), Vector::right());
+    assert_eq!(normal_for(CornerPolicy::PreferY), Vector::up());
+    assert_eq!(normal_for(CornerPolicy::None), Vector::default());
+
+    let diagonal = normal_for(CornerPolicy::Diagonal);
+    assert_eq!(diagonal.x, diagonal.y);
+    assert!(approx_eq(
+        diagonal.x,
+        Fp::from(std::f32::consts::FRAC_1_SQRT_2),
+        Fp::from(0.001)
+    ));
+}
+
+#[test]
+fn test_ray_vs_rect_with_default_policy_matches_ray_vs_rect() {
+    let ray_origin = Vector::from((20, 20));
+    let ray_direction = Vector::from((-1, -1));
+    let target = Rect::from((5, 5, 10, 10));
+
+    let plain = ray_vs_rect(ray_origin, ray_direction, target).expect("should have intersected");
+    let with_default = ray_vs_rect_with(ray_origin, ray_direction, target, CornerPolicy::default())
+        .expect("should have intersected");
+
+    assert_eq!(plain.contact_normal, with_default.contact_normal);
+    assert_eq!(plain.contact_normal, Vector::default());
+
+    let diagonal = ray_vs_rect_with(ray_origin, ray_direction, target, CornerPolicy::Diagonal)
+        .expect("should have intersected");
+    assert!(diagonal.contact_normal.x < Fp::zero());
+    assert_eq!(diagonal.contact_normal.x, diagonal.contact_normal.y);
+}
+
+#[test]
+fn test_ray_vs_rect_forward_target_behind_origin() {
+    let ray_origin = Vector::from((10, 0));
+    let ray_direction = Vector::from((1, 0));
+    let target = Rect::from((0, -1, 4, 2));
+
+    assert!(ray_vs_rect(ray_origin, ray_direction, target).is_none());
+    assert!(ray_vs_rect_forward(ray_origin, ray_direction, target).is_none());
+}
+
+#[test]
+fn test_ray_vs_rect_forward_target_straddling_origin() {
+    let ray_direction = Vector::from((1, 0));
+    let target = Rect::from((0, -1, 4, 2));
+
+    let inside =
+        ray_vs_rect(Vector::from((1, 0)), ray_direction, target).expect("start inside is reported");
+    assert_eq!(inside.closest_time, Fp::from(-1));
+    assert!(ray_vs_rect_forward(Vector::from((1, 0)), ray_direction, target).is_none());
+
+    let on_edge = ray_vs_rect_forward(Vector::from((0, 0)), ray_direction, target)
+        .expect("start on the near edge is reported");
+    assert_eq!(on_edge.closest_time, Fp::zero());
+}
+
+#[test]
+fn test_swept_rect_overlap_interval_glancing_pass() {
+    let origin = Rect::from((0, 0, 2, 2));
+    let delta = Vector::from((10, 10));
+    // The mover passes diagonally and only clips the lower left corner of the target
+    let target = Rect::from((5, 2, 2, 2));
+
+    assert_eq!(
+        swept_rect_overlap_interval(origin, target, delta),
+        Some((Fp::from(0.3), Fp::from(0.4)))
+    );
+
+    // The path passes above a target further to the right without overlapping it
+    assert!(swept_rect_overlap_interval(origin, Rect::from((7, 1, 2, 2)), delta).is_none());
+}
+
+#[test]
+fn test_swept_rect_overlap_interval_full_traversal() {
+    let origin = Rect::from((0, 0, 2, 2));
+    let target = Rect::from((4, -1, 2, 4));
+    let delta = Vector::from((10, 0));
+
+    assert_eq!(
+        swept_rect_overlap_interval(origin, target, delta),
+        Some((Fp::from(0.2), Fp::from(0.6)))
+    );
+
+    // Starting inside and moving slowly keeps the rectangles overlapped for the whole motion
+    assert_eq!(
+        swept_rect_overlap_interval(Rect::from((4, 0, 2, 2)), target, Vector::from((-1, 0))),
+        Some((Fp::zero(), Fp::one()))
+    );
+    assert_eq!(
+        swept_rect_overlap_interval(Rect::from((4, 0, 1, 1)), target, Vector::default()),
+        Some((Fp::zero(), Fp::one()))
+    );
+}
+
+#[test]
+fn test_ray_vs_rect_normalized_reports_world_distance() {
+    let ray_origin = Vector::from((0, 0));
+    let target = Rect::from((12, -20, 10, 40));
+
+    // The direction is five units long, so the parametric time would be 12 / 4 = 3
+    let direction = Vector::from((4, 3));
+    let parametric = ray_vs_rect(ray_origin, direction, target).expect("should hit");
+    assert_eq!(parametric.closest_time, Fp::from(3));
+
+    let result = ray_vs_rect_normalized(ray_origin, direction, Fp::from(20), target)
+        .expect("should hit within length");
+    assert!(approx_eq(
+        result.closest_time,
+        Fp::from(15),
+        Fp::from(0.001)
+    ));
+    assert!(approx_eq(
+        result.contact_point.x,
+        Fp::from(12),
+        Fp::from(0.001)
+    ));
+
+    let longer = ray_vs_rect_normalized(ray_origin, direction * 10, Fp::from(20), target)
+        .expect("direction length does not matter");
+    assert_eq!(longer.closest_time, result.closest_time);
+}
+
+#[test]
+fn test_ray_vs_rect_normalized_long_direction() {
+    let ray_origin = Vector::from((0, 0));
+    let target = Rect::from((250, -1, 2, 2));
+
+    // Squaring the length of these directions does not fit in an `Fp`
+    for direction in [Vector::from((300, 0)), Vector::from((30000, 0))] {
+        let result = ray_vs_rect_normalized(ray_origin, direction, Fp::from(260), target)
+            .expect("should hit within length");
+        assert_eq!(result.closest_time, Fp::from(250));
+        assert_eq!(result.contact_point, Vector::from((250, 0)));
+    }
+
+    let diagonal = Vector::from((-300, 400));
+    let result = ray_vs_rect_normalized(
+        ray_origin,
+        diagonal,
+        Fp::from(100),
+        Rect::from((-60, 40, 200, 200)),
+    )
+    .expect("should hit within length");
+    assert!(approx_eq(
+        result.closest_time,
+        Fp::from(50),
+        Fp::from(0.001)
+    ));
+}
+
+#[test]
+fn test_ray_vs_rect_normalized_length_and_zero_direction() {
+    let ray_origin = Vector::from((0, 0));
+    let target = Rect::from((12, -20, 10, 40));
+    let direction = Vector::from((4, 3));
+
+    assert!(ray_vs_rect_normalized(ray_origin, direction, Fp::from(14), target).is_none());
+    assert!(ray_vs_rect_normalized(ray_origin, Vector::default(), Fp::from(20), target).is_none());
+}
+
+#[test]
+fn test_remaining_plus_consumed_is_delta() {
+    let origin = Rect::from((0, 0, 2, 2));
+    let target = Rect::from((6, 3, 4, 4));
+    let delta = Vector::from((8, 4));
+
+    let result = swept_rect_vs_rect(origin, target, delta).expect("should hit");
+    assert_eq!(result.closest_time, Fp::from(0.5));
+
+    let consumed = delta * result.closest_time;
+    assert_eq!(consumed + result.remaining(delta), delta);
+    assert_eq!(result.remaining(delta), Vector::from((4, 2)));
+}
+
+#[test]
+fn test_swept_rect_vs_rect_matches_horizontal_time() {
+    let origin = Rect::from((0, 0, 2, 2));
+
+    // Target y from -5 to 3 covers missing below, resting flush on top of the target at -4,
+    // overlapping, touching the top of the mover at 2 and missing above
+    for target_y in -5..=3 {
+        for target_x in -12..=12 {
+            let target = Rect::from((target_x, target_y, 3, 4));
+            for x_delta in (-16..=16).filter(|x_delta| *x_delta != 0) {
+                let horizontal =
+                    swept_rect_vs_rect_horizontal_time(origin, target, Fp::from(x_delta));
+                let swept = swept_rect_vs_rect(origin, target, Vector::from((x_delta, 0)))
+                    .map(|result| result.closest_time);
+
+                assert_eq!(
+                    swept, horizontal,
+                    "target ({target_x}, {target_y}) delta {x_delta}"
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn test_ray_vs_swept_rect_drifts_into_ray() {
+    let ray_origin = Vector::from((0, 0));
+    let ray_direction = Vector::from((8, 0));
+    let drifting = Rect::from((4, 4, 2, 2));
+
+    let (time, result) =
+        ray_vs_swept_rect(ray_origin, ray_direction, drifting, Vector::from((0, -8)))
+            .expect("should touch the ray");
+    assert_eq!(time, Fp::from(0.5));
+    assert_eq!(result.closest_time, Fp::from(0.5));
+    assert_eq!(result.contact_point, Vector::from((4, 0)));
+    assert_eq!(result.contact_normal, Vector::right());
+
+    // Drifting along the ray, but above it
+    assert!(
+        ray_vs_swept_rect(ray_origin, ray_direction, drifting, Vector::from((-8, 0))).is_none()
+    );
+    // Stopping short of the ray
+    assert!(
+        ray_vs_swept_rect(ray_origin, ray_direction, drifting, Vector::from((0, -2))).is_none()
+    );
+}
+
+#[test]
+fn test_ray_vs_swept_rect_long_ray_and_motion() {
+    let ray_origin = Vector::from((0, 0));
+    let ray_direction = Vector::from((300, 0));
+    let falling = Rect::from((100, 200, 2, 2));
+
+    let (time, result) =
+        ray_vs_swept_rect(ray_origin, ray_direction, falling, Vector::from((0, -300)))
+            .expect("should touch the ray");
+    assert!(approx_eq(time, Fp::from(0.667), Fp::from(0.001)));
+    assert!(approx_eq(
+        result.contact_point.x,
+        Fp::from(100),
+        Fp::from(0.01)
+    ));
+    assert_eq!(result.contact_normal, Vector::right());
+}
+
+#[test]
+fn test_ray_vs_swept_rect_past_ray_end_and_already_crossing() {
+    let ray_origin = Vector::from((0, 0));
+    let ray_direction = Vector::from((8, 0));
+
+    // Slides in from the right and reaches the end of the ray first
+    let (time, result) = ray_vs_swept_rect(
+        ray_origin,
+        ray_direction,
+        Rect::from((12, -1, 2, 2)),
+        Vector::from((-8, 0)),
+    )
+    .expect("should touch the ray end");
+    assert_eq!(time, Fp::from(0.5));
+    assert_eq!(result.closest_time, Fp::one());
+
+    let (time, result) = ray_vs_swept_rect(
+        ray_origin,
+        ray_direction,
+        Rect::from((2, -1, 2, 2)),
+        Vector::from((0, 8)),
+    )
+    .expect("should already cross the ray");
+    assert_eq!(time, Fp::zero());
+    assert_eq!(result.contact_point, Vector::from((2, 0)));
+}
+
+#[test]
+fn test_ray_vs_rects_batch_matches_per_target_calls() {
+    let mut random = Lcg(7);
+
+    for _ in 0..500 {
+        let ray_origin = Vector::new(random.next_in(-20, 20), random.next_in(-20, 20));
+        // Some directions are axis aligned, to cover the parallel slabs
+        let kind = random.next_in(0, 3);
+        let ray_direction = if kind < Fp::one() {
+            Vector::new(random.next_in(-5, 5), Fp::zero())
+        } else if kind < Fp::from(2) {
+            Vector::new(Fp::zero(), random.next_in(-5, 5))
+        } else {
+            Vector::new(random.next_in(-5, 5), random.next_in(-5, 5))
+        };
+        let targets: Vec<Rect> = (0..8)
+            .map(|_| {
+                Rect::new(
+                    Vector::new(random.next_in(-20, 20), random.next_in(-20, 20)),
+                    Vector::new(random.next_in(-6, 6), random.next_in(-6, 6)),
+                )
+            })
+            .collect();
+
+        let mut expected: Option<(usize, RayIntersectionResult)> = None;
+        for (index, target) in targets.iter().enumerate() {
+            if let Some(result) = ray_vs_rect(ray_origin, ray_direction, *target) {
+                if expected
+                    .as_ref()
+                    .is_none_or(|(_, best)| result.closest_time < best.closest_time)
+                {
+                    expected = Some((index, result));
+                }
+            }
+        }
+
+        let actual = ray_vs_rects(ray_origin, ray_direction, &targets);
+        assert_eq!(
+            actual.as_ref().map(|(index, _)| *index),
+            expected.as_ref().map(|(index, _)| *index)
+        );
+        if let (Some((_, actual)), Some((_, expected))) = (actual, expected) {
+            assert_eq!(actual.closest_time, expected.closest_time);
+            assert_eq!(actual.contact_point, expected.contact_point);
+            assert_eq!(actual.contact_normal, expected.contact_normal);
+            assert_eq!(actual.face, expected.face);
+        }
+    }
+}
+
+#[test]
+fn test_target_face_is_opposite_of_mover_side() {
+    let origin = Rect::from((0, 0, 2, 2));
+    let target = Rect::from((6, -2, 4, 6));
+
+    let result = swept_rect_vs_rect(origin, target, Vector::from((8, 0))).expect("should hit");
+    assert_eq!(result.face, ContactSide::Right);
+    assert_eq!(target_face(&result), ContactSide::Left);
+    assert_eq!(
+        ContactSide::from_normal(-result.contact_normal),
+        target_face(&result)
+    );
+
+    let corner = ray_vs_rect(
+        Vector::from((0, 0)),
+        Vector::from((1, 1)),
+        Rect::from((5, 5, 10, 10)),
+    )
+    .expect("should hit the corner");
+    assert_eq!(target_face(&corner), ContactSide::Corner);
+}
+
+#[test]
+fn test_boundary_mode_ray_grazing_shared_edge() {
+    // Two tiles sharing the edge x = 1; the ray passes through its top end point, touching
+    // the left tile and entering the right one
+    let left = Rect::from((0, 0, 1, 1));
+    let right = Rect::from((1, 0, 1, 1));
+    let ray_origin = Vector::from((0, 2));
+    let ray_direction = Vector::from((2, -2));
+
+    assert!(
+        ray_vs_rect_with_boundary(ray_origin, ray_direction, left, BoundaryMode::HalfOpen)
+            .is_none()
+    );
+    let touch = ray_vs_rect_with_boundary(ray_origin, ray_direction, left, BoundaryMode::Closed)
+        .expect("closed boundary should report the touch");
+    assert_eq!(touch.closest_time, Fp::from(0.5));
+    assert_eq!(touch.contact_point, Vector::from((1, 1)));
+
+    for mode in [BoundaryMode::HalfOpen, BoundaryMode::Closed] {
+        let entered = ray_vs_rect_with_boundary(ray_origin, ray_direction, right, mode)
+            .expect("the ray enters the right tile");
+        assert_eq!(entered.closest_time, Fp::from(0.5));
+        assert_eq!(entered.face, ContactSide::Corner);
+    }
+
+    // The default mode is the behavior of ray_vs_rect
+    assert!(ray_vs_rect(ray_origin, ray_direction, left).is_none());
+    assert_eq!(BoundaryMode::default(), BoundaryMode::HalfOpen);
+}
+
+#[test]
+fn test_boundary_mode_ray_along_shared_edge() {
+    // A ray running along the shared edge y = 1 is parallel to it, so both tiles are
+    // reported in either mode
+    let below = Rect::from((2, 0, 1, 1));
+    let above = Rect::from((2, 1, 1, 1));
+
+    for mode in [BoundaryMode::HalfOpen, BoundaryMode::Closed] {
+        for tile in [below, above] {
+            let result =
+                ray_vs_rect_with_boundary(Vector::from((0, 1)), Vector::from((1, 0)), tile, mode)
+                    .expect("should hit");
+            assert_eq!(result.closest_time, Fp::from(2));
+        }
+    }
+}
+
+#[test]
+fn test_result_bytes_round_trip() {
+    let result = RayIntersectionResult {
+        contact_point: Vector::new(Fp::from(-12.25), Fp::from(30000)),
+        contact_normal: Vector::down(),
+        closest_time: Fp::from_raw(-3),
+        face: ContactSide::Bottom,
+        frame_fraction: None,
+    };
+
+    let bytes = result.to_bytes();
+    assert_eq!(bytes[0..4], Fp::from(-12.25).inner().to_le_bytes());
+    assert_eq!(bytes[20], 3);
+
+    let unpacked = RayIntersectionResult::from_bytes(&bytes).expect("should unpack");
+    assert_eq!(unpacked.contact_point, result.contact_point);
+    assert_eq!(unpacked.contact_normal, result.contact_normal);
+    assert_eq!(unpacked.closest_time, result.closest_time);
+    assert_eq!(unpacked.face, result.face);
+    assert_eq!(unpacked.frame_fraction, None);
+
+    let swept = RayIntersectionResult {
+        frame_fraction: Some(Fp::from(0.75)),
+        ..result
+    };
+    let swept_bytes = swept.to_bytes();
+    assert_eq!(swept_bytes[..21], bytes[..21]);
+    assert_eq!(swept_bytes[21], 1);
+    assert_eq!(
+        RayIntersectionResult::from_bytes(&swept_bytes)
+            .expect("should unpack")
+            .frame_fraction,
+        Some(Fp::from(0.75))
+    );
+
+    let mut padded = bytes.to_vec();
+    padded.extend_from_slice(&[0xff; 3]);
+    assert!(RayIntersectionResult::from_bytes(&padded).is_some());
+}
+
+#[test]
+fn test_result_from_bytes_rejects_truncated_or_invalid_input() {
+    let bytes = ray_vs_rect(
+        Vector::from((0, 0)),
+        Vector::from((1, 1)),
+        Rect::from((5, 5, 10, 10)),
+    )
+    .expect("should hit the corner")
+    .to_bytes();
+    assert_eq!(bytes[20], 4);
+
+    assert!(RayIntersectionResult::from_bytes(&bytes[..20]).is_none());
+    assert!(
+        RayIntersectionResult::from_bytes(&bytes[..RayIntersectionResult::PACKED_SIZE - 1])
+            .is_none()
+    );
+    assert!(RayIntersectionResult::from_bytes(&[]).is_none());
+
+    let mut invalid_face = bytes;
+    invalid_face[20] = 5;
+    assert!(RayIntersectionResult::from_bytes(&invalid_face).is_none());
+
+    let mut invalid_flag = bytes;
+    invalid_flag[21] = 2;
+    assert!(RayIntersectionResult::from_bytes(&invalid_flag).is_none());
+}
+
+#[test]
+fn test_ray_vs_rect_oneway_hits_solid_face() {
+    let platform = Rect::from((0, 0, 10, 2));
+
+    let result = ray_vs_rect_oneway(
+        Vector::from((3, 6)),
+        Vector::from((1, -2)),
+        platform,
+        Vector::up(),
+    )
+    .expect("falling onto the top should hit");
+    assert_eq!(result.closest_time, Fp::from(2));
+    assert_eq!(result.contact_point, Vector::from((5, 2)));
+    assert_eq!(result.face, ContactSide::Bottom);
+
+    // A wall that is only solid on its left face
+    let wall = Rect::from((8, -5, 2, 10));
+    assert!(ray_vs_rect_oneway(
+        Vector::from((0, 0)),
+        Vector::from((1, 0)),
+        wall,
+        Vector::left()
+    )
+    .is_some());
+}
+
+#[test]
+fn test_ray_vs_rect_oneway_passes_through_from_behind() {
+    let platform = Rect::from((0, 0, 10, 2));
+
+    // From below, from the side, and from inside the platform
+    assert!(ray_vs_rect_oneway(
+        Vector::from((5, -3)),
+        Vector::from((0, 1)),
+        platform,
+        Vector::up()
+    )
+    .is_none());
+    assert!(ray_vs_rect_oneway(
+        Vector::from((-4, 1)),
+        Vector::from((1, 0)),
+        platform,
+        Vector::up()
+    )
+    .is_none());
+    assert!(ray_vs_rect_oneway(
+        Vector::from((5, 1)),
+        Vector::from((0, -1)),
+        platform,
+        Vector::up()
+    )
+    .is_none());
+
+    // Moving down and to the right into the left face is not an approach to the top
+    assert!(ray_vs_rect_oneway(
+        Vector::from((-4.0, 1.5)),
+        Vector::from((2.0, -0.25)),
+        platform,
+        Vector::up()
+    )
+    .is_none());
+}
+
+#[test]
+fn test_ray_vs_rects_all_into_matches_allocating_version() {
+    let targets = [
+        Rect::from((12, -2, 2, 4)),
+        Rect::from((-3, -3, 6, 6)),
+        Rect::from((0, 20, 4, 4)),
+        Rect::from((6, -1, 1, 8)),
+        Rect::from((12, 0, 3, 3)),
+    ];
+    let ray_origin = Vector::from((0, 0));
+    let ray_direction = Vector::from((1, 0));
+
+    let expected = ray_vs_rects_all(ray_origin, ray_direction, &targets);
+    let order: Vec<usize> = expected.iter().map(|(index, _)| *index).collect();
+    assert_eq!(order, [1, 3, 0, 4]);
+
+    let mut buffer = vec![(
+        99,
+        ray_vs_rect(ray_origin, ray_direction, targets[0]).expect("should hit"),
+    )];
+    for _ in 0..2 {
+        let count = ray_vs_rects_all_into(ray_origin, ray_direction, &targets, &mut buffer);
+        assert_eq!(count, expected.len());
+        assert_eq!(buffer.len(), expected.len());
+
+        for ((index, actual), (expected_index, expected)) in buffer.iter().zip(&expected) {
+            assert_eq!(index, expected_index);
+            assert_eq!(actual.closest_time, expected.closest_time);
+            assert_eq!(actual.contact_point, expected.contact_point);
+            assert_eq!(actual.face, expected.face);
+        }
+    }
+
+    let missed = ray_vs_rects_all_into(
+        ray_origin,
+        Vector::from((0, -1)),
+        &targets[2..3],
+        &mut buffer,
+    );
+    assert_eq!(missed, 0);
+    assert!(buffer.is_empty());
+}
+
+#[test]
+fn test_ray_vs_rect_in_space_same_numbers() {
+    let target = Rect::from((-2, 6, 4, 2));
+    let ray_origin = Vector::from((0, 0));
+    let ray_direction = Vector::from((0, 2));
+
+    let y_up = ray_vs_rect_in_space(ray_origin, ray_direction, target, CoordinateSpace::YUp)
+        .expect("should hit");
+    let y_down = ray_vs_rect_in_space(ray_origin, ray_direction, target, CoordinateSpace::YDown)
+        .expect("should hit");
+
+    assert_eq!(y_up.face, ContactSide::Top);
+    assert_eq!(y_down.face, ContactSide::Bottom);
+    assert_eq!(y_up.contact_normal, y_down.contact_normal);
+    assert_eq!(y_up.closest_time, y_down.closest_time);
+    assert_eq!(y_up.contact_point, y_down.contact_point);
+
+    // Horizontal faces are the same in both spaces
+    let side = ray_vs_rect_in_space(
+        ray_origin,
+        Vector::from((1, 0)),
+        Rect::from((3, -1, 2, 2)),
+        CoordinateSpace::YDown,
+    )
+    .expect("should hit");
+    assert_eq!(side.face, ContactSide::Right);
+}
+
+#[test]
+fn test_ray_vs_rect_in_space_same_geometry() {
+    // A ray falling onto a floor, described with the y-axis up and with the y-axis down
+    let y_up = ray_vs_rect_in_space(
+        Vector::from((5, 10)),
+        Vector::from((0, -1)),
+        Rect::from((0, 0, 20, 2)),
+        CoordinateSpace::YUp,
+    )
+    .expect("should hit");
+    let y_down = ray_vs_rect_in_space(
+        Vector::from((5, -10)),
+        Vector::from((0, 1)),
+        Rect::from((0, -2, 20, 2)),
+        CoordinateSpace::YDown,
+    )
+    .expect("should hit");
+
+    assert_eq!(y_up.face, ContactSide::Bottom);
+    assert_eq!(y_down.face, ContactSide::Bottom);
+    assert_eq!(y_up.contact_normal, Vector::down());
+    assert_eq!(y_down.contact_normal, -Vector::down());
+    assert_eq!(y_up.closest_time, y_down.closest_time);
+    assert_eq!(CoordinateSpace::default(), CoordinateSpace::YUp);
+}
+
+#[test]
+fn test_is_moving_toward_approaching_receding_parallel() {
+    let origin = Rect::from((0, 0, 2, 2));
+    let target = Rect::from((6, 0, 2, 2));
+
+    assert!(is_moving_toward(origin, target, Vector::from((3, 0))));
+    assert!(is_moving_toward(origin, target, Vector::from((3, -7))));
+    assert!(!is_moving_toward(origin, target, Vector::from((-3, 0))));
+    assert!(!is_moving_toward(origin, target, Vector::from((0, 5))));
+
+    // Touching along an edge, sliding past it
+    let beside = Rect::from((2, 4, 2, 2));
+    assert!(is_moving_toward(origin, beside, Vector::from((0, 5))));
+    assert!(!is_moving_toward(origin, beside, Vector::from((-1, 5))));
+}
+
+#[test]
+fn test_is_moving_toward_never_skips_a_contact() {
+    let origin = Rect::from((0, 0, 2, 2));
+    let mut targets = Vec::new();
+    for y in -3..=3 {
+        for x in -3..=3 {
+            targets.push(Rect::from((x * 2, y * 2, 2, 2)));
+            targets.push(Rect::from((x * 3 + 1, y * 3 - 1, 1, 3)));
+        }
+    }
+
+    for dy in -4..=4 {
+        for dx in -4..=4 {
+            let delta = Vector::from((dx * 3, dy * 2));
+
+            for target in &targets {
+                if !is_moving_toward(origin, *target, delta) {
+                    assert!(
+                        swept_rect_vs_rect(origin, *target, delta).is_none(),
+                        "{target:?} {delta:?}"
+                    );
+                }
+            }
+
+            let expected = targets
+                .iter()
+                .enumerate()
+                .filter_map(|(index, target)| {
+                    swept_rect_vs_rect(origin, *target, delta).map(|result| (index, result))
+                })
+                .min_by_key(|(_, result)| result.closest_time);
+            let actual = swept_rect_vs_rects(origin, &targets, delta);
+
+            assert_eq!(
+                actual
+                    .as_ref()
+                    .map(|(index, result)| (*index, result.closest_time)),
+                expected
+                    .as_ref()
+                    .map(|(index, result)| (*index, result.closest_time))
+            );
+        }
+    }
+}
+
+#[test]
+fn test_swept_rect_vs_rect_zero_delta_overlapping() {
+    let zero = Vector::from((0, 0));
+    let target = Rect::from((0, 0, 10, 4));
+
+    // Sunk one unit into the top of the target
+    let origin = Rect::from((3, 3, 2, 2));
+    let result = swept_rect_vs_rect(origin, target, zero).expect("overlap should be reported");
+    assert_eq!(result.closest_time, Fp::zero());
+    assert_eq!(result.contact_normal, Vector::down());
+    assert_eq!(result.face, ContactSide::Bottom);
+    assert_eq!(result.contact_point, Vector::from((5, 5)));
+
+    // Poking into the left side of the target
+    let origin = Rect::from((-1, 1, 2, 2));
+    let result =
+        swept_rect_vs_rect_inclusive(origin, target, zero).expect("overlap should be reported");
+    assert_eq!(result.contact_normal, Vector::right());
+    assert_eq!(result.face, ContactSide::Right);
+}
+
+#[test]
+fn test_swept_rect_vs_rect_zero_delta_not_overlapping() {
+    let zero = Vector::from((0, 0));
+    let target = Rect::from((0, 0, 10, 4));
+
+    assert!(swept_rect_vs_rect(Rect::from((3, 6, 2, 2)), target, zero).is_none());
+    // Touching is not an overlap
+    assert!(swept_rect_vs_rect(Rect::from((3, 4, 2, 2)), target, zero).is_none());
+    assert!(swept_rect_vs_rect(Rect::from((10, 0, 2, 2)), target, zero).is_none());
+}
+
+#[test]
+fn test_swept_rect_vs_rect_flush_start_reports_normal() {
+    let wall = Rect::from((0, 0, 4, 4));
+
+    // Movers touching each face of the wall, moving straight and diagonally into it
+    let cases = [
+        (
+            Rect::from((-2, 1, 2, 2)),
+            [(1, 0), (1, 1), (1, -1)],
+            Vector::right(),
+        ),
+        (
+            Rect::from((4, 1, 2, 2)),
+            [(-1, 0), (-1, 1), (-1, -1)],
+            Vector::left(),
+        ),
+        (
+            Rect::from((1, 4, 2, 2)),
+            [(0, -1), (1, -1), (-1, -1)],
+            Vector::down(),
+        ),
+        (
+            Rect::from((1, -2, 2, 2)),
+            [(0, 1), (1, 1), (-1, 1)],
+            Vector::up(),
+        ),
+    ];
+
+    for (origin, deltas, normal) in cases {
+        for delta in deltas {
+            let result = swept_rect_vs_rect(origin, wall, Vector::from(delta))
+                .expect("a flush start moving into the wall should hit it");
+            assert_eq!(result.closest_time, Fp::zero(), "{delta:?}");
+            assert_eq!(result.contact_normal, normal, "{delta:?}");
+            assert_eq!(result.face, ContactSide::from_normal(normal), "{delta:?}");
+        }
+
+        // Moving away from the wall is not a contact
+        assert!(swept_rect_vs_rect(origin, wall, -normal).is_none());
+    }
+
+    // A gap too small for the time to resolve still reports the face
+    let almost_flush = Rect {
+        pos: Vector::new(-Fp::from(2) - Fp::from_raw(1), Fp::one()),
+        size: Vector::from((2, 2)),
+    };
+    let result = swept_rect_vs_rect(almost_flush, wall, Vector::from((4000, 0))).unwrap();
+    assert_eq!(result.closest_time, Fp::zero());
+    assert_eq!(result.contact_normal, Vector::right());
+}
+
+#[test]
+fn test_contact_normal_rejects_zero() {
+    assert_eq!(ContactNormal::new(Vector::default()), None);
+    assert_eq!(
+        ContactNormal::new(Vector::new(Fp::zero(), Fp::zero())),
+        None
+    );
+}
+
+#[test]
+fn test_contact_normal_cardinal_directions() {
+    for direction in [
+        Vector::up(),
+        Vector::down(),
+        Vector::left(),
+        Vector::right(),
+    ] {
+        let normal = ContactNormal::new(direction).expect("cardinal direction is valid");
+        assert_eq!(normal.vector(), direction);
+        assert_eq!(Vector::from(normal), direction);
+    }
+
+    // Longer vectors are normalized
+    let scaled = ContactNormal::new(Vector::from((0, 5))).expect("non-zero is valid");
+    assert_eq!(scaled.vector(), Vector::up());
+
+    let long = ContactNormal::new(Vector::from((200, 0))).expect("non-zero is valid");
+    assert_eq!(long.vector(), Vector::right());
+    let diagonal = ContactNormal::new(Vector::from((300, -300))).expect("non-zero is valid");
+    assert!(approx_eq(
+        length(diagonal.vector(), Rounding::Floor),
+        Fp::one(),
+        Fp::from(0.001)
+    ));
+}
+
+#[test]
+fn test_result_normal_accessor() {
+    let target = Rect::from((5, 5, 10, 10));
+
+    let face = ray_vs_rect(Vector::from((8, 0)), Vector::from((0, 1)), target).expect("should hit");
+    assert_eq!(face.normal().map(ContactNormal::vector), Some(Vector::up()));
+
+    let corner =
+        ray_vs_rect(Vector::from((0, 0)), Vector::from((1, 1)), target).expect("should hit");
+    assert_eq!(corner.face, ContactSide::Corner);
+    assert_eq!(corner.normal(), None);
+}
+
+#[test]
+fn test_swept_rect_to_matches_delta_sweep() {
+    let origin = Rect::from((1, 7, 2, 3));
+    let targets = [
+        Rect::from((6, 0, 4, 4)),
+        Rect::from((-8, 2, 2, 12)),
+        Rect::from((2, -6, 10, 2)),
+        Rect::from((20, 20, 1, 1)),
+    ];
+    let deltas = [
+        Vector::from((8, -6)),
+        Vector::from((-12, 0)),
+        Vector::from((3, -14)),
+        Vector::from((0.5, 0.25)),
+    ];
+
+    for target in targets {
+        for delta in deltas {
+            let expected = swept_rect_vs_rect(origin, target, delta);
+            let actual = swept_rect_to(origin, origin.move_by(delta), target);
+
+            assert_eq!(actual.is_some(), expected.is_some());
+            if let (Some(actual), Some(expected)) = (actual, expected) {
+                assert_eq!(actual.closest_time, expected.closest_time);
+                assert_eq!(actual.contact_point, expected.contact_point);
+                assert_eq!(actual.face, expected.face);
+            }
+        }
+    }
+
+    // A rect given with a negative size describes the same region
+    let flipped_end = Rect::from((11, 4, -2, -3));
+    assert_eq!(
+        swept_rect_to(origin, flipped_end, targets[0]).map(|result| result.closest_time),
+        swept_rect_vs_rect(origin, targets[0], Vector::from((8, -6)))
+            .map(|result| result.closest_time)
+    );
+}
+
+#[test]
+#[should_panic(expected = "origin and end must have the same size")]
+fn test_swept_rect_to_rejects_resize() {
+    let _ = swept_rect_to(
+        Rect::from((0, 0, 2, 2)),
+        Rect::from((4, 0, 3, 2)),
+        Rect::from((10, 0, 2, 2)),
+    );
+}
+
+#[test]
+fn test_ray_starting_on_face_moving_inward() {
+    let target = Rect::from((0, 0, 10, 10));
+    let cases = [
+        (
+            Vector::from((0, 4)),
+            Vector::from((1, 0)),
+            Vector::right(),
+            ContactSide::Right,
+        ),
+        (
+            Vector::from((10, 4)),
+            Vector::from((-1, 0)),
+            Vector::left(),
+            ContactSide::Left,
+        ),
+        (
+            Vector::from((6, 0)),
+            Vector::from((0, 1)),
+            Vector::up(),
+            ContactSide::Top,
+        ),
+        (
+            Vector::from((6, 10)),
+            Vector::from((0, -1)),
+            Vector::down(),
+            ContactSide::Bottom,
+        ),
+        // Entering at an angle still uses the normal of the face the ray starts on
+        (
+            Vector::from((0, 4)),
+            Vector::from((2, -1)),
+            Vector::right(),
+            ContactSide::Right,
+        ),
+        (
+            Vector::from((6, 10)),
+            Vector::from((-1, -3)),
+            Vector::down(),
+            ContactSide::Bottom,
+        ),
+    ];
+
+    for (ray_origin, ray_direction, normal, face) in cases {
+        let result = ray_vs_rect(ray_origin, ray_direction, target)
+            .unwrap_or_else(|| panic!("{ray_origin:?} {ray_direction:?} should hit"));
+        assert_eq!(result.closest_time, Fp::zero());
+        assert_eq!(result.contact_point, ray_origin);
+        assert_eq!(result.contact_normal, normal);
+        assert_eq!(result.face, face);
+    }
+}
+
+#[test]
+fn test_ray_starting_on_face_moving_outward() {
+    let target = Rect::from((0, 0, 10, 10));
+    let cases = [
+        (Vector::from((0, 4)), Vector::from((-1, 0))),
+        (Vector::from((10, 4)), Vector::from((1, 0))),
+        (Vector::from((6, 0)), Vector::from((0, -1))),
+        (Vector::from((6, 10)), Vector::from((0, 1))),
+        (Vector::from((0, 4)), Vector::from((-2, 1))),
+        (Vector::from((6, 10)), Vector::from((1, 3))),
+    ];
+
+    for (ray_origin, ray_direction) in cases {
+        assert!(
+            ray_vs_rect(ray_origin, ray_direction, target).is_none(),
+            "{ray_origin:?} {ray_direction:?}"
+        );
+    }
+}
+
+#[test]
+fn test_swept_rect_vs_rects_all_bullet_through_three_targets() {
+    let targets = [
+        Rect::from((30, -1, 2, 3)),
+        Rect::from((10, -1, 2, 3)),
+        Rect::from((20, 5, 2, 3)),
+        Rect::from((20, -1, 2, 3)),
+        Rect::from((45, -1, 2, 3)),
+    ];
+    let bullet = Rect::from((0, 0, 1, 1));
+    let delta = Vector::from((40, 0));
+
+    let contacts = swept_rect_vs_rects_all(bullet, &targets, delta);
+    let order: Vec<usize> = contacts.iter().map(|(index, _)| *index).collect();
+    assert_eq!(order, [1, 3, 0]);
+
+    for (index, result) in &contacts {
+        let single = swept_rect_vs_rect(bullet, targets[*index], delta).expect("should hit");
+        assert_eq!(result.closest_time, single.closest_time);
+        assert_eq!(result.face, ContactSide::Right);
+    }
+    assert!(contacts
+        .windows(2)
+        .all(|pair| pair[0].1.closest_time < pair[1].1.closest_time));
+
+    let (nearest, _) = swept_rect_vs_rects(bullet, &targets, delta).expect("should hit");
+    assert_eq!(nearest, contacts[0].0);
+
+    assert!(swept_rect_vs_rects_all(bullet, &targets, Vector::from((-5, 0))).is_empty());
+}
+
+#[test]
+fn test_anti_tunnel_sweep_thin_rects_crossing_paths() {
+    // A thin vertical bar moving right and a thin horizontal bar moving down cross each other
+    // only between t = 0.595 and t = 0.605
+    let vertical = Rect::from((-60.0, 0.0, 0.5, 2.0));
+    let vertical_delta = Vector::from((100.0, 0.0));
+    let horizontal = Rect::from((0.0, 60.0, 2.0, 0.5));
+    let horizontal_delta = Vector::from((0.0, -100.0));
+
+    assert!(!rect_vs_rect(vertical, horizontal));
+    assert!(!rect_vs_rect(
+        vertical.move_by(vertical_delta),
+        horizontal.move_by(horizontal_delta)
+    ));
+
+    // Too few samples step over the crossing
+    assert_eq!(
+        anti_tunnel_sweep(vertical, vertical_delta, horizontal, horizontal_delta, 4),
+        None
+    );
+
+    let time = anti_tunnel_sweep(vertical, vertical_delta, horizontal, horizontal_delta, 256)
+        .expect("should catch the crossing");
+    assert_eq!(time, Fp::from_raw(153 * Fp::SCALE / 256));
+
+    let (entry, exit) =
+        swept_rect_overlap_interval(vertical, horizontal, vertical_delta - horizontal_delta)
+            .expect("should cross");
+    assert!(entry <= time && time < exit);
+    assert!(time - entry <= Fp::from_raw(Fp::SCALE / 256));
+}
+
+#[test]
+fn test_frame_fraction_only_set_by_sweeps() {
+    let origin = Rect::from((0, 0, 2, 2));
+    let target = Rect::from((10, 0, 2, 2));
+    let delta = Vector::from((16, 0));
+
+    let swept = swept_rect_vs_rect(origin, target, delta).expect("should hit");
+    assert_eq!(swept.frame_fraction, Some(Fp::from(0.5)));
+    assert_eq!(swept.frame_fraction, Some(swept.closest_time));
+
+    let inclusive = swept_rect_vs_rect_inclusive(origin, target, Vector::from((8, 0)))
+        .expect("should touch at the end");
+    assert_eq!(inclusive.frame_fraction, Some(Fp::one()));
+
+    let overlapping = swept_rect_vs_rect(origin, Rect::from((1, 0, 2, 2)), Vector::default())
+        .expect("should overlap");
+    assert_eq!(overlapping.frame_fraction, Some(Fp::zero()));
+
+    // The same contact as a ray cast along a direction of a different length
+    let ray = ray_vs_rect(Vector::from((2, 1)), Vector::from((1, 0)), target).expect("should hit");
+    assert_eq!(ray.closest_time, Fp::from(8));
+    assert_eq!(ray.frame_fraction, None);
+
+    let (frame_time, moving) = ray_vs_swept_rect(
+        Vector::from((0, 0)),
+        Vector::from((0, 10)),
+        Rect::from((-10, 4, 2, 2)),
+        Vector::from((20, 0)),
+    )
+    .expect("should cross the ray");
+    assert_eq!(moving.frame_fraction, Some(frame_time));
+}
+
+#[test]
+fn test_line_vs_rect_target_ahead() {
+    let target = Rect::from((6, -2, 4, 4));
+    let point = Vector::from((0, 1));
+    let direction = Vector::from((2, 0));
+
+    let result = line_vs_rect(point, direction, target).expect("should cross");
+    assert_eq!(result.closest_time, Fp::from(3));
+    assert_eq!(result.contact_point, Vector::from((6, 1)));
+    assert_eq!(result.contact_normal, Vector::right());
+    assert_eq!(result.face, ContactSide::Right);
+
+    let ray = ray_vs_rect(point, direction, target).expect("should hit");
+    assert_eq!(result.closest_time, ray.closest_time);
+
+    assert!(line_vs_rect(Vector::from((0, 5)), direction, target).is_none());
+    assert!(line_vs_rect(point, Vector::default(), target).is_none());
+}
+
+#[test]
+fn test_line_vs_rect_target_behind() {
+    let target = Rect::from((-2, -10, 4, 4));
+    let point = Vector::from((1, 0));
+    let direction = Vector::from((0, 1));
+
+    assert!(ray_vs_rect(point, direction, target).is_none());
+
+    let result = line_vs_rect(point, direction, target).expect("should cross");
+    assert_eq!(result.closest_time, Fp::from(-6));
+    assert_eq!(result.contact_point, Vector::from((1, -6)));
+    assert_eq!(result.contact_normal, Vector::down());
+    assert_eq!(result.face, ContactSide::Bottom);
+    assert_eq!(
+        point + direction * result.closest_time,
+        result.contact_point
+    );
+}
+
+#[test]
+fn test_line_vs_rect_point_inside_reports_nearest_edge() {
+    let target = Rect::from((0, 0, 10, 4));
+    let direction = Vector::from((1, 0));
+
+    let behind = line_vs_rect(Vector::from((3, 2)), direction, target).expect("should cross");
+    assert_eq!(behind.closest_time, Fp::from(-3));
+    assert_eq!(behind.contact_normal, Vector::right());
+
+    let ahead = line_vs_rect(Vector::from((8, 2)), direction, target).expect("should cross");
+    assert_eq!(ahead.closest_time, Fp::from(2));
+    assert_eq!(ahead.contact_point, Vector::from((10, 2)));
+    assert_eq!(ahead.contact_normal, Vector::left());
+
+    let centered = line_vs_rect(Vector::from((5, 2)), direction, target).expect("should cross");
+    assert_eq!(centered.closest_time, Fp::from(5));
+}
+
+/// The slab test of `ray_vs_rect` for a ray parallel to one axis, written out with the
+/// `Fp::MIN` and `Fp::MAX` sentinels for the parallel axis.
+fn axis_aligned_reference(
+    ray_origin: Vector,
+    ray_direction: Vector,
+    target: Rect,
+) -> Option<(Fp, Vector, Vector, ContactSide)> {
+    let slab = |origin: Fp, direction: Fp, min: Fp, max: Fp| {
+        if direction.is_zero() {
+            return (origin >= min && origin <= max).then_some((Fp::MIN, Fp::MAX));
+        }
+        let (a, b) = ((min - origin) / direction, (max - origin) / direction);
+        Some(if a < b { (a, b) } else { (b, a) })
+    };
+
+    let (near_x, far_x) = slab(ray_origin.x, ray_direction.x, target.left(), target.right())?;
+    let (near_y, far_y) = slab(ray_origin.y, ray_direction.y, target.bottom(), target.top())?;
+    if near_x >= far_y || near_y >= far_x || far_x.min(far_y) <= Fp::zero() {
+        return None;
+    }
+
+    let time = near_x.max(near_y);
+    let (normal, face) = if near_x > near_y {
+        if ray_direction.x > Fp::zero() {
+            (Vector::right(), ContactSide::Right)
+        } else {
+            (Vector::left(), ContactSide::Left)
+        }
+    } else if ray_direction.y > Fp::zero() {
+        (Vector::up(), ContactSide::Top)
+    } else {
+        (Vector::down(), ContactSide::Bottom)
+    };
+
+    Some((time, ray_origin + ray_direction * time, normal, face))
+}
+
+fn assert_axis_aligned_parity(ray_direction: Vector) {
+    let targets = [
+        Rect::from((4, -2, 3, 4)),
+        Rect::from((-7.5, -3.25, 2.0, 1.5)),
+        Rect::from((-1, -1, 2, 2)),
+        Rect::from((0, 2, 5, 5)),
+    ];
+
+    for target in targets {
+        for x in -10..=10 {
+            for y in -10..=10 {
+                let ray_origin = Vector::new(
+                    Fp::from_raw(x * Fp::SCALE / 2),
+                    Fp::from_raw(y * Fp::SCALE / 2),
+                );
+                let actual = ray_vs_rect(ray_origin, ray_direction, target).map(|result| {
+                    (
+                        result.closest_time,
+                        result.contact_point,
+                        result.contact_normal,
+                        result.face,
+                    )
+                });
+
+                assert_eq!(
+                    actual,
+                    axis_aligned_reference(ray_origin, ray_direction, target),
+                    "{ray_origin:?} {ray_direction:?} {target:?}"
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn test_ray_vs_rect_horizontal_fast_path_parity() {
+    for ray_direction in [
+        Vector::from((1, 0)),
+        Vector::from((-3, 0)),
+        Vector::from((0.25, 0.0)),
+    ] {
+        assert_axis_aligned_parity(ray_direction);
+    }
+}
+
+#[test]
+fn test_ray_vs_rect_vertical_fast_path_parity() {
+    for ray_direction in [
+        Vector::from((0, 1)),
+        Vector::from((0, -2)),
+        Vector::from((0.0, -0.5)),
+    ] {
+        assert_axis_aligned_parity(ray_direction);
+    }
+}
+
+#[test]
+fn test_swept_rect_vs_trigger_enters_and_leaves() {
+    let trigger = Rect::from((4, -10, 4, 20));
+    let origin = Rect::from((0, 0, 2, 2));
+
+    let (enter, exit) =
+        swept_rect_vs_trigger(origin, trigger, Vector::from((16, 0))).expect("passes through");
+    assert_eq!(enter, Fp::from(0.125));
+    assert_eq!(exit, Fp::from(0.5));
+
+    // Leaving exactly at the end of the frame is still inside for the whole interval
+    let (_, exit) =
+        swept_rect_vs_trigger(origin, trigger, Vector::from((8, 0))).expect("passes through");
+    assert_eq!(exit, Fp::one());
+}
+
+#[test]
+fn test_swept_rect_vs_trigger_enters_without_leaving() {
+    let trigger = Rect::from((4, -10, 20, 20));
+    let origin = Rect::from((0, 0, 2, 2));
+
+    let (enter, exit) =
+        swept_rect_vs_trigger(origin, trigger, Vector::from((8, 4))).expect("enters");
+    assert_eq!(enter, Fp::from(0.25));
+    assert_eq!(exit, Fp::one());
+
+    let inside = Rect::from((10, 0, 2, 2));
+    assert_eq!(
+        swept_rect_vs_trigger(inside, trigger, Vector::from((-2, 0))),
+        Some((Fp::zero(), Fp::one()))
+    );
+
+    // Stopping flush against the trigger never enters it
+    assert!(swept_rect_vs_trigger(origin, trigger, Vector::from((2, 0))).is_none());
+    assert!(swept_rect_vs_trigger(origin, trigger, Vector::from((-8, 0))).is_none());
+}
+
+#[test]
+fn test_ray_vs_rects_all_equal_times_in_index_order() {
+    // Stacked targets whose left faces are all at x = 5
+    let targets = [
+        Rect::from((5, 1, 2, 2)),
+        Rect::from((12, -1, 2, 2)),
+        Rect::from((5, -3, 2, 4)),
+        Rect::from((5, -1, 4, 2)),
+        Rect::from((1, -1, 2, 2)),
+    ];
+    let ray_origin = Vector::from((0, 1));
+    let ray_direction = Vector::from((1, 0));
+
+    let hits = ray_vs_rects_all(ray_origin, ray_direction, &targets);
+    let order: Vec<(usize, Fp)> = hits
+        .iter()
+        .map(|(index, result)| (*index, result.closest_time))
+        .collect();
+    assert_eq!(
+        order,
+        [
+            (4, Fp::from(1)),
+            (0, Fp::from(5)),
+            (2, Fp::from(5)),
+            (3, Fp::from(5)),
+            (1, Fp::from(12)),
+        ]
+    );
+
+    let mut reversed_hits = Vec::new();
+    let reversed: Vec<Rect> = targets.iter().rev().copied().collect();
+    ray_vs_rects_all_into(ray_origin, ray_direction, &reversed, &mut reversed_hits);
+    let reversed_order: Vec<usize> = reversed_hits.iter().map(|(index, _)| *index).collect();
+    assert_eq!(reversed_order, [0, 1, 2, 4, 3]);
 }
@@ -0,0 +1,63 @@
+/*
+ * Copyright (c) Peter Bjorklund. All rights reserved. https://github.com/piot/impact-rs
+ * Licensed under the MIT License. See LICENSE in the project root for license information.
+ */
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use fixed32::Fp;
+use fixed32_math::{Rect, Vector};
+use impact_rs::limits::{set_delta_warning_hook, MAX_SWEEP_DELTA};
+use impact_rs::prelude::full::*;
+
+#[test]
+fn test_huge_delta_does_not_report_immediate_contact() {
+    // The target is a quarter unit away; with this delta the entry time rounds to zero
+    let origin = Rect::from((0.0, 0.0, 1.0, 1.0));
+    let target = Rect::from((1.25, 0.0, 1.0, 1.0));
+    let delta = Vector::from((20000, 0));
+
+    assert!(swept_rect_vs_rect(origin, target, delta).is_none());
+    assert!(swept_rect_vs_rect_inclusive(origin, target, delta).is_none());
+    assert!(swept_rect_overlap_interval(origin, target, delta).is_none());
+    assert!(swept_rect_vs_rect_horizontal_time(origin, target, delta.x).is_none());
+    assert!(swept_rect_vs_rect_vertical_time(origin, target, delta.x).is_none());
+}
+
+#[test]
+fn test_huge_delta_near_edge_of_range_does_not_overflow() {
+    let origin = Rect::from((10000, 0, 1, 1));
+    let target = Rect::from((10020, 0, 1, 1));
+
+    assert!(swept_rect_vs_rect(origin, target, Vector::from((20000, 0))).is_none());
+
+    // The longest accepted motion is still resolved near the edge of the range
+    let far_origin = Rect::from((28000, 0, 1, 1));
+    let far_target = Rect::from((30000, 0, 1, 1));
+    let delta = Vector::new(MAX_SWEEP_DELTA, Fp::zero());
+
+    let result = swept_rect_vs_rect(far_origin, far_target, delta).expect("should hit");
+    assert!(result.closest_time > Fp::zero());
+    assert_eq!(result.face, ContactSide::Right);
+}
+
+#[test]
+fn test_delta_warning_hook_reports_rejected_delta() {
+    static REJECTED: AtomicUsize = AtomicUsize::new(0);
+
+    // The hook is global, so only count the delta of this test
+    fn count_rejected(delta: Vector) {
+        if delta == Vector::from((0, -5000)) {
+            REJECTED.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    set_delta_warning_hook(Some(count_rejected));
+    let origin = Rect::from((0, 0, 1, 1));
+    let floor = Rect::from((-10, -20, 20, 10));
+    assert!(swept_rect_vs_rect(origin, floor, Vector::from((0, -5000))).is_none());
+    assert!(swept_rect_vs_rect(origin, floor, Vector::from((0, -4000))).is_some());
+    set_delta_warning_hook(None);
+
+    assert_eq!(REJECTED.load(Ordering::Relaxed), 1);
+}
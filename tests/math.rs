@@ -0,0 +1,168 @@
+/*
+ * Copyright (c) Peter Bjorklund. All rights reserved. https://github.com/piot/impact-rs
+ * Licensed under the MIT License. See LICENSE in the project root for license information.
+ */
+
+use fixed32::Fp;
+use fixed32_math::{Rect, Vector};
+use impact_rs::math::{
+    approx_eq, approx_eq_result, approx_eq_vector, cross, dot, length, perpendicular,
+    perpendicular_cw, saturating_div, vec_max, vec_min, Rounding, DEFAULT_EPSILON,
+};
+use impact_rs::ray_vs_rect;
+
+#[test]
+fn test_dot_perpendicular_is_zero() {
+    assert_eq!(dot(Vector::right(), Vector::up()), Fp::zero());
+    assert_eq!(dot(Vector::from((3, -2)), Vector::from((4, 6))), Fp::zero());
+}
+
+#[test]
+fn test_cross_parallel_is_zero() {
+    assert_eq!(
+        cross(Vector::from((2, 3)), Vector::from((4, 6))),
+        Fp::zero()
+    );
+    assert_eq!(
+        cross(Vector::from((2, 3)), Vector::from((-2, -3))),
+        Fp::zero()
+    );
+}
+
+#[test]
+fn test_dot_and_cross_signs() {
+    let a = Vector::from((1, 0));
+
+    assert_eq!(dot(a, Vector::from((5, 1))), Fp::from(5));
+    assert_eq!(dot(a, Vector::from((-5, 1))), Fp::from(-5));
+    assert_eq!(cross(a, Vector::from((1, 2))), Fp::from(2));
+    assert_eq!(cross(a, Vector::from((1, -2))), Fp::from(-2));
+}
+
+#[test]
+fn test_saturating_div_clamps_large_quotients() {
+    assert_eq!(saturating_div(Fp::from(-200), Fp::from(0.001)), Fp::MIN);
+    assert_eq!(saturating_div(Fp::from(200), Fp::from(0.001)), Fp::MAX);
+    assert_eq!(saturating_div(Fp::zero(), Fp::zero()), Fp::zero());
+    assert_eq!(saturating_div(Fp::from(7), Fp::from(-2)), Fp::from(-3.5));
+}
+
+#[test]
+fn test_approx_eq_epsilon_boundary() {
+    let eps = Fp::from_raw(4);
+    let value = Fp::from(3);
+
+    assert!(approx_eq(value, value + Fp::from_raw(4), eps));
+    assert!(approx_eq(value, value - Fp::from_raw(4), eps));
+    assert!(!approx_eq(value, value + Fp::from_raw(5), eps));
+    assert!(!approx_eq(value, value - Fp::from_raw(5), eps));
+    assert!(!approx_eq(Fp::MAX, Fp::MIN, eps));
+
+    let v = Vector::from((1, 2));
+    assert!(approx_eq_vector(
+        v,
+        v + Vector::new(Fp::from_raw(4), Fp::from_raw(-4)),
+        eps
+    ));
+    assert!(!approx_eq_vector(
+        v,
+        v + Vector::new(Fp::zero(), Fp::from_raw(5)),
+        eps
+    ));
+}
+
+#[test]
+fn test_approx_eq_result_compares_every_field() {
+    let target = Rect::from((5, -5, 10, 10));
+    let result =
+        ray_vs_rect(Vector::from((0, 0)), Vector::from((1, 0)), target).expect("should hit");
+    let shifted = ray_vs_rect(
+        Vector::new(Fp::from_raw(3), Fp::zero()),
+        Vector::from((1, 0)),
+        target,
+    )
+    .expect("should hit");
+
+    assert!(approx_eq_result(&result, &shifted, Fp::from_raw(3)));
+    assert!(!approx_eq_result(&result, &shifted, Fp::from_raw(2)));
+}
+
+#[test]
+fn test_vec_min_max_mixed_signs() {
+    let a = Vector::new(Fp::from(-2.5), Fp::from(7));
+    let b = Vector::new(Fp::from(3), Fp::from(-0.25));
+
+    assert_eq!(vec_min(a, b), Vector::new(Fp::from(-2.5), Fp::from(-0.25)));
+    assert_eq!(vec_max(a, b), Vector::new(Fp::from(3), Fp::from(7)));
+    assert_eq!(vec_min(a, b), vec_min(b, a));
+    assert_eq!(vec_max(a, a), a);
+
+    let c = Vector::from((-4, -1));
+    let d = Vector::from((-1, -4));
+    assert_eq!(vec_min(c, d), Vector::from((-4, -4)));
+    assert_eq!(vec_max(c, d), Vector::from((-1, -1)));
+}
+
+#[test]
+fn test_length_rounding_brackets_exact_value() {
+    for (x, y) in [
+        (1, 1),
+        (3, 4),
+        (-7, 2),
+        (65_537, -3),
+        (123_456_789, 987_654),
+        (-1_500_000_000, 1_499_999_999),
+        (0, -5),
+    ] {
+        let v = Vector::new(Fp::from_raw(x), Fp::from_raw(y));
+        let exact = (f64::from(x).powi(2) + f64::from(y).powi(2)).sqrt();
+
+        let floor = length(v, Rounding::Floor).inner();
+        let ceil = length(v, Rounding::Ceil).inner();
+
+        assert!(f64::from(floor) <= exact, "floor of {x}, {y}");
+        assert!(f64::from(ceil) >= exact, "ceil of {x}, {y}");
+        assert!(ceil - floor <= 1, "{x}, {y}");
+    }
+
+    assert_eq!(length(Vector::from((-6, 8)), Rounding::Ceil), Fp::from(10));
+    assert_eq!(
+        length(Vector::from((30000, 30000)), Rounding::Floor),
+        Fp::MAX
+    );
+}
+
+#[test]
+fn test_perpendicular_rotates_cardinal_directions() {
+    let counter_clockwise = [
+        (Vector::right(), Vector::up()),
+        (Vector::up(), Vector::left()),
+        (Vector::left(), Vector::down()),
+        (Vector::down(), Vector::right()),
+    ];
+
+    for (v, rotated) in counter_clockwise {
+        assert_eq!(perpendicular(v), rotated);
+        assert_eq!(perpendicular_cw(rotated), v);
+        assert_eq!(dot(v, perpendicular(v)), Fp::zero());
+        assert_eq!(cross(v, perpendicular(v)), Fp::one());
+        assert_eq!(cross(v, perpendicular_cw(v)), Fp::neg_one());
+    }
+
+    let v = Vector::from((3.5, -2.25));
+    assert_eq!(perpendicular(perpendicular(v)), -v);
+    assert_eq!(perpendicular_cw(perpendicular(v)), v);
+}
+
+#[test]
+fn test_default_epsilon_is_one_64th() {
+    assert_eq!(DEFAULT_EPSILON, Fp::from(1.0 / 64.0));
+
+    let value = Fp::from(7);
+    assert!(approx_eq(value, value + DEFAULT_EPSILON, DEFAULT_EPSILON));
+    assert!(!approx_eq(
+        value,
+        value + DEFAULT_EPSILON + Fp::from_raw(1),
+        DEFAULT_EPSILON
+    ));
+}
@@ -0,0 +1,349 @@
+/*
+ * Copyright (c) Peter Bjorklund. All rights reserved. https://github.com/piot/impact-rs
+ * Licensed under the MIT License. See LICENSE in the project root for license information.
+ */
+
+use fixed32::Fp;
+use fixed32_math::{Rect, Vector};
+use impact_rs::prelude::full::*;
+
+#[test]
+fn test_rect_penetration_picks_smallest_axis() {
+    let a = Rect::from((0.0, 0.0, 2.0, 2.0));
+    let b = Rect::from((1.5, -4.0, 4.0, 8.0));
+
+    assert_eq!(
+        rect_penetration(a, b),
+        Some(Vector::new(Fp::from(-0.5), Fp::zero()))
+    );
+    assert_eq!(
+        rect_penetration(Rect::from((0, 0, 1, 1)), Rect::from((1, 0, 1, 1))),
+        None
+    );
+}
+
+#[test]
+fn test_depenetrate_two_adjacent_tiles() {
+    let tiles = [Rect::from((0, 0, 1, 1)), Rect::from((1, 0, 1, 1))];
+    let character = Rect::from((0.5, 0.75, 1.0, 1.0));
+
+    let resolved = depenetrate(character, &tiles, 8);
+
+    assert!(tiles.iter().all(|tile| !rect_vs_rect(resolved, *tile)));
+    assert_eq!(resolved, Rect::from((0.5, 1.0, 1.0, 1.0)));
+}
+
+#[test]
+fn test_depenetrate_floor_and_wall() {
+    let tiles = [
+        Rect::from((0, 0, 1, 1)),
+        Rect::from((1, 0, 1, 1)),
+        Rect::from((1, 1, 1, 1)),
+    ];
+    let character = Rect::from((0.25, 0.875, 1.0, 1.0));
+
+    let resolved = depenetrate(character, &tiles, 8);
+
+    assert!(tiles.iter().all(|tile| !rect_vs_rect(resolved, *tile)));
+    assert_eq!(resolved, Rect::from((0.0, 1.0, 1.0, 1.0)));
+}
+
+#[test]
+fn test_depenetrate_respects_max_iterations() {
+    let tiles = [Rect::from((0, 0, 4, 4))];
+    let character = Rect::from((1, 3, 2, 2));
+
+    assert_eq!(depenetrate(character, &tiles, 0), character);
+}
+
+#[test]
+fn test_contact_manifold_face_face_resting() {
+    let body = Rect::from((2.0, 3.75, 2.0, 2.0));
+    let floor = Rect::from((0.0, 0.0, 10.0, 4.0));
+
+    let (points, normal, depth) = rect_contact_manifold(body, floor).expect("should be in contact");
+
+    assert_eq!(
+        points,
+        [Vector::from((2.0, 3.75)), Vector::from((4.0, 3.75))]
+    );
+    assert_eq!(normal, Vector::down());
+    assert_eq!(depth, Fp::from(0.25));
+}
+
+#[test]
+fn test_contact_manifold_wide_body_on_narrow_pillar() {
+    let body = Rect::from((0.0, 3.5, 10.0, 2.0));
+    let pillar = Rect::from((4.0, 0.0, 2.0, 4.0));
+
+    let (points, normal, depth) =
+        rect_contact_manifold(body, pillar).expect("should be in contact");
+
+    assert_eq!(points, [Vector::from((4, 4)), Vector::from((6, 4))]);
+    assert_eq!(normal, Vector::down());
+    assert_eq!(depth, Fp::from(0.5));
+}
+
+#[test]
+fn test_contact_manifold_corner_overlap() {
+    let body = Rect::from((0.0, 0.0, 2.0, 2.0));
+    let other = Rect::from((1.75, 1.5, 4.0, 4.0));
+
+    let (points, normal, depth) = rect_contact_manifold(body, other).expect("should be in contact");
+
+    assert_eq!(points[0], Vector::from((2, 2)));
+    assert_eq!(points[0], points[1]);
+    assert_eq!(normal, Vector::right());
+    assert_eq!(depth, Fp::from(0.25));
+}
+
+#[test]
+fn test_contact_manifold_no_overlap() {
+    let body = Rect::from((0, 0, 2, 2));
+    let floor = Rect::from((0, -4, 10, 4));
+
+    assert!(rect_contact_manifold(body, floor).is_none());
+}
+
+#[test]
+fn test_separate_rects_three_mutually_overlapping() {
+    let mut rects = [
+        Rect::from((0, 0, 4, 4)),
+        Rect::from((1, 1, 4, 4)),
+        Rect::from((2, 0, 4, 4)),
+    ];
+
+    separate_rects(&mut rects, 32);
+
+    assert!(overlapping_pairs(&rects).is_empty(), "{rects:?}");
+}
+
+#[test]
+fn test_separate_rects_identical_rects() {
+    let mut rects = [Rect::from((0, 0, 2, 2)), Rect::from((0, 0, 2, 2))];
+
+    separate_rects(&mut rects, 4);
+
+    assert!(!rect_vs_rect(rects[0], rects[1]));
+    assert_eq!(rects[0], Rect::from((0, 1, 2, 2)));
+    assert_eq!(rects[1], Rect::from((0, -1, 2, 2)));
+}
+
+#[test]
+fn test_rect_overlaps_any_stops_at_first_overlap() {
+    let character = Rect::from((0, 0, 2, 2));
+    let targets = [
+        Rect::from((1, 1, 2, 2)),
+        Rect::from((10, 10, 2, 2)),
+        Rect::from((-1, 0, 2, 2)),
+    ];
+
+    assert!(rect_overlaps_any(character, &targets));
+    assert!(rect_overlaps_any(character, &targets[2..]));
+    assert!(!rect_overlaps_any(character, &targets[1..2]));
+    assert!(!rect_overlaps_any(character, &[]));
+}
+
+#[test]
+fn test_would_overlap_move_into_wall() {
+    let targets = [Rect::from((10, 10, 2, 2)), Rect::from((4, -4, 2, 10))];
+    let character = Rect::from((0, 0, 2, 2));
+
+    assert!(would_overlap(character, &targets, Vector::from((3, 1))));
+    assert!(would_overlap(character, &targets, Vector::from((5, 0))));
+    // Ending flush against the wall is not an overlap
+    assert!(!would_overlap(character, &targets, Vector::from((2, 0))));
+}
+
+#[test]
+fn test_would_overlap_move_into_open_space() {
+    let targets = [Rect::from((10, 10, 2, 2)), Rect::from((4, -4, 2, 10))];
+    let character = Rect::from((0, 0, 2, 2));
+
+    assert!(!would_overlap(character, &targets, Vector::from((-3, 1))));
+    assert!(!would_overlap(character, &targets, Vector::from((0, 0))));
+    // Only the destination is tested, so jumping over the wall is not an overlap
+    assert!(!would_overlap(character, &targets, Vector::from((8, 0))));
+    assert!(!would_overlap(character, &[], Vector::from((5, 0))));
+}
+
+#[test]
+fn test_rect_overlaps_all_collects_every_index() {
+    let character = Rect::from((0, 0, 2, 2));
+    let targets = [
+        Rect::from((1, 1, 2, 2)),
+        Rect::from((2, 0, 2, 2)),
+        Rect::from((10, 10, 2, 2)),
+        Rect::from((-1, 0, 2, 2)),
+    ];
+
+    assert_eq!(rect_overlaps_all(character, &targets), vec![0, 3]);
+    assert!(rect_overlaps_all(character, &targets[1..3]).is_empty());
+}
+
+#[test]
+fn test_deepest_overlap_picks_deeper_target() {
+    let stuck = Rect::from((0, 0, 4, 4));
+    let targets = [
+        Rect::from((-10, -10, 11, 20)),
+        Rect::from((10, 10, 2, 2)),
+        Rect::from((2, -10, 10, 20)),
+    ];
+
+    assert_eq!(
+        deepest_overlap(stuck, &targets),
+        Some((2, Vector::from((-2, 0))))
+    );
+    assert_eq!(
+        deepest_overlap(stuck, &targets[..2]),
+        Some((0, Vector::from((1, 0))))
+    );
+    assert_eq!(deepest_overlap(stuck, &targets[1..2]), None);
+}
+
+#[test]
+fn test_deepest_overlap_ties_resolve_to_lowest_index() {
+    let stuck = Rect::from((0, 0, 4, 4));
+    let targets = [Rect::from((3, 0, 4, 4)), Rect::from((-3, 0, 4, 4))];
+
+    assert_eq!(
+        deepest_overlap(stuck, &targets),
+        Some((0, Vector::from((-1, 0))))
+    );
+}
+
+#[test]
+fn test_points_in_rects_adjacent_tiles() {
+    let tiles = [
+        Rect::from((0, 0, 4, 4)),
+        Rect::from((4, 0, 4, 4)),
+        Rect::from((0, 4, 4, 4)),
+        Rect::from((4, 4, 4, 4)),
+    ];
+    let points = [
+        Vector::from((1, 1)),
+        Vector::from((4, 0)),
+        Vector::from((3, 7)),
+        Vector::from((4, 4)),
+        Vector::from((8, 2)),
+        Vector::from((-1, 3)),
+        Vector::from((7, 3)),
+    ];
+
+    assert_eq!(
+        points_in_rects(&points, &tiles),
+        vec![Some(0), Some(1), Some(2), Some(3), None, None, Some(1)]
+    );
+    assert_eq!(points_in_rects(&points, &[]), vec![None; points.len()]);
+}
+
+#[test]
+fn test_resolve_pair_equal_weights() {
+    let mut a = Rect::from((0, 0, 4, 4));
+    let mut b = Rect::from((2, 0, 4, 4));
+
+    resolve_pair(&mut a, &mut b, Fp::from(5), Fp::from(5));
+
+    assert_eq!(a, Rect::from((-1, 0, 4, 4)));
+    assert_eq!(b, Rect::from((3, 0, 4, 4)));
+}
+
+#[test]
+fn test_resolve_pair_three_to_one() {
+    let mut heavy = Rect::from((0, 0, 4, 4));
+    let mut light = Rect::from((0, 2, 4, 4));
+
+    resolve_pair(&mut heavy, &mut light, Fp::from(3), Fp::one());
+
+    assert_eq!(heavy, Rect::from((0.0, -0.5, 4.0, 4.0)));
+    assert_eq!(light, Rect::from((0.0, 3.5, 4.0, 4.0)));
+    assert!(!rect_vs_rect(heavy, light));
+}
+
+#[test]
+fn test_resolve_pair_immovable_body() {
+    let mut wall = Rect::from((4, 0, 4, 10));
+    let mut mover = Rect::from((1, 2, 4, 4));
+
+    resolve_pair(&mut wall, &mut mover, Fp::MAX, Fp::one());
+    assert_eq!(wall, Rect::from((4, 0, 4, 10)));
+    assert_eq!(mover, Rect::from((0, 2, 4, 4)));
+
+    // A weightless mover takes the whole push
+    let mut mover = Rect::from((1, 2, 4, 4));
+    resolve_pair(&mut mover, &mut wall, Fp::zero(), Fp::one());
+    assert_eq!(wall, Rect::from((4, 0, 4, 10)));
+    assert_eq!(mover, Rect::from((0, 2, 4, 4)));
+
+    // Both weightless: split evenly instead of dividing by zero
+    let mut a = Rect::from((0, 0, 4, 4));
+    let mut b = Rect::from((2, 0, 4, 4));
+    resolve_pair(&mut a, &mut b, Fp::zero(), Fp::zero());
+    assert_eq!(a, Rect::from((-1, 0, 4, 4)));
+    assert_eq!(b, Rect::from((3, 0, 4, 4)));
+}
+
+#[test]
+fn test_overlap_axes_only_x() {
+    // Stacked in the same column with a gap between them
+    let a = Rect::from((0, 0, 4, 2));
+    let b = Rect::from((2, 5, 4, 2));
+
+    assert_eq!(overlap_axes(a, b), (true, false));
+    assert!(!rect_vs_rect(a, b));
+}
+
+#[test]
+fn test_overlap_axes_only_y() {
+    let a = Rect::from((0, 0, 2, 4));
+    let b = Rect::from((6, 1, 2, 2));
+
+    assert_eq!(overlap_axes(a, b), (false, true));
+    assert_eq!(overlap_axes(b, a), (false, true));
+}
+
+#[test]
+fn test_overlap_axes_both_and_touching() {
+    let a = Rect::from((0, 0, 4, 4));
+
+    assert_eq!(overlap_axes(a, Rect::from((3, 3, 4, 4))), (true, true));
+    assert!(rect_vs_rect(a, Rect::from((3, 3, 4, 4))));
+
+    // Sharing the edge x = 4 is not an overlap on x
+    assert_eq!(overlap_axes(a, Rect::from((4, 1, 2, 2))), (false, true));
+}
+
+#[test]
+fn test_penetration_on_axis_x() {
+    let a = Rect::from((3, 0, 2, 2));
+    let b = Rect::from((4, 0, 4, 8));
+
+    assert_eq!(penetration_on_axis(a, b, Axis::X), Some(Fp::from(-1)));
+    assert_eq!(penetration_on_axis(b, a, Axis::X), Some(Fp::from(1)));
+    assert_eq!(penetration_on_axis(a, b, Axis::Y), Some(Fp::from(-2)));
+}
+
+#[test]
+fn test_penetration_on_axis_y_ignores_other_axis() {
+    // Apart on x, but the projections onto y overlap
+    let a = Rect::from((0, 3, 2, 2));
+    let b = Rect::from((10, 0, 4, 4));
+
+    assert_eq!(penetration_on_axis(a, b, Axis::Y), Some(Fp::from(1)));
+    assert_eq!(penetration_on_axis(a, b, Axis::X), None);
+    assert_eq!(rect_penetration(a, b), None);
+}
+
+#[test]
+fn test_penetration_on_axis_non_overlap() {
+    let a = Rect::from((0, 0, 2, 2));
+
+    assert_eq!(
+        penetration_on_axis(a, Rect::from((2, 0, 2, 2)), Axis::X),
+        None
+    );
+    assert_eq!(
+        penetration_on_axis(a, Rect::from((5, 7, 2, 2)), Axis::Y),
+        None
+    );
+}
@@ -0,0 +1,57 @@
+/*
+ * Copyright (c) Peter Bjorklund. All rights reserved. https://github.com/piot/impact-rs
+ * Licensed under the MIT License. See LICENSE in the project root for license information.
+ */
+
+use fixed32_math::Vector;
+use impact_rs::prelude::full::*;
+
+#[test]
+fn test_ccw_triangle_is_kept() {
+    let mut triangle = vec![
+        Vector::from((0, 0)),
+        Vector::from((10, 0)),
+        Vector::from((10, 10)),
+    ];
+    let original = triangle.clone();
+
+    assert!(polygon_is_ccw(&triangle));
+    ensure_ccw(&mut triangle);
+    assert_eq!(triangle, original);
+}
+
+#[test]
+fn test_cw_triangle_is_reversed() {
+    let mut triangle = vec![
+        Vector::from((0, 0)),
+        Vector::from((10, 10)),
+        Vector::from((10, 0)),
+    ];
+
+    assert!(!polygon_is_ccw(&triangle));
+    ensure_ccw(&mut triangle);
+    assert!(polygon_is_ccw(&triangle));
+    assert_eq!(
+        triangle,
+        vec![
+            Vector::from((10, 0)),
+            Vector::from((10, 10)),
+            Vector::from((0, 0)),
+        ]
+    );
+}
+
+#[test]
+fn test_collinear_vertices_are_degenerate() {
+    let mut collinear = vec![
+        Vector::from((0, 0)),
+        Vector::from((2, 2)),
+        Vector::from((5, 5)),
+    ];
+    let original = collinear.clone();
+
+    assert!(!polygon_is_ccw(&collinear));
+    ensure_ccw(&mut collinear);
+    assert_eq!(collinear, original);
+    assert!(!polygon_is_ccw(&[]));
+}
@@ -0,0 +1,168 @@
+/*
+ * Copyright (c) Peter Bjorklund. All rights reserved. https://github.com/piot/impact-rs
+ * Licensed under the MIT License. See LICENSE in the project root for license information.
+ */
+
+use fixed32::Fp;
+use fixed32_math::{Rect, Vector};
+use impact_rs::prelude::full::*;
+use impact_rs::trig::rotate;
+
+#[test]
+fn test_ray_vs_rect_matches_free_function() {
+    let ray = Ray::new(Vector::from((1, 2)), Vector::from((3, 4)));
+    let target = Rect::from((5, 6, 7, 8));
+
+    let free = ray_vs_rect(ray.origin, ray.direction, target).expect("should hit");
+    let method = ray.vs_rect(target).expect("should hit");
+
+    assert_eq!(method.closest_time, free.closest_time);
+    assert_eq!(method.contact_point, free.contact_point);
+    assert_eq!(method.contact_normal, free.contact_normal);
+    assert_eq!(ray.point_at(method.closest_time), method.contact_point);
+}
+
+#[test]
+fn test_ray_vs_rects_picks_nearest() {
+    let ray = Ray::new(Vector::from((0, 0)), Vector::from((1, 0)));
+    let targets = [Rect::from((20, -5, 10, 10)), Rect::from((10, -5, 5, 10))];
+
+    let (index, result) = ray.vs_rects(&targets).expect("should hit");
+    assert_eq!(index, 1);
+    assert_eq!(result.closest_time, Fp::from(10));
+    assert!(ray.vs_rects(&[]).is_none());
+}
+
+#[test]
+fn test_conservative_contact_never_inside_target() {
+    let target = Rect::new(
+        Vector::new(Fp::from(3.3), Fp::from(-1.7)),
+        Vector::new(Fp::from(2.9), Fp::from(4.1)),
+    );
+    let center = target.pos + target.size / 2;
+
+    for step in 0..72 {
+        let angle = Fp::from(step) * Fp::from(0.0872665);
+        let direction = rotate(Vector::new(Fp::from(1.37), Fp::zero()), angle);
+
+        for start in [5, 11] {
+            let ray = Ray::new(center - direction * Fp::from(start), direction);
+            let exact = ray.vs_rect(target).expect("aimed at the center");
+            let conservative = RayQuery::new(ray)
+                .conservative(true)
+                .vs_rect(target)
+                .expect("aimed at the center");
+
+            let point = conservative.contact_point;
+            let inside = point.x > target.left()
+                && point.x < target.right()
+                && point.y > target.bottom()
+                && point.y < target.top();
+            assert!(!inside, "angle step {step}: {point:?} inside {target:?}");
+            assert!(conservative.closest_time <= exact.closest_time);
+            assert_eq!(ray.point_at(conservative.closest_time), point);
+        }
+    }
+}
+
+#[test]
+fn test_conservative_pulls_straddling_ray_out() {
+    // Starting inside the target, the entry point behind the origin is reported
+    let target = Rect::from((0, 0, 10, 10));
+    let ray = Ray::new(
+        Vector::new(Fp::from(5.1), Fp::from(5)),
+        Vector::from((3, 0)),
+    );
+
+    let result = RayQuery::new(ray)
+        .conservative(true)
+        .vs_rect(target)
+        .expect("straddles the target");
+    assert!(result.contact_point.x <= target.left());
+    assert_eq!(
+        RayQuery::new(ray).corner_policy(CornerPolicy::None),
+        RayQuery::new(ray)
+    );
+}
+
+#[test]
+fn test_ray_cast_cache_matches_fresh_casts() {
+    let ray = Ray::new(Vector::from((0, 0)), Vector::from((2, 1)));
+    let targets = [
+        Rect::from((10, 0, 4, 8)),
+        Rect::from((-5, -5, 2, 2)),
+        Rect::from((4, 1, 2, 2)),
+        Rect::from((20, 20, 2, 2)),
+    ];
+    let mut cache = RayCastCache::new(ray, &targets);
+    assert_eq!(cache.len(), targets.len());
+
+    for (index, target) in targets.iter().enumerate() {
+        assert!(!cache.is_cached(index));
+        let fresh = ray.vs_rect(*target);
+        let cached = cache.result(index).cloned();
+
+        assert_eq!(cached.is_some(), fresh.is_some());
+        if let (Some(cached), Some(fresh)) = (cached, fresh) {
+            assert_eq!(cached.closest_time, fresh.closest_time);
+            assert_eq!(cached.contact_point, fresh.contact_point);
+            assert_eq!(cached.face, fresh.face);
+        }
+        assert!(cache.is_cached(index));
+    }
+
+    let (index, nearest) = cache.nearest().expect("should hit");
+    let (expected_index, expected) = ray.vs_rects(&targets).expect("should hit");
+    assert_eq!(index, expected_index);
+    assert_eq!(nearest.closest_time, expected.closest_time);
+}
+
+#[test]
+fn test_ray_cast_cache_invalidation() {
+    let ray = Ray::new(Vector::from((0, 0)), Vector::from((1, 0)));
+    let mut cache = RayCastCache::new(
+        ray,
+        &[Rect::from((10, -1, 2, 2)), Rect::from((20, -1, 2, 2))],
+    );
+    let _ = cache.nearest();
+    assert!(cache.is_cached(0) && cache.is_cached(1));
+
+    // Setting an unchanged target keeps the cached result
+    cache.set_target(1, Rect::from((20, -1, 2, 2)));
+    assert!(cache.is_cached(1));
+
+    cache.set_target(0, Rect::from((30, -1, 2, 2)));
+    assert!(!cache.is_cached(0));
+    assert!(cache.is_cached(1));
+    assert_eq!(cache.target(0), Rect::from((30, -1, 2, 2)));
+
+    let (index, result) = cache.nearest().expect("should hit");
+    assert_eq!(index, 1);
+    assert_eq!(result.closest_time, Fp::from(20));
+
+    cache.invalidate(1);
+    assert!(!cache.is_cached(1));
+    assert_eq!(
+        cache.result(1).map(|result| result.closest_time),
+        Some(Fp::from(20))
+    );
+}
+
+#[test]
+fn test_ray_from_tuples() {
+    let origin = Vector::from((1, 2));
+    let direction = Vector::from((3, 4));
+
+    assert_eq!(Ray::from((origin, direction)), Ray::new(origin, direction));
+    assert_eq!(Ray::from(((1, 2), (3, 4))), Ray::new(origin, direction));
+    assert_eq!(
+        Ray::from(((1.0, 2.0), direction)),
+        Ray::new(origin, direction)
+    );
+
+    let result = Ray::from(((0, 0), (1, 0)))
+        .vs_rect(Rect::from((5, -1, 2, 2)))
+        .expect("should hit");
+    assert_eq!(result.closest_time, Fp::from(5));
+    assert_eq!(result.contact_normal, Vector::right());
+}
@@ -0,0 +1,290 @@
+/*
+ * Copyright (c) Peter Bjorklund. All rights reserved. https://github.com/piot/impact-rs
+ * Licensed under the MIT License. See LICENSE in the project root for license information.
+ */
+
+use fixed32::Fp;
+use fixed32_math::{Rect, Vector};
+use impact_rs::math::Rounding;
+use impact_rs::prelude::full::*;
+
+#[test]
+fn test_normalize_rect_negative_width() {
+    let rect = Rect::from((10, 2, -4, 3));
+    assert_eq!(normalize_rect(rect), Rect::from((6, 2, 4, 3)));
+}
+
+#[test]
+fn test_normalize_rect_negative_height() {
+    let rect = Rect::from((1, 8, 5, -6));
+    assert_eq!(normalize_rect(rect), Rect::from((1, 2, 5, 6)));
+}
+
+#[test]
+fn test_normalize_rect_negative_width_and_height() {
+    let rect = Rect::from((10, 8, -4, -6));
+    assert_eq!(normalize_rect(rect), Rect::from((6, 2, 4, 6)));
+}
+
+#[test]
+fn test_normalize_rect_positive_is_unchanged() {
+    let rect = Rect::from((1, 2, 3, 4));
+    assert_eq!(normalize_rect(rect), rect);
+}
+
+#[test]
+fn test_queries_accept_negative_sized_rects() {
+    let ray_origin = Vector::from((0, 1));
+    let ray_direction = Vector::from((1, 0));
+    let flipped = Rect::from((9, 3, -4, -3));
+    let normalized = normalize_rect(flipped);
+
+    let from_flipped =
+        ray_vs_rect(ray_origin, ray_direction, flipped).expect("should have intersected");
+    let from_normalized =
+        ray_vs_rect(ray_origin, ray_direction, normalized).expect("should have intersected");
+    assert_eq!(from_flipped.closest_time, from_normalized.closest_time);
+    assert_eq!(from_flipped.closest_time, Fp::from(5));
+
+    let origin = Rect::from((2, 2, -2, -2));
+    let target = Rect::from((10, 0, -4, 2));
+    assert_eq!(
+        swept_rect_vs_rect_horizontal_time(origin, target, Fp::from(10)),
+        swept_rect_vs_rect_horizontal_time(
+            normalize_rect(origin),
+            normalize_rect(target),
+            Fp::from(10)
+        )
+    );
+}
+
+#[test]
+fn test_clamp_to_rect_outside_each_edge() {
+    let bounds = Rect::from((-2, 1, 6, 4));
+
+    assert_eq!(
+        clamp_to_rect(Vector::from((-5, 3)), bounds),
+        Vector::from((-2, 3))
+    );
+    assert_eq!(
+        clamp_to_rect(Vector::from((9, 3)), bounds),
+        Vector::from((4, 3))
+    );
+    assert_eq!(
+        clamp_to_rect(Vector::from((0, -7)), bounds),
+        Vector::from((0, 1))
+    );
+    assert_eq!(
+        clamp_to_rect(Vector::from((0, 8)), bounds),
+        Vector::from((0, 5))
+    );
+    assert_eq!(
+        clamp_to_rect(Vector::from((9, 8)), bounds),
+        Vector::from((4, 5))
+    );
+}
+
+#[test]
+fn test_clamp_to_rect_inside_is_unchanged() {
+    let bounds = Rect::from((-2, 1, 6, 4));
+    let inside = Vector::new(Fp::from(1.5), Fp::from(2.25));
+
+    assert_eq!(clamp_to_rect(inside, bounds), inside);
+    assert_eq!(
+        clamp_to_rect(Vector::from((4, 1)), bounds),
+        Vector::from((4, 1))
+    );
+    // Rects with negative size cover the same region
+    assert_eq!(
+        clamp_to_rect(Vector::from((9, 8)), Rect::from((4, 5, -6, -4))),
+        Vector::from((4, 5))
+    );
+}
+
+#[test]
+fn test_rect_from_min_max_matches_hand_constructed() {
+    let rect = rect_from_min_max(Vector::from((-3, 2)), Vector::from((5, 7)));
+    assert_eq!(rect, Rect::from((-3, 2, 8, 5)));
+
+    let point = rect_from_min_max(Vector::from((1, 1)), Vector::from((1, 1)));
+    assert_eq!(point, Rect::from((1, 1, 0, 0)));
+}
+
+#[test]
+#[should_panic(expected = "max must not be smaller than min")]
+fn test_rect_from_min_max_rejects_inverted_corners() {
+    let _ = rect_from_min_max(Vector::from((5, 0)), Vector::from((4, 3)));
+}
+
+#[test]
+fn test_rect_from_center_size_matches_hand_constructed() {
+    let rect = rect_from_center_size(Vector::from((0, 0)), Vector::from((4, 6)));
+    assert_eq!(rect, Rect::from((-2, -3, 4, 6)));
+
+    let odd = rect_from_center_size(Vector::from((1, 1)), Vector::from((3, 1)));
+    assert_eq!(
+        odd,
+        Rect {
+            pos: Vector::new(Fp::from(-0.5), Fp::from(0.5)),
+            size: Vector::from((3, 1)),
+        }
+    );
+}
+
+#[test]
+fn test_rect_constructors_with_ray_vs_rect() {
+    let from_corners = rect_from_min_max(Vector::from((8, -1)), Vector::from((10, 1)));
+    let from_center = rect_from_center_size(Vector::from((9, 0)), Vector::from((2, 2)));
+    assert_eq!(from_corners, from_center);
+
+    let result = ray_vs_rect(Vector::from((0, 0)), Vector::from((1, 0)), from_center).unwrap();
+    assert_eq!(result.closest_time, Fp::from(8));
+    assert_eq!(result.contact_point, Vector::from((8, 0)));
+    assert_eq!(result.face, ContactSide::Right);
+}
+
+#[test]
+fn test_clamp_rect_into_each_side() {
+    let bounds = Rect::from((0, 0, 20, 10));
+    let cases = [
+        (
+            Rect::from((-3, 4, 4, 2)),
+            Rect::from((0, 4, 4, 2)),
+            ContactSide::Left,
+        ),
+        (
+            Rect::from((18, 4, 4, 2)),
+            Rect::from((16, 4, 4, 2)),
+            ContactSide::Right,
+        ),
+        (
+            Rect::from((6, -1, 4, 2)),
+            Rect::from((6, 0, 4, 2)),
+            ContactSide::Bottom,
+        ),
+        (
+            Rect::from((6, 9, 4, 2)),
+            Rect::from((6, 8, 4, 2)),
+            ContactSide::Top,
+        ),
+    ];
+
+    for (inner, expected, expected_side) in cases {
+        let (clamped, side) = clamp_rect_into(inner, bounds);
+        assert_eq!(clamped, expected);
+        assert_eq!(side, Some(expected_side));
+    }
+}
+
+#[test]
+fn test_clamp_rect_into_corner_and_inside() {
+    let bounds = Rect::from((0, 0, 20, 10));
+
+    let (clamped, side) = clamp_rect_into(Rect::from((19, -2, 4, 4)), bounds);
+    assert_eq!(clamped, Rect::from((16, 0, 4, 4)));
+    assert_eq!(side, Some(ContactSide::Corner));
+
+    let flush = Rect::from((0, 6, 4, 4));
+    assert_eq!(clamp_rect_into(flush, bounds), (flush, None));
+}
+
+#[test]
+fn test_clamp_rect_into_larger_than_bounds() {
+    let bounds = Rect::from((0, 0, 20, 10));
+
+    let (clamped, side) = clamp_rect_into(Rect::from((-4, 2, 30, 4)), bounds);
+    assert_eq!(clamped, Rect::from((0, 2, 30, 4)));
+    assert_eq!(side, Some(ContactSide::Left));
+
+    let (aligned, side) = clamp_rect_into(Rect::from((0, 2, 30, 4)), bounds);
+    assert_eq!(aligned, Rect::from((0, 2, 30, 4)));
+    assert_eq!(side, None);
+}
+
+#[test]
+fn test_swept_aabb_positive_delta() {
+    let origin = Rect::from((1, 2, 3, 4));
+    assert_eq!(
+        swept_aabb(origin, Vector::from((5, 6))),
+        Rect::from((1, 2, 8, 10))
+    );
+}
+
+#[test]
+fn test_swept_aabb_negative_delta() {
+    let origin = Rect::from((1, 2, 3, 4));
+    assert_eq!(
+        swept_aabb(origin, Vector::from((-5, -1))),
+        Rect::from((-4, 1, 8, 5))
+    );
+
+    // A negative-size origin covers the same region as its normalized form
+    assert_eq!(
+        swept_aabb(Rect::from((4, 6, -3, -4)), Vector::from((-5, -1))),
+        Rect::from((-4, 1, 8, 5))
+    );
+}
+
+#[test]
+fn test_swept_aabb_zero_delta() {
+    let origin = Rect::from((1, 2, 3, 4));
+    assert_eq!(swept_aabb(origin, Vector::from((0, 0))), origin);
+}
+
+#[test]
+fn test_distance_to_rect_rounding_brackets_exact_value() {
+    let rect = Rect::from((-3.5, 2.25, 4.0, 1.5));
+
+    for step in -20..=20 {
+        let point = Vector::from((step as f32 * 0.37, 5.0 - step as f32 * 0.29));
+        let closest = clamp_to_rect(point, rect);
+        let exact = f64::from(f32::from(point.x) - f32::from(closest.x))
+            .hypot(f64::from(f32::from(point.y) - f32::from(closest.y)));
+
+        let floor = f64::from(f32::from(distance_to_rect(point, rect, Rounding::Floor)));
+        let ceil = f64::from(f32::from(distance_to_rect(point, rect, Rounding::Ceil)));
+
+        assert!(floor <= exact + 1e-9, "{point:?}");
+        assert!(ceil >= exact - 1e-9, "{point:?}");
+    }
+
+    assert_eq!(
+        distance_to_rect(Vector::from((0, 3)), rect, Rounding::Ceil),
+        Fp::zero()
+    );
+}
+
+#[test]
+fn test_inflate_rect_grows_every_side() {
+    let rect = Rect::from((2, -3, 4, 2));
+
+    assert_eq!(
+        inflate_rect(rect, Fp::from(0.5)),
+        Rect::from((1.5, -3.5, 5.0, 3.0))
+    );
+    assert_eq!(inflate_rect(rect, Fp::zero()), rect);
+    // A negative size is normalized before inflating
+    assert_eq!(
+        inflate_rect(Rect::from((6, -1, -4, -2)), Fp::one()),
+        Rect::from((1, -4, 6, 4))
+    );
+}
+
+#[test]
+fn test_inflate_rect_shrinks_within_bounds() {
+    let rect = Rect::from((0, 0, 10, 6));
+
+    let shrunk = inflate_rect(rect, Fp::from(-2));
+    assert_eq!(shrunk, Rect::from((2, 2, 6, 2)));
+    assert_eq!(inflate_rect(shrunk, Fp::from(2)), rect);
+
+    assert_eq!(inflate_rect(rect, Fp::from(-3)), Rect::from((3, 3, 4, 0)));
+}
+
+#[test]
+fn test_inflate_rect_shrinking_past_zero_clamps_size() {
+    let rect = Rect::from((0, 0, 10, 6));
+
+    assert_eq!(inflate_rect(rect, Fp::from(-4)), Rect::from((4, 3, 2, 0)));
+    assert_eq!(inflate_rect(rect, Fp::from(-100)), Rect::from((5, 3, 0, 0)));
+}
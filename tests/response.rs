@@ -0,0 +1,384 @@
+/*
+ * Copyright (c) Peter Bjorklund. All rights reserved. https://github.com/piot/impact-rs
+ * Licensed under the MIT License. See LICENSE in the project root for license information.
+ */
+
+use fixed32::Fp;
+use fixed32_math::{Rect, Vector};
+use impact_rs::math::approx_eq;
+use impact_rs::prelude::full::*;
+
+#[test]
+fn test_resolve_velocity_elastic_vertical_bounce() {
+    let velocity = Vector::from((0, -10));
+
+    let resolved = resolve_velocity(velocity, Vector::up(), Fp::one(), Fp::zero());
+    assert_eq!(resolved, Vector::from((0, 10)));
+
+    // The sign of the normal does not matter
+    let resolved = resolve_velocity(velocity, Vector::down(), Fp::one(), Fp::zero());
+    assert_eq!(resolved, Vector::from((0, 10)));
+}
+
+#[test]
+fn test_resolve_velocity_high_friction_shallow_impact() {
+    let velocity = Vector::from((10, -1));
+
+    let resolved = resolve_velocity(velocity, Vector::up(), Fp::zero(), Fp::from(0.9));
+
+    assert!(approx_eq(resolved.x, Fp::one(), Fp::from(0.001)));
+    assert_eq!(resolved.y, Fp::zero());
+}
+
+#[test]
+fn test_resolve_velocity_clamps_coefficients() {
+    let velocity = Vector::from((4, -2));
+
+    let clamped = resolve_velocity(velocity, Vector::up(), Fp::from(3), Fp::from(-2));
+    let limits = resolve_velocity(velocity, Vector::up(), Fp::one(), Fp::zero());
+
+    assert_eq!(clamped, limits);
+    assert_eq!(clamped, Vector::from((4, 2)));
+}
+
+#[test]
+fn test_contact_impulse_head_on_bounce() {
+    let velocity = Vector::from((-6, 0));
+    let mass = Fp::from(2);
+
+    let elastic = contact_impulse(velocity, Vector::left(), Fp::one(), mass);
+    assert_eq!(elastic, Vector::from((24, 0)));
+
+    // Without restitution the impulse only cancels the approach
+    let inelastic = contact_impulse(velocity, Vector::right(), Fp::zero(), mass);
+    assert_eq!(inelastic, Vector::from((12, 0)));
+    assert_eq!(velocity + inelastic * (Fp::one() / mass), Vector::default());
+}
+
+#[test]
+fn test_contact_impulse_glancing_contact() {
+    let velocity = Vector::from((10, -1));
+    let restitution = Fp::from(0.5);
+    let mass = Fp::from(4);
+
+    let impulse = contact_impulse(velocity, Vector::up(), restitution, mass);
+    assert_eq!(impulse, Vector::from((0, 6)));
+
+    // Only the normal part of the velocity changes, exactly as with a frictionless response
+    let bounced = velocity + impulse * (Fp::one() / mass);
+    assert_eq!(
+        bounced,
+        resolve_velocity(velocity, Vector::up(), restitution, Fp::zero())
+    );
+}
+
+#[test]
+fn test_safe_position_never_overlaps_target() {
+    let origin = Rect::from((0, 0, 2, 2));
+    let target = Rect::from((9, 1, 4, 4));
+    let delta = Vector::from((12, 3));
+    let result = swept_rect_vs_rect(origin, target, delta).expect("should hit");
+    let contact = origin.move_by(delta * result.closest_time);
+
+    for skin in [0.0, 0.001, 0.1, 0.5] {
+        let stopped = safe_position(origin, delta, &result, Fp::from(skin));
+        assert!(!rect_vs_rect(stopped, target), "skin {skin} overlaps");
+        assert!(stopped.pos.x <= contact.pos.x);
+        assert!(stopped.pos.y <= contact.pos.y);
+    }
+}
+
+#[test]
+fn test_safe_position_backs_off_by_skin_and_clamps_to_origin() {
+    let origin = Rect::from((0, 0, 2, 2));
+    let delta = Vector::from((0, 8));
+    let result = swept_rect_vs_rect(origin, Rect::from((-4, 6, 10, 4)), delta).expect("should hit");
+    let contact_y = result.closest_time * Fp::from(8);
+
+    assert_eq!(
+        safe_position(origin, delta, &result, Fp::from(0.25)).pos.y,
+        contact_y - Fp::from(0.25)
+    );
+    assert_eq!(
+        safe_position(origin, delta, &result, Fp::from(-1)).pos.y,
+        contact_y
+    );
+    assert_eq!(safe_position(origin, delta, &result, Fp::from(20)), origin);
+}
+
+#[test]
+fn test_safe_position_long_delta() {
+    // Squaring the length of this delta does not fit in an `Fp`
+    let origin = Rect::from((0, 0, 2, 2));
+    let delta = Vector::from((400, 0));
+    let result =
+        swept_rect_vs_rect(origin, Rect::from((250, -4, 2, 10)), delta).expect("should hit");
+
+    let stopped = safe_position(origin, delta, &result, Fp::one());
+    assert!(approx_eq(stopped.pos.x, Fp::from(247), Fp::from(0.01)));
+}
+
+#[test]
+fn test_move_axis_separated_slides_out_of_inside_corner() {
+    // A floor made of tiles with a wall on the left, forming an inside corner
+    let level = [
+        Rect::from((0, 0, 4, 4)),
+        Rect::from((4, 0, 4, 4)),
+        Rect::from((8, 0, 4, 4)),
+        Rect::from((-4, 0, 4, 12)),
+    ];
+    // Resting in the corner, touching both the floor and the wall
+    let character = Rect::from((0, 4, 2, 2));
+
+    // Running right with gravity pushing down. The floor contact is at time zero, so a
+    // single sweep along the combined delta would stop the character in the corner.
+    let delta = Vector::from((6, -2));
+
+    let (moved, blocked_x, blocked_y) = move_axis_separated(character, &level, delta);
+    assert_eq!(moved, Rect::from((6, 4, 2, 2)));
+    assert!(!blocked_x);
+    assert!(blocked_y);
+}
+
+#[test]
+fn test_move_axis_separated_blocks_both_axes_into_corner() {
+    let level = [Rect::from((0, 0, 8, 4)), Rect::from((-4, 0, 4, 12))];
+    let character = Rect::from((3, 6, 2, 2));
+
+    let (moved, blocked_x, blocked_y) =
+        move_axis_separated(character, &level, Vector::from((-5, -4)));
+    assert_eq!(moved, Rect::from((0, 4, 2, 2)));
+    assert!(blocked_x);
+    assert!(blocked_y);
+
+    let (free, blocked_x, blocked_y) = move_axis_separated(character, &level, Vector::from((2, 1)));
+    assert_eq!(free, Rect::from((5, 7, 2, 2)));
+    assert!(!blocked_x);
+    assert!(!blocked_y);
+}
+
+#[test]
+fn test_move_axis_separated_falls_along_stacked_wall_tiles() {
+    // A wall made of stacked tiles, flush with the right side of the falling character
+    let wall: Vec<Rect> = (-4..4).map(|i| Rect::from((4, 2 * i, 2, 2))).collect();
+    let character = Rect::from((2, 0, 2, 2));
+
+    let (moved, blocked_x, blocked_y) =
+        move_axis_separated(character, &wall, Vector::from((0, -3)));
+    assert_eq!(moved, Rect::from((2, -3, 2, 2)));
+    assert!(!blocked_x);
+    assert!(!blocked_y);
+
+    // Pushing into the wall while falling still slides down along it
+    let (moved, blocked_x, blocked_y) =
+        move_axis_separated(character, &wall, Vector::from((1, -3)));
+    assert_eq!(moved, Rect::from((2, -3, 2, 2)));
+    assert!(blocked_x);
+    assert!(!blocked_y);
+}
+
+#[test]
+fn test_move_and_slide_events_sequential_contacts() {
+    let targets = [
+        Rect::from((0, 0, 7, 4)),
+        Rect::from((7, 0, 7, 4)),
+        Rect::from((9, 0, 2, 10)),
+    ];
+    let origin = Rect::from((0, 6, 2, 2));
+    let delta = Vector::from((8, -4));
+
+    let events = move_and_slide_events(origin, &targets, delta);
+    assert_eq!(
+        events.as_slice(),
+        [
+            (Fp::from(0.5), 0, ContactSide::Bottom),
+            (Fp::from(0.875), 2, ContactSide::Right),
+        ]
+    );
+    assert!(events
+        .as_slice()
+        .windows(2)
+        .all(|pair| pair[0].0 < pair[1].0));
+
+    // Slides over the seam between the floor tiles without snagging
+    assert_eq!(
+        move_and_slide(origin, &targets, delta),
+        Rect::from((7, 4, 2, 2))
+    );
+}
+
+#[test]
+fn test_move_and_slide_without_contacts() {
+    let targets = [Rect::from((10, 10, 2, 2))];
+    let origin = Rect::from((0, 0, 2, 2));
+
+    assert!(move_and_slide_events(origin, &targets, Vector::from((3, 1))).is_empty());
+    assert_eq!(
+        move_and_slide(origin, &targets, Vector::from((3, 1))),
+        Rect::from((3, 1, 2, 2))
+    );
+}
+
+#[test]
+fn test_move_and_ground_landing_exactly_on_floor() {
+    let floor = [Rect::from((0, 0, 4, 4)), Rect::from((4, 0, 4, 4))];
+
+    let (moved, grounded) = move_and_ground(
+        Rect::from((1, 7, 2, 2)),
+        &floor,
+        Vector::from((2, -3)),
+        Fp::from(0.25),
+    );
+    assert_eq!(moved, Rect::from((3, 4, 2, 2)));
+    assert!(grounded);
+}
+
+#[test]
+fn test_move_and_ground_hovering_above_floor() {
+    let floor = [Rect::from((0, 0, 8, 4))];
+    let probe = Fp::from(0.25);
+
+    // Half a unit above the floor is out of reach of the probe
+    let (moved, grounded) = move_and_ground(
+        Rect::from((1, 6, 2, 2)),
+        &floor,
+        Vector::from((1.0, -1.5)),
+        probe,
+    );
+    assert_eq!(moved, Rect::from((2.0, 4.5, 2.0, 2.0)));
+    assert!(!grounded);
+
+    // An eighth of a unit above it is within reach
+    let (_, grounded) = move_and_ground(
+        Rect::from((1, 6, 2, 2)),
+        &floor,
+        Vector::from((1.0, -1.875)),
+        probe,
+    );
+    assert!(grounded);
+
+    let (_, grounded) = move_and_ground(
+        Rect::from((1, 4, 2, 2)),
+        &floor,
+        Vector::from((0, 0)),
+        Fp::zero(),
+    );
+    assert!(!grounded);
+}
+
+#[test]
+fn test_move_and_ground_ignores_walls_beside() {
+    let wall = [Rect::from((4, 0, 2, 20))];
+
+    let (moved, grounded) = move_and_ground(
+        Rect::from((0, 10, 2, 2)),
+        &wall,
+        Vector::from((4, 0)),
+        Fp::one(),
+    );
+    assert_eq!(moved, Rect::from((2, 10, 2, 2)));
+    assert!(!grounded);
+}
+
+#[test]
+fn test_move_and_slide_trace_inside_corner() {
+    let targets = [Rect::from((0, 0, 20, 4)), Rect::from((12, 0, 2, 10))];
+    let origin = Rect::from((0, 6, 2, 2));
+    let delta = Vector::from((16, -4));
+
+    let trace = move_and_slide_trace(origin, &targets, delta, 4);
+    assert_eq!(trace.len(), 2);
+
+    let (landed, floor_contact) = &trace[0];
+    assert_eq!(*landed, Rect::from((8, 4, 2, 2)));
+    let floor_contact = floor_contact
+        .as_ref()
+        .expect("landing redirects the motion");
+    assert_eq!(floor_contact.face, ContactSide::Bottom);
+    assert_eq!(floor_contact.closest_time, Fp::from(0.5));
+
+    let (stopped, wall_contact) = &trace[1];
+    assert_eq!(*stopped, Rect::from((10, 4, 2, 2)));
+    assert_eq!(
+        wall_contact.as_ref().map(|contact| contact.face),
+        Some(ContactSide::Right)
+    );
+
+    assert_eq!(move_and_slide(origin, &targets, delta), *stopped);
+}
+
+#[test]
+fn test_move_and_slide_trace_limits_iterations() {
+    let targets = [Rect::from((0, 0, 20, 4)), Rect::from((12, 0, 2, 10))];
+    let origin = Rect::from((0, 6, 2, 2));
+    let delta = Vector::from((16, -4));
+
+    let trace = move_and_slide_trace(origin, &targets, delta, 1);
+    assert_eq!(trace.len(), 1);
+    assert_eq!(trace[0].0, Rect::from((8, 4, 2, 2)));
+
+    assert!(move_and_slide_trace(origin, &targets, delta, 0).is_empty());
+
+    let free = move_and_slide_trace(origin, &targets, Vector::from((1, 1)), 4);
+    assert_eq!(free.len(), 1);
+    assert_eq!(free[0].0, Rect::from((1, 7, 2, 2)));
+    assert!(free[0].1.is_none());
+}
+
+#[test]
+fn test_move_and_slide_five_floor_tiles_no_snag() {
+    let tiles: Vec<Rect> = (0..5).map(|i| Rect::from((i * 4, 0, 4, 4))).collect();
+    let reversed: Vec<Rect> = tiles.iter().rev().copied().collect();
+    let origin = Rect::from((0, 6, 2, 2));
+
+    // Lands with its right edge exactly on the seam between two tiles, then slides on
+    for targets in [&tiles, &reversed] {
+        let delta = Vector::from((12, -4));
+        assert_eq!(
+            move_and_slide(origin, targets, delta),
+            Rect::from((12, 4, 2, 2))
+        );
+
+        let contacts = move_and_slide_events(origin, targets, delta);
+        assert_eq!(contacts.len(), 1);
+        assert!(contacts.grounded());
+    }
+
+    // Sliding from one end of the row to the other while pushed into the floor
+    for targets in [&tiles, &reversed] {
+        let resting = Rect::from((0, 4, 2, 2));
+        assert_eq!(
+            move_and_slide(resting, targets, Vector::from((18, -1))),
+            Rect::from((18, 4, 2, 2))
+        );
+    }
+}
+
+#[test]
+fn test_move_and_slide_limited_in_v_trap() {
+    // A pit between two walls: the mover hits the right wall and then drops to the floor
+    let targets = [
+        Rect::from((0, 0, 12, 4)),
+        Rect::from((0, 0, 4, 12)),
+        Rect::from((8, 0, 4, 12)),
+    ];
+    let origin = Rect::from((5, 8, 2, 2));
+    let delta = Vector::from((4, -8));
+
+    let (moved, status) = move_and_slide_limited(origin, &targets, delta, 1);
+    assert_eq!(moved, Rect::from((6, 6, 2, 2)));
+    assert_eq!(status, ResolveStatus::IterationLimit);
+    assert!(!status.is_completed());
+
+    let (moved, status) = move_and_slide_limited(origin, &targets, delta, 2);
+    assert_eq!(moved, Rect::from((6, 4, 2, 2)));
+    assert!(status.is_completed());
+    assert_eq!(move_and_slide(origin, &targets, delta), moved);
+
+    let (moved, status) = move_and_slide_limited(origin, &targets, delta, 0);
+    assert_eq!(moved, origin);
+    assert_eq!(status, ResolveStatus::IterationLimit);
+
+    let (_, status) = move_and_slide_limited(origin, &targets, Vector::default(), 0);
+    assert!(status.is_completed());
+}
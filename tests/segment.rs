@@ -0,0 +1,137 @@
+/*
+ * Copyright (c) Peter Bjorklund. All rights reserved. https://github.com/piot/impact-rs
+ * Licensed under the MIT License. See LICENSE in the project root for license information.
+ */
+
+use fixed32::Fp;
+use fixed32_math::{Rect, Vector};
+use impact_rs::prelude::full::*;
+
+#[test]
+fn test_segment_stops_short_of_rect() {
+    let target = Rect::from((10, -2, 4, 4));
+    let segment = Segment::new(Vector::from((0, 0)), Vector::from((8, 0)));
+
+    // The ray along the same direction reaches the rect
+    assert!(ray_vs_rect(segment.start, segment.end - segment.start, target).is_some());
+    assert!(segment.vs_rect(target).is_none());
+    assert_eq!(segment.length(), Fp::from(8));
+}
+
+#[test]
+fn test_segment_crosses_rect() {
+    let target = Rect::from((4, -2, 4, 4));
+    let segment = Segment::new(Vector::from((0, 0)), Vector::from((16, 0)));
+
+    let result = segment.vs_rect(target).expect("should cross");
+    assert_eq!(result.closest_time, Fp::from(0.25));
+    assert_eq!(result.contact_point, Vector::from((4, 0)));
+    assert_eq!(result.contact_normal, Vector::right());
+
+    let from_inside = Segment::new(Vector::from((6, 0)), Vector::from((16, 0)));
+    let result = from_inside.vs_rect(target).expect("starts inside");
+    assert_eq!(result.closest_time, Fp::zero());
+    assert_eq!(result.contact_point, from_inside.start);
+}
+
+#[test]
+fn test_segment_ending_on_rect_face() {
+    let segments = [
+        (Vector::from((0, 0)), Vector::from((8, 0))),
+        (Vector::from((0.3, -7.1)), Vector::from((-5.77, 3.33))),
+        (Vector::from((-2.9, 1.7)), Vector::from((13.13, -0.01))),
+    ];
+
+    for (start, end) in segments {
+        let delta = end - start;
+        // A target whose face along x touches the end point
+        let face_x = if delta.x > Fp::zero() {
+            end.x
+        } else {
+            end.x - Fp::from(4)
+        };
+        let target = Rect {
+            pos: Vector::new(face_x, end.y - Fp::from(2)),
+            size: Vector::from((4, 4)),
+        };
+
+        let result = Segment::new(start, end)
+            .vs_rect(target)
+            .expect("should touch at the end");
+        assert_eq!(result.closest_time, Fp::one());
+        assert_eq!(result.contact_point, end);
+
+        // Ending a raw unit short of the face rounds to a contact at the end, clamped to it
+        let short_end = end - Vector::new(Fp::from_raw(delta.x.inner().signum()), Fp::zero());
+        let result = Segment::new(start, short_end)
+            .vs_rect(target)
+            .expect("rounds to a contact at the end");
+        assert_eq!(result.closest_time, Fp::one());
+        assert_eq!(result.contact_point, short_end);
+
+        let far_short_end = end - Vector::new(delta.x / Fp::from(8), Fp::zero());
+        assert!(Segment::new(start, far_short_end).vs_rect(target).is_none());
+    }
+}
+
+#[test]
+fn test_segment_vs_segment() {
+    let a = Segment::new(Vector::from((0, 0)), Vector::from((8, 0)));
+
+    let crossing = Segment::new(Vector::from((2, -2)), Vector::from((2, 2)));
+    assert_eq!(
+        a.vs_segment(crossing),
+        Some((Fp::from(0.25), Vector::from((2, 0))))
+    );
+
+    let touching_end = Segment::new(Vector::from((8, 0)), Vector::from((8, 4)));
+    assert_eq!(a.vs_segment(touching_end), Some((Fp::one(), a.end)));
+
+    let too_short = Segment::new(Vector::from((2, 1)), Vector::from((2, 4)));
+    assert!(a.vs_segment(too_short).is_none());
+
+    let parallel = Segment::new(Vector::from((0, 1)), Vector::from((8, 1)));
+    assert!(a.vs_segment(parallel).is_none());
+}
+
+#[test]
+fn test_long_segment_length_and_crossing() {
+    let a = Segment::new(Vector::from((0, 0)), Vector::from((300, 0)));
+    assert_eq!(a.length(), Fp::from(300));
+    assert_eq!(
+        Segment::new(Vector::from((0, 0)), Vector::from((180, 240))).length(),
+        Fp::from(300)
+    );
+
+    let crossing = Segment::new(Vector::from((75, -200)), Vector::from((75, 200)));
+    assert_eq!(
+        a.vs_segment(crossing),
+        Some((Fp::from(0.25), Vector::from((75, 0))))
+    );
+
+    let diagonal = Segment::new(Vector::from((0, 0)), Vector::from((200, 200)));
+    let other_diagonal = Segment::new(Vector::from((0, 200)), Vector::from((200, 0)));
+    assert_eq!(
+        diagonal.vs_segment(other_diagonal),
+        Some((Fp::from(0.5), Vector::from((100, 100))))
+    );
+
+    let too_short = Segment::new(Vector::from((75, 10)), Vector::from((75, 250)));
+    assert!(a.vs_segment(too_short).is_none());
+}
+
+#[test]
+fn test_segment_from_tuples() {
+    let start = Vector::from((0, 0));
+    let end = Vector::from((16, 0));
+
+    assert_eq!(Segment::from((start, end)), Segment::new(start, end));
+    assert_eq!(Segment::from(((0, 0), (16, 0))), Segment::new(start, end));
+
+    let target = Rect::from((4, -2, 4, 4));
+    let result = Segment::from(((0, 0), (16, 0)))
+        .vs_rect(target)
+        .expect("should cross");
+    assert_eq!(result.closest_time, Fp::from(0.25));
+    assert!(Segment::from(((0, 0), (2, 0))).vs_rect(target).is_none());
+}
@@ -0,0 +1,301 @@
+/*
+ * Copyright (c) Peter Bjorklund. All rights reserved. https://github.com/piot/impact-rs
+ * Licensed under the MIT License. See LICENSE in the project root for license information.
+ */
+
+use fixed32::Fp;
+use fixed32_math::{Rect, Vector};
+use impact_rs::prelude::full::*;
+use impact_rs::tiles::{
+    merge_tiles, raycast_grid, swept_rect_tiles, swept_rect_vs_tilemap, tile_to_rect,
+    world_to_tile, GridRayIter,
+};
+
+#[test]
+fn test_world_to_tile_crossing_origin() {
+    let tile_size = Fp::one();
+
+    assert_eq!(
+        world_to_tile(Vector::from((-0.5, -0.5)), tile_size),
+        (-1, -1)
+    );
+    assert_eq!(world_to_tile(Vector::from((0.5, 0.5)), tile_size), (0, 0));
+    assert_eq!(world_to_tile(Vector::from((0.0, 0.0)), tile_size), (0, 0));
+    assert_eq!(world_to_tile(Vector::from((-1.0, 1.0)), tile_size), (-1, 1));
+    assert_eq!(
+        world_to_tile(Vector::from((-1.5, -2.0)), tile_size),
+        (-2, -2)
+    );
+}
+
+#[test]
+fn test_world_to_tile_non_unit_size() {
+    let tile_size = Fp::from(16);
+
+    assert_eq!(
+        world_to_tile(Vector::from((15.9, -0.01)), tile_size),
+        (0, -1)
+    );
+    assert_eq!(
+        world_to_tile(Vector::from((-16.0, -16.01)), tile_size),
+        (-1, -2)
+    );
+    assert_eq!(world_to_tile(Vector::from((33.0, 47.0)), tile_size), (2, 2));
+}
+
+#[test]
+fn test_world_to_tile_just_below_negative_boundary() {
+    let tile_size = Fp::from(3);
+    let just_below = Vector::new(Fp::from(-3) - Fp::from_raw(1), Fp::zero());
+
+    assert_eq!(world_to_tile(just_below, tile_size), (-2, 0));
+}
+
+#[test]
+fn test_tile_to_rect_round_trip() {
+    let tile_size = Fp::from(8);
+
+    for (tx, ty) in [(0, 0), (-1, -1), (3, -2), (-4, 5)] {
+        let rect = tile_to_rect(tx, ty, tile_size);
+        assert_eq!(rect.size, Vector::new(tile_size, tile_size));
+        assert_eq!(world_to_tile(rect.pos, tile_size), (tx, ty));
+        assert_eq!(
+            world_to_tile(
+                rect.pos + rect.size - Vector::new(Fp::from_raw(1), Fp::from_raw(1)),
+                tile_size
+            ),
+            (tx, ty)
+        );
+    }
+
+    assert_eq!(tile_to_rect(-1, 0, tile_size), Rect::from((-8, 0, 8, 8)));
+}
+
+fn brute_force_sweep(
+    origin: Rect,
+    delta: Vector,
+    tile_size: Fp,
+    solid_tiles: &[(i32, i32)],
+) -> Option<Fp> {
+    solid_tiles
+        .iter()
+        .filter_map(|(tx, ty)| swept_rect_vs_rect(origin, tile_to_rect(*tx, *ty, tile_size), delta))
+        .map(|result| result.closest_time)
+        .min()
+}
+
+#[test]
+fn test_swept_rect_vs_tilemap_horizontal_slide_into_solid_row() {
+    let tile_size = Fp::one();
+    // Solid tiles from x = 6 onwards in the row the mover travels through
+    let solid_tiles: Vec<(i32, i32)> = (6..12).map(|tx| (tx, 0)).collect();
+    let is_solid = |tx: i32, ty: i32| solid_tiles.contains(&(tx, ty));
+
+    let origin = Rect::from((0.0, 0.25, 0.5, 0.5));
+    let delta = Vector::from((8.0, 0.0));
+
+    let result = swept_rect_vs_tilemap(origin, delta, tile_size, is_solid)
+        .expect("should hit the solid row");
+
+    assert_eq!(result.contact_normal, Vector::right());
+    assert_eq!(
+        Some(result.closest_time),
+        brute_force_sweep(origin, delta, tile_size, &solid_tiles)
+    );
+
+    let short = swept_rect_vs_tilemap(origin, Vector::from((2.0, 0.0)), tile_size, is_solid);
+    assert!(short.is_none());
+}
+
+#[test]
+fn test_swept_rect_vs_tilemap_diagonal_into_corner_block() {
+    let tile_size = Fp::from(2);
+    let solid_tiles = [(3, 3), (4, 3), (3, 4), (-3, -3)];
+    let is_solid = |tx: i32, ty: i32| solid_tiles.contains(&(tx, ty));
+
+    let origin = Rect::from((0.0, 0.5, 1.0, 1.0));
+    let delta = Vector::from((8.0, 7.0));
+
+    let result = swept_rect_vs_tilemap(origin, delta, tile_size, is_solid)
+        .expect("should hit the corner block");
+
+    assert!(result.closest_time >= Fp::zero() && result.closest_time < Fp::one());
+    assert!(result.contact_normal.x >= Fp::zero() && result.contact_normal.y >= Fp::zero());
+    assert_eq!(
+        Some(result.closest_time),
+        brute_force_sweep(origin, delta, tile_size, &solid_tiles)
+    );
+}
+
+#[test]
+fn test_swept_rect_vs_tilemap_empty_map() {
+    let origin = Rect::from((-3, -3, 1, 1));
+
+    assert!(
+        swept_rect_vs_tilemap(origin, Vector::from((20, -9)), Fp::one(), |_, _| false).is_none()
+    );
+}
+
+/// Finds the tiles hit by a ray by testing every tile in a window with `ray_vs_rect`.
+fn brute_force_ray_tiles(origin: Vector, direction: Vector, tile_size: Fp) -> Vec<(i32, i32)> {
+    let mut hits = Vec::new();
+    for ty in -20..=20 {
+        for tx in -20..=20 {
+            let tile = tile_to_rect(tx, ty, tile_size);
+            if let Some(result) = ray_vs_rect(origin, direction, tile) {
+                hits.push((result.closest_time, (tx, ty)));
+            }
+        }
+    }
+    hits.sort_by_key(|(time, _)| *time);
+    hits.into_iter().map(|(_, tile)| tile).collect()
+}
+
+#[test]
+fn test_grid_ray_iter_matches_raycast_grid_and_brute_force() {
+    let tile_size = Fp::from(2);
+    let cases = [
+        (Vector::from((0.5, 0.25)), Vector::from((3.0, 1.25))),
+        (Vector::from((-1.5, 3.5)), Vector::from((2.0, -5.0))),
+        (Vector::from((7.25, -0.75)), Vector::from((-4.0, -1.5))),
+        (Vector::from((1.0, 1.0)), Vector::from((0.0, -3.0))),
+    ];
+
+    for (origin, direction) in cases {
+        let streamed: Vec<_> = GridRayIter::new(origin, direction, tile_size)
+            .take(6)
+            .collect();
+
+        assert_eq!(streamed, raycast_grid(origin, direction, tile_size, 6));
+        assert_eq!(
+            streamed,
+            brute_force_ray_tiles(origin, direction, tile_size)[..6],
+            "{origin:?} {direction:?}"
+        );
+    }
+}
+
+#[test]
+fn test_grid_ray_iter_diagonal_skips_corner_tiles() {
+    let tiles: Vec<_> = GridRayIter::new(Vector::from((0.5, 0.5)), Vector::from((1, 1)), Fp::one())
+        .take(3)
+        .collect();
+
+    assert_eq!(tiles, [(0, 0), (1, 1), (2, 2)]);
+}
+
+#[test]
+fn test_grid_ray_iter_stops_early_and_zero_direction() {
+    let first_solid = GridRayIter::new(Vector::from((0.5, 0.5)), Vector::from((1, 0)), Fp::one())
+        .find(|&(tx, _)| tx >= 40);
+    assert_eq!(first_solid, Some((40, 0)));
+
+    let still: Vec<_> =
+        GridRayIter::new(Vector::from((-3.5, 2.5)), Vector::from((0, 0)), Fp::one()).collect();
+    assert_eq!(still, [(-4, 2)]);
+}
+
+#[test]
+fn test_swept_rect_tiles_horizontal_three_tiles() {
+    let tile_size = Fp::from(4);
+    let origin = Rect::from((1, 1, 2, 2));
+
+    assert_eq!(
+        swept_rect_tiles(origin, Vector::from((8, 0)), tile_size),
+        [(0, 0), (1, 0), (2, 0)]
+    );
+    assert_eq!(
+        swept_rect_tiles(origin, Vector::from((-8, 0)), tile_size),
+        [(-2, 0), (-1, 0), (0, 0)]
+    );
+}
+
+#[test]
+fn test_swept_rect_tiles_diagonal() {
+    let tiles = swept_rect_tiles(
+        Rect::from((0.5, 0.5, 1.0, 1.0)),
+        Vector::from((1.0, -1.5)),
+        Fp::one(),
+    );
+
+    assert_eq!(
+        tiles,
+        [
+            (0, -1),
+            (1, -1),
+            (2, -1),
+            (0, 0),
+            (1, 0),
+            (2, 0),
+            (0, 1),
+            (1, 1),
+            (2, 1)
+        ]
+    );
+
+    let mut deduplicated = tiles.clone();
+    deduplicated.sort_unstable();
+    deduplicated.dedup();
+    assert_eq!(deduplicated.len(), tiles.len());
+}
+
+/// Checks that `merged` covers every tile of `solid` exactly once and nothing else.
+fn assert_exact_cover(solid: &[(i32, i32)], merged: &[Rect], tile_size: Fp) {
+    let half = Vector::new(tile_size, tile_size) / 2;
+
+    for &(tx, ty) in solid {
+        let center = tile_to_rect(tx, ty, tile_size).pos + half;
+        let covering = merged
+            .iter()
+            .filter(|rect| point_in_rect(center, **rect))
+            .count();
+        assert_eq!(covering, 1, "tile {tx}, {ty}");
+    }
+
+    let tiles_covered: i32 = merged
+        .iter()
+        .map(|rect| {
+            (rect.size.x.inner() / tile_size.inner()) * (rect.size.y.inner() / tile_size.inner())
+        })
+        .sum();
+    assert_eq!(tiles_covered as usize, solid.len());
+
+    for (index, a) in merged.iter().enumerate() {
+        for b in &merged[index + 1..] {
+            assert!(!rect_vs_rect(*a, *b), "{a:?} overlaps {b:?}");
+        }
+    }
+}
+
+#[test]
+fn test_merge_tiles_solid_block() {
+    let tile_size = Fp::from(2);
+    let solid: Vec<(i32, i32)> = (-3..2)
+        .flat_map(|ty| (4..10).map(move |tx| (tx, ty)))
+        .rev()
+        .collect();
+
+    let merged = merge_tiles(&solid, tile_size);
+    assert_eq!(merged, [Rect::from((8, -6, 12, 10))]);
+    assert_exact_cover(&solid, &merged, tile_size);
+}
+
+#[test]
+fn test_merge_tiles_l_shape() {
+    let tile_size = Fp::from(4);
+    // A three tile wide floor with a column rising from its left end
+    let solid = [(0, 3), (0, 0), (1, 0), (2, 0), (0, 1), (0, 2), (1, 0)];
+
+    let merged = merge_tiles(&solid, tile_size);
+    assert_eq!(
+        merged,
+        [Rect::from((0, 0, 12, 4)), Rect::from((0, 4, 4, 12))]
+    );
+    assert_exact_cover(
+        &[(0, 0), (1, 0), (2, 0), (0, 1), (0, 2), (0, 3)],
+        &merged,
+        tile_size,
+    );
+
+    assert!(merge_tiles(&[], tile_size).is_empty());
+}
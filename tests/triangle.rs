@@ -0,0 +1,116 @@
+/*
+ * Copyright (c) Peter Bjorklund. All rights reserved. https://github.com/piot/impact-rs
+ * Licensed under the MIT License. See LICENSE in the project root for license information.
+ */
+
+use std::f32::consts::FRAC_1_SQRT_2;
+
+use fixed32::Fp;
+use fixed32_math::{Rect, Vector};
+use impact_rs::math::approx_eq;
+use impact_rs::prelude::full::*;
+
+/// A 45 degree slope tile rising to the right.
+fn slope() -> (Vector, Vector, Vector) {
+    (
+        Vector::from((0, 0)),
+        Vector::from((10, 0)),
+        Vector::from((10, 10)),
+    )
+}
+
+fn assert_close(a: Fp, b: Fp) {
+    assert!(approx_eq(a, b, Fp::from(0.01)), "{a:?} != {b:?}");
+}
+
+#[test]
+fn test_landing_on_slope() {
+    let (a, b, c) = slope();
+    let falling = Rect::from((2, 8, 2, 2));
+
+    let result = swept_rect_vs_triangle(falling, a, b, c, Vector::from((0, -10)))
+        .expect("should land on the slope");
+
+    // The lower-right corner (4, y) touches the slope y = x when the bottom is at y = 4
+    assert_close(result.closest_time, Fp::from(0.4));
+    assert_close(result.contact_normal.x, Fp::from(FRAC_1_SQRT_2));
+    assert_close(result.contact_normal.y, Fp::from(-FRAC_1_SQRT_2));
+}
+
+#[test]
+fn test_landing_on_slope_any_winding() {
+    let (a, b, c) = slope();
+    let falling = Rect::from((2, 8, 2, 2));
+    let delta = Vector::from((0, -10));
+
+    let ccw = swept_rect_vs_triangle(falling, a, b, c, delta).expect("should land");
+    let cw = swept_rect_vs_triangle(falling, a, c, b, delta).expect("should land");
+
+    assert_eq!(ccw.closest_time, cw.closest_time);
+    assert_eq!(ccw.contact_normal, cw.contact_normal);
+}
+
+#[test]
+fn test_sliding_down_slope() {
+    let (a, b, c) = slope();
+    // Resting on the slope: the lower-right corner is at (6, 6) on the surface
+    let resting = Rect::from((4, 6, 2, 2));
+
+    let parallel = swept_rect_vs_triangle(resting, a, b, c, Vector::from((-3, -3)));
+    assert!(
+        parallel.is_none(),
+        "sliding along the surface should be free"
+    );
+
+    let pressing =
+        swept_rect_vs_triangle(Rect::from((4, 7, 2, 2)), a, b, c, Vector::from((-3, -5)))
+            .expect("pressing into the slope while sliding should touch it");
+    assert!(pressing.contact_normal.x > Fp::zero());
+    assert!(pressing.contact_normal.y < Fp::zero());
+}
+
+#[test]
+fn test_walking_into_slope_from_flat_ground() {
+    let (a, b, c) = slope();
+    let walker = Rect::from((11, 0, 2, 2));
+
+    assert!(swept_rect_vs_triangle(walker, a, b, c, Vector::from((2, 0))).is_none());
+
+    let result = swept_rect_vs_triangle(walker, a, b, c, Vector::from((-4, 0)))
+        .expect("should hit the vertical side");
+    assert_eq!(result.contact_normal, Vector::left());
+    assert_close(result.closest_time, Fp::from(0.25));
+}
+
+#[test]
+fn test_landing_on_slope_with_tiny_horizontal_delta() {
+    let (a, b, c) = slope();
+    let falling = Rect::from((2, 20, 2, 2));
+    let tiny = Fp::from_raw(1);
+
+    assert!(swept_rect_vs_triangle(falling, a, b, c, Vector::new(tiny, Fp::from(-15))).is_none());
+
+    let result = swept_rect_vs_triangle(falling, a, b, c, Vector::new(tiny, Fp::from(-18)))
+        .expect("should land on the slope");
+    assert_close(result.closest_time, Fp::from(16.0 / 18.0));
+    assert_close(result.contact_normal.x, Fp::from(FRAC_1_SQRT_2));
+}
+
+#[test]
+fn test_landing_on_long_slope() {
+    // A slope with 300 unit legs, whose edges do not fit in an `Fp` when squared
+    let (a, b, c) = (
+        Vector::from((0, 0)),
+        Vector::from((300, 0)),
+        Vector::from((300, 300)),
+    );
+    let falling = Rect::from((148, 200, 2, 2));
+
+    let result = swept_rect_vs_triangle(falling, a, b, c, Vector::from((0, -100)))
+        .expect("should land on the slope");
+
+    // The lower-right corner (150, y) touches the slope y = x when the bottom is at y = 150
+    assert_close(result.closest_time, Fp::from(0.5));
+    assert_close(result.contact_normal.x, Fp::from(FRAC_1_SQRT_2));
+    assert_close(result.contact_normal.y, Fp::from(-FRAC_1_SQRT_2));
+}
@@ -0,0 +1,105 @@
+/*
+ * Copyright (c) Peter Bjorklund. All rights reserved. https://github.com/piot/impact-rs
+ * Licensed under the MIT License. See LICENSE in the project root for license information.
+ */
+
+use std::f32::consts::{FRAC_PI_2, PI};
+
+use fixed32::Fp;
+use fixed32_math::Vector;
+use impact_rs::math::{approx_eq, approx_eq_vector};
+use impact_rs::trig::{cos, rotate, sin};
+
+/// Angles like `π/2` are not exactly representable, so results can be a few raw units off.
+const NEAR: Fp = Fp::from_raw(4);
+
+fn assert_near(actual: Fp, expected: Fp, message: &str) {
+    assert!(
+        approx_eq(actual, expected, NEAR),
+        "{message}: {actual:?} != {expected:?}"
+    );
+}
+
+fn assert_vector_near(actual: Vector, expected: Vector) {
+    assert!(
+        approx_eq_vector(actual, expected, NEAR),
+        "{actual:?} != {expected:?}"
+    );
+}
+
+#[test]
+fn test_sin_cos_quarter_turns() {
+    let cases = [
+        (0.0, 0, 1),
+        (FRAC_PI_2, 1, 0),
+        (PI, 0, -1),
+        (3.0 * FRAC_PI_2, -1, 0),
+    ];
+
+    for (angle, expected_sin, expected_cos) in cases {
+        assert_near(sin(Fp::from(angle)), Fp::from(expected_sin), "sin");
+        assert_near(cos(Fp::from(angle)), Fp::from(expected_cos), "cos");
+        assert_near(
+            sin(Fp::from(-angle)),
+            -Fp::from(expected_sin),
+            "sin of negative",
+        );
+    }
+}
+
+#[test]
+fn test_sin_precision_and_wrapping() {
+    let tolerance = Fp::from_raw(2);
+
+    for step in -200..=200 {
+        let angle = step as f32 * 0.05;
+        let fp_angle = Fp::from(angle);
+        let exact = Fp::from(f32::from(fp_angle).sin());
+
+        assert!(approx_eq(sin(fp_angle), exact, tolerance), "sin {angle}");
+        assert!(
+            approx_eq(
+                cos(fp_angle),
+                Fp::from(f32::from(fp_angle).cos()),
+                tolerance
+            ),
+            "cos {angle}"
+        );
+    }
+}
+
+#[test]
+fn test_rotate_quarter_turns() {
+    let v = Vector::from((3, 1));
+
+    assert_eq!(rotate(v, Fp::zero()), v);
+    assert_vector_near(rotate(v, Fp::from(FRAC_PI_2)), Vector::from((-1, 3)));
+    assert_vector_near(rotate(v, Fp::from(PI)), Vector::from((-3, -1)));
+    assert_vector_near(rotate(v, Fp::from(3.0 * FRAC_PI_2)), Vector::from((1, -3)));
+}
+
+/// The largest difference between `sin`/`cos` of `raw` and the exact values, in world units.
+fn worst_error(raws: impl Iterator<Item = i32>) -> f64 {
+    raws.map(|raw| {
+        let angle = f64::from(raw) / f64::from(Fp::SCALE);
+        let error =
+            |value: Fp, exact: f64| (f64::from(value.inner()) / f64::from(Fp::SCALE) - exact).abs();
+        error(sin(Fp::from_raw(raw)), angle.sin()).max(error(cos(Fp::from_raw(raw)), angle.cos()))
+    })
+    .fold(0.0, f64::max)
+}
+
+#[test]
+fn test_sin_cos_documented_precision() {
+    let half_pi = (std::f64::consts::FRAC_PI_2 * f64::from(Fp::SCALE)) as i32;
+
+    // The polynomial error below 2^-17 plus rounding to the nearest raw unit
+    let reduced = worst_error((-half_pi..=half_pi).step_by(7));
+    assert!(reduced <= 2.0_f64.powi(-17) * 2.0, "{reduced}");
+
+    // Every angle, including the ones that are reduced by many turns
+    let near_zero = worst_error((-20 * half_pi..=20 * half_pi).step_by(13));
+    let whole_range = worst_error((i32::MIN..=i32::MAX).step_by(9973));
+    assert!(near_zero <= 2.0_f64.powi(-15), "{near_zero}");
+    assert!(whole_range <= 2.0_f64.powi(-15), "{whole_range}");
+}
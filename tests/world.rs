@@ -0,0 +1,411 @@
+/*
+ * Copyright (c) Peter Bjorklund. All rights reserved. https://github.com/piot/impact-rs
+ * Licensed under the MIT License. See LICENSE in the project root for license information.
+ */
+
+use fixed32::Fp;
+use fixed32_math::{Rect, Vector};
+use impact_rs::math::{approx_eq, length, Rounding};
+use impact_rs::prelude::full::*;
+use impact_rs::tiles::tile_to_rect;
+
+#[test]
+fn test_query_point_inside_one_collider() {
+    let mut world = World::new();
+    let left = world.insert(Rect::from((0, 0, 4, 4)));
+    let right = world.insert(Rect::from((4, 0, 4, 4)));
+
+    assert_eq!(world.query_point(Vector::from((1, 1))), Some(left));
+    assert_eq!(world.query_point(Vector::from((4, 1))), Some(right));
+    assert_eq!(world.query_point_all(Vector::from((4, 1))), vec![right]);
+}
+
+#[test]
+fn test_query_point_stacked_colliders_topmost_first() {
+    let mut world = World::new();
+    let floor = world.insert(Rect::from((0, 0, 20, 4)));
+    let crate_box = world.insert(Rect::from((2, 2, 2, 2)));
+
+    let point = Vector::from((3, 3));
+    assert_eq!(world.query_point(point), Some(crate_box));
+    assert_eq!(world.query_point_all(point), vec![crate_box, floor]);
+
+    assert_eq!(
+        world.remove(crate_box),
+        Some(ColliderShape::Rect(Rect::from((2, 2, 2, 2))))
+    );
+    assert_eq!(world.query_point(point), Some(floor));
+}
+
+#[test]
+fn test_query_point_far_from_circle() {
+    let mut world = World::new();
+    let circle = world.insert(Circle::new(Vector::from((0, 0)), Fp::from(5)));
+
+    assert_eq!(world.query_point(Vector::from((3, 4))), Some(circle));
+    assert_eq!(world.query_point(Vector::from((200, 0))), None);
+    assert_eq!(world.query_point(Vector::from((300, 300))), None);
+}
+
+#[test]
+fn test_query_point_empty_space() {
+    let mut world = World::new();
+    assert_eq!(world.query_point(Vector::from((0, 0))), None);
+
+    world.insert(Rect::from((0, 0, 4, 4)));
+    assert_eq!(world.query_point(Vector::from((10, 10))), None);
+    assert!(world.query_point_all(Vector::from((10, 10))).is_empty());
+}
+
+#[test]
+fn test_query_aabb_region_overlapping_several_colliders() {
+    let mut world = World::new();
+    let floor = world.insert(Rect::from((0, 0, 20, 2)));
+    let far_away = world.insert(Rect::from((40, 0, 4, 4)));
+    let wall = world.insert(Rect::from((8, 2, 2, 8)));
+    let touching = world.insert(Rect::from((12, 2, 2, 2)));
+
+    let selected = world.query_aabb(Rect::from((6, 1, 6, 4)));
+    assert_eq!(selected, vec![floor, wall]);
+    assert!(!selected.contains(&far_away));
+    assert!(!selected.contains(&touching));
+
+    // A selection dragged from the top right covers the same region
+    assert_eq!(world.query_aabb(Rect::from((12, 5, -6, -4))), selected);
+}
+
+#[test]
+fn test_query_aabb_empty_region() {
+    let mut world = World::new();
+    world.insert(Rect::from((0, 0, 4, 4)));
+    world.insert(Rect::from((8, 0, 4, 4)));
+
+    assert!(world.query_aabb(Rect::from((4, 0, 4, 4))).is_empty());
+    assert!(World::new().query_aabb(Rect::from((0, 0, 4, 4))).is_empty());
+}
+
+#[test]
+fn test_raycast_mixed_shapes_picks_nearest() {
+    let ray_origin = Vector::from((0, 0));
+    let ray_direction = Vector::from((1, 0));
+
+    let mut world = World::new();
+    let rect = world.insert(Rect::from((30, -2, 4, 4)));
+    let obb = world.insert(Obb::new(
+        Vector::from((20, 0)),
+        Vector::from((2, 2)),
+        Fp::from(0.3),
+    ));
+    let circle = world.insert(Circle::new(Vector::from((40, 0)), Fp::from(2)));
+
+    let (id, result) = world
+        .raycast(ray_origin, ray_direction)
+        .expect("should hit");
+    assert_eq!(id, obb);
+    // The box is rotated, so the left face is tilted and the normal is not axis aligned
+    assert!(result.closest_time < Fp::from(18));
+    assert_eq!(result.contact_point.y, Fp::zero());
+    assert!(result.contact_normal.x > Fp::zero());
+    assert!(result.contact_normal.y > Fp::zero());
+    assert_eq!(result.face, ContactSide::Right);
+
+    world.remove(obb);
+    assert_eq!(
+        world.raycast(ray_origin, ray_direction).map(|(id, _)| id),
+        Some(rect)
+    );
+
+    world.remove(rect);
+    let (id, result) = world
+        .raycast(ray_origin, ray_direction)
+        .expect("should hit");
+    assert_eq!(id, circle);
+    assert_eq!(result.closest_time, Fp::from(38));
+}
+
+#[test]
+fn test_raycast_rotated_box_normal_in_world_space() {
+    let mut world = World::new();
+    // Rotated a quarter turn, the local x-axis points up
+    let obb = world.insert(Obb {
+        center: Vector::from((0, 10)),
+        half_size: Vector::from((1, 3)),
+        axis: Vector::up(),
+    });
+
+    let (id, result) = world
+        .raycast(Vector::from((0, 0)), Vector::from((0, 1)))
+        .expect("should hit");
+    assert_eq!(id, obb);
+    assert_eq!(result.closest_time, Fp::from(9));
+    assert_eq!(result.contact_normal, Vector::up());
+    assert_eq!(result.face, ContactSide::Top);
+
+    assert!(world
+        .raycast(Vector::from((5, 0)), Vector::from((0, 1)))
+        .is_none());
+    assert_eq!(world.query_point(Vector::from((2.5, 10.0))), Some(obb));
+}
+
+#[test]
+fn test_restore_snapshot_undoes_mutations() {
+    let mut world = World::new();
+    let wall = world.insert(Rect::from((10, -2, 4, 4)));
+    let _ball = world.insert(Circle::new(Vector::from((20, 0)), Fp::from(2)));
+    let _post = world.insert(Obb::new(
+        Vector::from((30, 0)),
+        Vector::from((1, 3)),
+        Fp::from(0.3),
+    ));
+
+    let origin = Vector::from((0, 0));
+    let direction = Vector::from((1, 0));
+    let (before_id, before) = world.raycast(origin, direction).unwrap();
+    let snapshot = world.snapshot();
+
+    world.remove(wall);
+    let blocker = world.insert(Rect::from((5, -2, 1, 4)));
+    assert_eq!(world.raycast(origin, direction).unwrap().0, blocker);
+
+    world.restore(&snapshot);
+    let (after_id, after) = world.raycast(origin, direction).unwrap();
+    assert_eq!(after_id, before_id);
+    assert_eq!(after.closest_time, before.closest_time);
+    assert_eq!(after.contact_point, before.contact_point);
+    assert_eq!(after.contact_normal, before.contact_normal);
+    assert_eq!(world.snapshot(), snapshot);
+    assert_eq!(world.insert(Rect::from((0, 0, 1, 1))), blocker);
+}
+
+#[test]
+fn test_swept_rect_rests_skin_width_from_surface() {
+    let mut world = World::new();
+    let floor = world.insert(Rect::from((-10, -4, 20, 4)));
+    let wall = world.insert(Rect::from((6, 0, 2, 10)));
+
+    let origin = Rect::from((0, 5, 2, 2));
+    let fall = Vector::from((0, -10));
+    let slide = Vector::from((8, 0));
+
+    // No skin by default, so the result matches the plain sweep
+    let (id, result) = world.swept_rect(origin, fall).expect("should land");
+    assert_eq!(id, floor);
+    assert_eq!(
+        origin.move_by(fall * result.closest_time).bottom(),
+        Fp::zero()
+    );
+
+    let skin = Fp::from(0.25);
+    world.set_skin_width(skin);
+    assert_eq!(world.skin_width(), skin);
+
+    let (id, result) = world.swept_rect(origin, fall).expect("should land");
+    assert_eq!(id, floor);
+    let landed = origin.move_by(fall * result.closest_time);
+    assert!(landed.bottom() >= skin);
+    assert!(approx_eq(landed.bottom(), skin, Fp::from(0.001)));
+    assert_eq!(result.contact_point, landed.pos + landed.size);
+
+    let (id, result) = world
+        .swept_rect(origin, slide)
+        .expect("should hit the wall");
+    assert_eq!(id, wall);
+    let gap = Fp::from(6) - origin.move_by(slide * result.closest_time).right();
+    assert!(gap >= skin);
+    assert!(approx_eq(gap, skin, Fp::from(0.001)));
+}
+
+#[test]
+fn test_insert_tilemap_adds_one_rect_per_tile() {
+    let mut world = World::new();
+    let existing = world.insert(Rect::from((100, 100, 2, 2)));
+    let tiles = [(0, 0), (1, 0), (-2, 3)];
+
+    let ids = world.insert_tilemap(&tiles, Fp::from(4));
+    assert_eq!(ids.len(), tiles.len());
+    assert_eq!(world.len(), 4);
+    assert!(!ids.contains(&existing));
+
+    for (id, (tx, ty)) in ids.iter().zip(tiles) {
+        assert_eq!(
+            world.get(*id),
+            Some(ColliderShape::Rect(tile_to_rect(tx, ty, Fp::from(4))))
+        );
+    }
+    assert_eq!(world.query_point(Vector::from((-6, 13))), Some(ids[2]));
+
+    assert!(world.insert_tilemap(&[], Fp::from(4)).is_empty());
+    assert_eq!(world.len(), 4);
+}
+
+#[test]
+fn test_nearest_within_picks_closest_collider() {
+    let mut world = World::new();
+    let two_away = world.insert(Rect::from((2, -1, 2, 2)));
+    let three_away = world.insert(Rect::from((-5, -1, 2, 2)));
+    let circle = world.insert(Circle::new(Vector::from((0, 5)), Fp::from(1)));
+    let just_outside = world.insert(Rect::from((0.0, -6.01, 2.0, 2.0)));
+    let radius = Fp::from(4);
+
+    assert_eq!(
+        world.nearest_within(Vector::from((0, 0)), radius),
+        Some(two_away)
+    );
+
+    world.remove(two_away);
+    assert_eq!(
+        world.nearest_within(Vector::from((0, 0)), radius),
+        Some(three_away)
+    );
+
+    world.remove(three_away);
+    // The circle is measured by its bounding rect, which is four units away
+    assert_eq!(
+        world.nearest_within(Vector::from((0, 0)), radius),
+        Some(circle)
+    );
+
+    world.remove(circle);
+    assert_eq!(world.nearest_within(Vector::from((0, 0)), radius), None);
+    assert_eq!(
+        world.nearest_within(Vector::from((0, 0)), Fp::from(4.01)),
+        Some(just_outside)
+    );
+}
+
+#[test]
+fn test_nearest_within_ties_and_containment() {
+    let mut world = World::new();
+    let left = world.insert(Rect::from((-3, 0, 2, 2)));
+    let right = world.insert(Rect::from((1, 0, 2, 2)));
+
+    // Both are one unit away, the one inserted last wins
+    assert_eq!(
+        world.nearest_within(Vector::from((0, 1)), Fp::from(2)),
+        Some(right)
+    );
+
+    let under = world.insert(Rect::from((-10, -10, 20, 20)));
+    assert_eq!(
+        world.nearest_within(Vector::from((0, 1)), Fp::zero()),
+        Some(under)
+    );
+    assert_eq!(
+        world.nearest_within(Vector::from((-2, 1)), Fp::zero()),
+        Some(under)
+    );
+
+    world.remove(under);
+    assert_eq!(
+        world.nearest_within(Vector::from((-2, 1)), Fp::zero()),
+        Some(left)
+    );
+    assert_eq!(world.nearest_within(Vector::from((0, 1)), -Fp::one()), None);
+}
+
+#[test]
+fn test_query_ray_returns_user_data() {
+    let mut world = World::new();
+    let near = world.insert_with_user_data(Rect::from((10, -2, 2, 4)), 100);
+    let far = world.insert_with_user_data(Circle::new(Vector::from((20, 0)), Fp::from(2)), 200);
+    let untagged = world.insert(Rect::from((4, 8, 2, 4)));
+
+    let origin = Vector::from((0, 0));
+    let (id, user_data, result) = world.query_ray(origin, Vector::from((1, 0))).unwrap();
+    assert_eq!((id, user_data), (near, Some(100)));
+    assert_eq!(result.closest_time, Fp::from(10));
+
+    world.remove(near);
+    assert_eq!(world.user_data(near), None);
+    let (id, user_data, _) = world.query_ray(origin, Vector::from((1, 0))).unwrap();
+    assert_eq!((id, user_data), (far, Some(200)));
+
+    let (id, user_data, _) = world.query_ray(origin, Vector::from((1.0, 2.5))).unwrap();
+    assert_eq!((id, user_data), (untagged, None));
+
+    assert!(world.query_ray(origin, Vector::from((-1, 0))).is_none());
+}
+
+#[test]
+fn test_restore_snapshot_restores_user_data() {
+    let mut world = World::new();
+    let wall = world.insert_with_user_data(Rect::from((10, -2, 2, 4)), 7);
+    let snapshot = world.snapshot();
+
+    world.remove(wall);
+    let other = world.insert_with_user_data(Rect::from((10, -2, 2, 4)), 8);
+    assert_eq!(world.user_data(wall), None);
+
+    world.restore(&snapshot);
+    assert_eq!(world.user_data(wall), Some(7));
+    assert_eq!(world.user_data(other), None);
+    assert_eq!(
+        world
+            .query_ray(Vector::from((0, 0)), Vector::from((1, 0)))
+            .map(|(id, user_data, _)| (id, user_data)),
+        Some((wall, Some(7)))
+    );
+}
+
+#[test]
+fn test_raycast_tie_picks_first_inserted() {
+    let mut world = World::new();
+    let _miss = world.insert(Rect::from((10, 10, 2, 2)));
+    let first = world.insert(Rect::from((10, -2, 2, 4)));
+    let _same_face = world.insert(Rect::from((10, -1, 6, 2)));
+    let _circle = world.insert(Circle::new(Vector::from((12, 0)), Fp::from(2)));
+
+    let (id, result) = world
+        .raycast(Vector::from((0, 0)), Vector::from((1, 0)))
+        .expect("should hit");
+    assert_eq!(id, first);
+    assert_eq!(result.closest_time, Fp::from(10));
+}
+
+#[test]
+fn test_swept_rect_skin_on_long_corner_hit() {
+    let mut world = World::new();
+    let block = world.insert(Rect::from((250, 250, 10, 10)));
+    world.set_skin_width(Fp::one());
+
+    // The corners meet exactly, so the skin is measured along a delta whose squared length
+    // does not fit in an `Fp`
+    let origin = Rect::from((0, 0, 2, 2));
+    let delta = Vector::from((300, 300));
+    let (id, result) = world
+        .swept_rect(origin, delta)
+        .expect("should hit the corner");
+    assert_eq!(id, block);
+    assert_eq!(result.face, ContactSide::Corner);
+
+    let moved = origin.move_by(delta * result.closest_time);
+    let gap = length(
+        Vector::from((250, 250)) - (moved.pos + moved.size),
+        Rounding::Floor,
+    );
+    assert!(gap >= Fp::one());
+    assert!(approx_eq(gap, Fp::one(), Fp::from(0.01)));
+}
+
+#[test]
+fn test_restore_snapshot_restores_skin_width() {
+    let mut world = World::new();
+    world.insert(Rect::from((10, -4, 2, 8)));
+    world.set_skin_width(Fp::from(0.5));
+
+    let origin = Rect::from((0, 0, 2, 2));
+    let delta = Vector::from((16, 0));
+    let (_, before) = world.swept_rect(origin, delta).unwrap();
+    let snapshot = world.snapshot();
+
+    world.set_skin_width(Fp::zero());
+    assert_ne!(world.snapshot(), snapshot);
+
+    world.restore(&snapshot);
+    assert_eq!(world.skin_width(), Fp::from(0.5));
+    assert_eq!(
+        world.swept_rect(origin, delta).unwrap().1.closest_time,
+        before.closest_time
+    );
+    assert_eq!(world.snapshot(), snapshot);
+}